@@ -36,9 +36,10 @@ pub struct CommandLineOptions {
     // OPTIONS
     //////////
 
-    /// Input file with Quake3 Server log messages
+    /// Input file with Quake3 Server log messages -- may be repeated, for the files to be read in order, as a single feed
+    /// (such as rotated logs: `--log-file games.log.1 --log-file games.log`)
     #[structopt(long)]
-    pub log_file: Option<String>,
+    pub log_file: Vec<String>,
 
 }
 
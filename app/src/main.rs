@@ -17,7 +17,7 @@
 //!         --verbose     Outputs any non-fatal errors or inconsistencies in the events to stderr
 //!
 //! OPTIONS:
-//!         --log-file <log-file>    Input file with Quake3 Server log messages
+//!         --log-file <log-file>...    Input file with Quake3 Server log messages -- may be repeated, for the files to be read in order, as a single feed
 //!
 //!
 //! Explore some execution options:
@@ -27,6 +27,7 @@
 //!  - ./target/release/app -extended --log-file '<path_to_quake3_log_file>'      # performs extra analysis and gives out a richer report
 //!  - ./target/release/app -pedantic --log-file '<path_to_quake3_log_file>'      # stop on any error or inconsistency in the events
 //!  - ./target/release/app -verbose  --log-file '<path_to_quake3_log_file>'      # continues on any non-fatal errors or inconsistencies in the events, but outputs them to stderr
+//!  - ./target/release/app --log-file games.log.1 --log-file games.log           # reads rotated logs as a single feed
 //! 
 //! To prove this application works with Streams of data of undefined size, run in bash:
 //!  for i in {0..1000}; do cat 'bll/tests/resources/qgames_permissive.log'; done | time -v ./target/release/app --extended >/dev/null
@@ -56,6 +57,7 @@ use std::{
     borrow::Cow,
     collections::HashSet,
     io::BufWriter,
+    path::PathBuf,
     sync::Arc,
 };
 
@@ -71,12 +73,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let command_line_options = command_line::parse_from_args();
 
-    let dal_implementation = match command_line_options.log_file {
-        Some(log_file) => dal_api::Quake3ServerEventsImplementations::SyncLogFileReader(dal_api::FileReaderInfo { log_file_path: Cow::Owned(log_file) }),
-        None => dal_api::Quake3ServerEventsImplementations::StdinReader,
+    let mut log_files = command_line_options.log_file;
+    let dal_implementation = match log_files.len() {
+        0 => dal_api::Quake3ServerEventsImplementations::StdinReader,
+        1 => dal_api::Quake3ServerEventsImplementations::SyncLogFileReader(dal_api::FileReaderInfo { log_file_path: Cow::Owned(log_files.remove(0)) }),
+        _ => dal_api::Quake3ServerEventsImplementations::MultiFileReader(log_files.into_iter().map(PathBuf::from).collect()),
     };
     let dal_config = Arc::new(dal_api::Config {
         debug: command_line_options.debug,
+        validate_time_ranges: command_line_options.pedantic,
+        strict_init_game: command_line_options.pedantic,
         ..dal_api::Config::default()
     });
     let logic_config = bll_api::Config {
@@ -86,6 +92,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         processor_pipeline: if command_line_options.extended {
             HashSet::from([
                 bll_api::EventAnalyserOperations::MeansOfDeath,
+                bll_api::EventAnalyserOperations::KillTimeline,
                 bll_api::EventAnalyserOperations::Kills,
                 bll_api::EventAnalyserOperations::PlayerIdsAndNickNamesResolutions,
                 bll_api::EventAnalyserOperations::GameReportedScores,
@@ -105,7 +112,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let presentation_writer = BufWriter::with_capacity(OUTPUT_BUFFER_SIZE, std::io::stdout());
 
 
-    let log_dao = dal::factory::instantiate_log_dao(dal_implementation, dal_config);
+    let log_dao = dal::factory::instantiate_log_dao(dal_implementation, dal_config)?;
     let logic = bll::SummaryLogic::new(logic_config);
     let summaries_stream = logic.summarize_games(log_dao)?;
     presentation::to_json(&presentation_config, summaries_stream, presentation_writer)?;
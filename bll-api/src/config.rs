@@ -1,7 +1,10 @@
 //! Resting place for BLL's [Config] & friends
 
 
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+};
 
 /// Configuration to dictate the tunable behaviors of the Business Logic Layer
 pub struct Config {
@@ -23,16 +26,123 @@ pub struct Config {
     /// What operations should be applied -- each with their own CPU & RAM resources needs
     pub processor_pipeline: HashSet<EventAnalyserOperations>,
 
+    /// How self-kills (suicides) are told apart from regular kills -- see [SelfKillKey]
+    pub self_kill_by: SelfKillKey,
+
+    /// If true, a `ClientUserinfoChanged` for an unknown client id is taken as an implicit connect, registering the player
+    /// -- instead of flagging a `ClientNotConnected` violation. Useful for trimmed logs lacking the `ClientConnect` events.
+    pub userinfo_implies_connect: bool,
+
+    /// If true, a `ClientDisconnect` for a client id that already disconnected (and didn't connect again) is taken as
+    /// a benign no-op -- instead of flagging a `ClientNotConnected` violation. Useful for logs with duplicate disconnects.
+    pub tolerate_duplicate_disconnects: bool,
+
+    /// If true, the players in the summaries are kept in their order of appearance -- instead of sorted by name
+    pub preserve_player_order: bool,
+
+    /// If true, map names are lowercased -- so case variants (`q3dm17` vs `Q3DM17`) are taken as the same map,
+    /// merging in the by-map stats
+    pub canonicalize_map_names: bool,
+
+    /// If set, games with less than this many participants (`GameMatchSummary::total_participants` -- so disconnected
+    /// players count) are dropped -- such as empty-server warmups. The reasons are logged if [Self::log_issues] is set.
+    pub min_players: Option<usize>,
+
+    /// If set, logs having more than this many games are rejected: an error is yielded in place of the first game past
+    /// the limit, ending the summaries -- as a resource safety measure against unexpectedly huge inputs.
+    /// Games dropped due to [Self::min_players] still count.
+    pub max_total_games: Option<usize>,
+
+    /// The mean of death used, by the means of death processor, for `Kill` events having an empty reason
+    /// (their text description ending in ` by `) -- so they don't show up under an empty key
+    pub unknown_means_label: String,
+
+    /// The name used for players whose names are empty or made of whitespace only (as in `ClientUserinfoChanged: 2 n\\t\\0`)
+    /// -- both when resolving the players & when attributing their kills -- so they don't show up under a blank key
+    pub empty_name_label: String,
+
+    /// If true, each summary is tagged with the hash of the game's raw log lines, as `GameMatchSummary::source_hash`
+    /// -- which requires the DAL to compute it: see `dal_api::Config::hash_source_lines`
+    pub hash_source_lines: bool,
+
+    /// If true, the repeated player names share their storage in the logic layer's internal bookkeeping -- the game
+    /// participants & the kill timeline, which keeps a name per kill. The summaries themselves hold their own `String`s
+    /// (allocated once per player & game), so they are the same either way.\
+    /// Names are interned per game: the pool is emptied whenever a new game starts, so memory stays bounded on endless feeds.
+    pub intern_names: bool,
+
+    /// If true, groups consecutive games played by the exact same set of players (rematches) into a series,
+    /// filling in `GameMatchSummary::series_id`
+    pub group_rematches: bool,
+
+    /// If true, each summary carries the mean, median & standard deviation of its players' frags,
+    /// as `GameMatchSummary::kill_distribution`
+    pub kill_distribution: bool,
+
+    /// If set, the aggregate stats across all games (`model::report::GlobalStats`) are saved to this file after each
+    /// completed game -- and, if the file already exists, the aggregation resumes from it. Useful for very long live feeds,
+    /// where a restart shouldn't lose the aggregation done so far.\
+    /// The checkpoint also keeps the id of the last consumed event: when resuming, the events up to it are skipped --
+    /// so a replayed feed doesn't have its games aggregated twice. The DAL may be spared from reading those lines again
+    /// through `dal_api::Config::skip_lines` -- see `bll::Checkpoint::skip_lines()`.
+    pub checkpoint_path: Option<PathBuf>,
+
+    /// If true, the ordered list of the stages composed for this config -- from the events `Stream` to the summaries one --
+    /// is logged, at the debug level, whenever the games are summarized: for users to verify the pipeline they got
+    pub log_pipeline_composition: bool,
+
+    /// If true, `SummaryLogicApi::summarize_games_to_sink()` stops at the first game that couldn't be summarized
+    /// -- otherwise, such games are skipped, being reported as `Warning::SkippedGame`, and the deliveries go on
+    pub stop_sink_on_errors: bool,
+
 }
 
 /// The operations the Business Logic Layer may perform on the Quake3 Events feed
 /// to aggregate into a summary to present to the user
-#[derive(Debug, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum EventAnalyserOperations {
     MeansOfDeath,
     Kills,
     PlayerIdsAndNickNamesResolutions,
     GameReportedScores,
+    /// Tracks the log time of the `Kill` events, enabling timing statistics
+    KillTimeline,
+    /// Echoes every `Kill` event, unaggregated, for auditing purposes -- may be large
+    KillLog,
+    /// Counts the `Item` events (pickups) of each game, as a measure of activity
+    ItemActivity,
+    /// Keeps the chat transcript (`say`, `say_team` & `tell` messages) of each game
+    Chat,
+}
+
+/// Returns the [Config::processor_pipeline] combinations the Business Logic Layer is able to run
+/// -- any set of operations containing [EventAnalyserOperations::Kills]: summarizing the games with any other fails
+pub fn supported_pipelines() -> Vec<HashSet<EventAnalyserOperations>> {
+    use EventAnalyserOperations::*;
+    let optional_operations = [MeansOfDeath, PlayerIdsAndNickNamesResolutions, GameReportedScores, KillTimeline, KillLog, ItemActivity, Chat];
+    (0..1_u32 << optional_operations.len())
+        .map(|selection| optional_operations.iter()
+            .enumerate()
+            .filter(|(bit, _operation)| selection & (1 << bit) != 0)
+            .map(|(_bit, operation)| *operation)
+            .chain([Kills])
+            .collect())
+        .collect()
+}
+
+/// Tells if `ops` is one of the [supported_pipelines()] -- allowing the [Config] to be validated upfront
+pub fn is_supported_pipeline(ops: &HashSet<EventAnalyserOperations>) -> bool {
+    ops.contains(&EventAnalyserOperations::Kills)
+}
+
+/// The criteria for detecting a self-kill (suicide) in a `Kill` event -- which is not credited as a frag in the
+/// per-player kill statistics, such as the kill timeline. The game scores are not affected
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SelfKillKey {
+    /// The killer & victim ids are the same -- correct even if distinct players share the same name
+    Id,
+    /// The killer & victim names are the same
+    Name,
 }
 
 impl Default for Config {
@@ -43,7 +153,44 @@ impl Default for Config {
             stop_on_event_model_violations: false,
             processor_pipeline: HashSet::from([
                 EventAnalyserOperations::Kills
-            ])
+            ]),
+            self_kill_by: SelfKillKey::Id,
+            userinfo_implies_connect: false,
+            tolerate_duplicate_disconnects: false,
+            preserve_player_order: false,
+            canonicalize_map_names: false,
+            min_players: None,
+            max_total_games: None,
+            unknown_means_label: String::from("UNKNOWN"),
+            empty_name_label: String::from("<unnamed>"),
+            hash_source_lines: false,
+            intern_names: false,
+            group_rematches: false,
+            kill_distribution: false,
+            checkpoint_path: None,
+            log_pipeline_composition: false,
+            stop_sink_on_errors: false,
         }
     }
-}
\ No newline at end of file
+}
+
+
+/// Unit tests for the [config](super) module
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// Tests that known combinations of operations are told apart from the unsupported ones
+    #[test]
+    fn supported_pipeline_combinations() {
+        use EventAnalyserOperations::*;
+        assert!(is_supported_pipeline(&HashSet::from([Kills])), "The default pipeline should be supported");
+        assert!(is_supported_pipeline(&HashSet::from([GameReportedScores, PlayerIdsAndNickNamesResolutions, Kills])), "The order of the operations shouldn't matter");
+        assert!(!is_supported_pipeline(&HashSet::new()), "An empty pipeline should not be supported");
+        assert!(!is_supported_pipeline(&HashSet::from([MeansOfDeath])), "`Kills` is always required");
+        assert!(is_supported_pipeline(&HashSet::from([Chat, ItemActivity, Kills])), "Any combination with `Kills` should be supported");
+        assert_eq!(supported_pipelines().len(), 128, "Every combination of the 7 optional operations, along with `Kills`, should be listed");
+    }
+
+}
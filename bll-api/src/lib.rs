@@ -3,10 +3,16 @@
 mod config;
 pub use config::*;
 
+mod warning;
+pub use warning::Warning;
+
 use common::types::Result;
 use dal_api::Quake3ServerEvents;
-use model::report::GamesSummary;
-use std::sync::Arc;
+use model::report::{GameMatchSummary, GamesSummary};
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+};
 
 
 pub trait SummaryLogicApi {
@@ -19,4 +25,18 @@ pub trait SummaryLogicApi {
     /// See [Config] for the options of how to compose the operations.
     fn summarize_games(&self, log_dao: Box<dyn Quake3ServerEvents>) -> Result<GamesSummary>;
 
+    /// Same as [Self::summarize_games()], but also collects the [Warning]s found along the way -- regardless of
+    /// [Config::log_issues], which keeps controlling if they are logged as well.\
+    /// The collection is populated as the returned `Stream` is consumed, being complete once it is drained.
+    fn summarize_games_with_warnings(&self, log_dao: Box<dyn Quake3ServerEvents>) -> Result<(GamesSummary, Arc<Mutex<Vec<Warning>>>)>;
+
+    /// Async-consumer counterpart of [Self::summarize_games()]: each game is handed to `sink` -- along with its 1-based game id --
+    /// as soon as it is summarized, with the returned `Future` being awaited before the next game is processed: a slow sink,
+    /// such as one writing to a congested network, slows down the reading of the events (backpressure).\
+    /// Games that couldn't be summarized are skipped -- see [Config::stop_sink_on_errors] -- but still take up their game id.\
+    /// The returned `Future` resolves once all games were delivered -- or to the first error from `sink`.
+    fn summarize_games_to_sink<Sink, SinkFuture>(&self, log_dao: Box<dyn Quake3ServerEvents>, sink: Sink) -> impl Future<Output=Result<()>>
+                                                 where Sink: FnMut(u32, GameMatchSummary) -> SinkFuture,
+                                                       SinkFuture: Future<Output=Result<()>>;
+
 }
\ No newline at end of file
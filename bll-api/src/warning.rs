@@ -0,0 +1,29 @@
+//! Resting place for [Warning]
+
+
+use std::fmt::{Display, Formatter};
+
+/// The non-fatal issues found while summarizing the games -- see [crate::SummaryLogicApi::summarize_games_with_warnings()].\
+/// Their [Display] form is the one logged when [crate::Config::log_issues] is set.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// An event couldn't be read from the feed (IO or parsing error) -- so it was skipped
+    FeedError { event_id: u32, description: String },
+    /// A game summary ended up in an inconsistent state -- which no log should be able to cause, therefore indicating a bug
+    Inconsistency { event_id: u32, description: String },
+    /// A game was left out of the summaries -- `game_index` is its 1-based position in the feed, errors included
+    SkippedGame { game_index: u32, map_name: Option<String>, reason: String },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::FeedError { event_id, description } =>
+                write!(f, "Failed to process Quake 3 Server event #{event_id}: {description}"),
+            Warning::Inconsistency { event_id, description } =>
+                write!(f, "Event #{event_id}: inconsistent game summary: {description}"),
+            Warning::SkippedGame { game_index, map_name, reason } =>
+                write!(f, "Game #{game_index} (map: {}) skipped: {reason}", map_name.as_deref().unwrap_or("<unknown>")),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Measures the means of death processor over a kill-heavy log -- a single game of rocket splashes --
+//! to keep an eye on the per-`Kill` overhead of the additional events it produces.
+//!
+//! Two measurements are made:
+//!   1) The number of heap allocations -- counted by a wrapping global allocator and printed before the benchmarks run
+//!   2) The time taken to summarize the game, with & without the means of death processor
+//!
+//! Run with `cargo bench -p bll --bench means_of_death`.
+//!
+//! # Analysis 2026-10-16
+//!     1) Emitting the `MeanOfDeath` events through `flat_map()` -- a sub-stream per event -- costed ~28% over the pipeline
+//!        without the processor (143ms vs 111ms for the 100k kills); the dedicated `FlatMapPair` combinator brought it down to ~1%
+//!     2) Allocations went from 2 to 1 per kill, by no longer allocating the means of death key on every `Kill` when summarizing
+//!        -- the remaining one is the owned reason name of the `MeanOfDeath` event
+//!
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::HashSet,
+    io::Cursor,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use bll::SummaryLogic;
+use bll_api::{Config, EventAnalyserOperations, SummaryLogicApi};
+use dal::sync_reader::Quake3LogSyncReader;
+use criterion::{criterion_group, criterion_main, Criterion, black_box};
+
+
+/// How many `Kill` events the benchmarked game has
+const KILLS: usize = 100_000;
+
+
+/// Counts every allocation made through the system allocator
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+/// Builds a log with a single game of [KILLS] rocket splashes between 2 players
+fn rocket_splash_log() -> String {
+    let mut log = String::from("  0:00 InitGame: \\mapname\\q3dm17\n  \
+                                0:01 ClientConnect: 2\n  \
+                                0:01 ClientUserinfoChanged: 2 n\\Isgalamido\\t\\0\n  \
+                                0:02 ClientConnect: 3\n  \
+                                0:02 ClientUserinfoChanged: 3 n\\Zeh\\t\\0\n");
+    for kill in 0..KILLS {
+        let (killer, victim) = if kill % 2 == 0 { ((2, "Isgalamido"), (3, "Zeh")) } else { ((3, "Zeh"), (2, "Isgalamido")) };
+        log.push_str(&format!("  3:37 Kill: {} {} 7: {} killed {} by MOD_ROCKET_SPLASH\n", killer.0, victim.0, killer.1, victim.1));
+    }
+    log.push_str("  9:59 ShutdownGame:\n");
+    log
+}
+
+/// Summarizes all games of `log`, returning the number of summaries
+fn summarize(log: &str, means_of_death: bool) -> usize {
+    let mut processor_pipeline = HashSet::from([
+        EventAnalyserOperations::Kills,
+        EventAnalyserOperations::PlayerIdsAndNickNamesResolutions,
+        EventAnalyserOperations::GameReportedScores,
+    ]);
+    if means_of_death {
+        processor_pipeline.insert(EventAnalyserOperations::MeansOfDeath);
+    }
+    let config = Config {
+        processor_pipeline,
+        ..Config::default()
+    };
+    let log_dao = Quake3LogSyncReader::new(Arc::new(dal_api::Config::default()), "<bench>", Cursor::new(log.as_bytes().to_vec()), 0);
+    let summaries_stream = SummaryLogic::new(config).summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
+    futures::executor::block_on_stream(summaries_stream).count()
+}
+
+fn bench_means_of_death(criterion: &mut Criterion) {
+
+    let log = rocket_splash_log();
+
+    for means_of_death in [false, true] {
+        let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+        let games = summarize(&log, means_of_death);
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+        println!("means_of_death={means_of_death}: {allocations} allocations to summarize {games} games of {KILLS} kills");
+    }
+
+    let mut group = criterion.benchmark_group("Summarizing a rocket splash heavy log");
+    group.bench_function("without means of death", |bencher| bencher.iter(|| black_box(summarize(&log, false))));
+    group.bench_function("with means of death",    |bencher| bencher.iter(|| black_box(summarize(&log, true))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_means_of_death);
+criterion_main!(benches);
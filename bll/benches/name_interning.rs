@@ -0,0 +1,130 @@
+//! Measures the effects of `bll_api::Config::intern_names` when summarizing kill-heavy games,
+//! helping us decide if sharing the storage of the repeated player names is worth it.
+//!
+//! The events are generated in memory, borrowing their names, so the counted allocations are the logic layer's own --
+//! not the ones made by the DAL when reading & parsing the log lines.
+//!
+//! Two measurements are made:
+//!   1) The number of heap allocations -- counted by a wrapping global allocator and printed before the benchmarks run
+//!   2) The time taken to summarize all the games
+//!
+//! Run with `cargo bench -p bll --bench name_interning`.
+//!
+//! # Analysis 2026-10-16
+//!     1) Without interning, the logic layer makes ~1 allocation per kill (102273 for the 100k kills): the fragger's name kept
+//!        by the kill timeline -- the participants & the summaries' maps only allocate a name once per player & game
+//!     2) Interning takes that down to 2198 allocations, with the kill timeline sharing the participants' names
+//!     3) Time is about the same (~95ms without vs ~89ms with) -- the hashing pays for most of the saved allocations:
+//!        interning is for memory, not speed, hence it being off by default
+//!
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    borrow::Cow,
+    collections::HashSet,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use bll::SummaryLogic;
+use bll_api::{Config, EventAnalyserOperations, SummaryLogicApi};
+use common::types::Result;
+use dal_api::Quake3ServerEvents;
+use model::quake3_events::Quake3Events;
+use futures::{stream, Stream};
+use criterion::{criterion_group, criterion_main, Criterion, black_box};
+
+
+/// How many games are summarized
+const GAMES: u32 = 20;
+
+/// How many `Kill` events each game has
+const KILLS_PER_GAME: u32 = 5_000;
+
+/// The players of every game -- their ids are their positions here, plus 2
+const PLAYERS: [&str; 4] = ["Isgalamido", "Dono da Bola", "Mocinha", "Zeh"];
+
+
+/// Counts every allocation made through the system allocator
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+/// Yields [GAMES] games of [KILLS_PER_GAME] kills between the [PLAYERS], with all names borrowed
+struct InMemoryDAL;
+
+impl Quake3ServerEvents for InMemoryDAL {
+    fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
+        let events_per_game = 2 * PLAYERS.len() as u32 + KILLS_PER_GAME + 2;
+        let events = (0..GAMES * events_per_game).map(move |event_id| {
+            let time_secs = Some(event_id % events_per_game);
+            match event_id % events_per_game {
+                0 => Quake3Events::InitGame { event_id, time_secs, map_name: Some(Cow::Borrowed("q3dm17")), source: None },
+                n if n <= 2 * PLAYERS.len() as u32 => {
+                    let client_id = (n - 1) / 2 + 2;
+                    if n % 2 == 1 {
+                        Quake3Events::ClientConnect { event_id, time_secs, client_id }
+                    } else {
+                        Quake3Events::ClientUserinfoChanged { event_id, time_secs, client_id, name: Cow::Borrowed(PLAYERS[client_id as usize - 2]) }
+                    }
+                },
+                n if n == events_per_game - 1 => Quake3Events::ShutdownGame { event_id, time_secs, source_hash: None },
+                n => {
+                    let killer = n as usize % PLAYERS.len();
+                    let victim = (killer + 1) % PLAYERS.len();
+                    Quake3Events::Kill { event_id, time_secs, killer_id: killer as u32 + 2, victim_id: victim as u32 + 2, reason_id: 7,
+                                         killer_name: Cow::Borrowed(PLAYERS[killer]), victim_name: Cow::Borrowed(PLAYERS[victim]), reason_name: Cow::Borrowed("MOD_ROCKET_SPLASH") }
+                },
+            }
+        });
+        Ok(Box::pin(stream::iter(events)))
+    }
+}
+
+/// Summarizes all games yielded by [InMemoryDAL], returning the number of summaries
+fn summarize(intern_names: bool) -> usize {
+    let config = Config {
+        processor_pipeline: HashSet::from([
+            EventAnalyserOperations::MeansOfDeath,
+            EventAnalyserOperations::KillTimeline,
+            EventAnalyserOperations::Kills,
+            EventAnalyserOperations::PlayerIdsAndNickNamesResolutions,
+            EventAnalyserOperations::GameReportedScores,
+        ]),
+        intern_names,
+        ..Config::default()
+    };
+    let summaries_stream = SummaryLogic::new(config).summarize_games(Box::new(InMemoryDAL)).expect("sumarize_games() shouldn't fail here");
+    futures::executor::block_on_stream(summaries_stream).count()
+}
+
+fn bench_name_interning(criterion: &mut Criterion) {
+
+    for intern_names in [false, true] {
+        let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+        let games = summarize(intern_names);
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+        println!("intern_names={intern_names}: {allocations} allocations to summarize {games} games of {KILLS_PER_GAME} kills");
+    }
+
+    let mut group = criterion.benchmark_group("Summarizing kill-heavy games");
+    group.bench_function("without name interning", |bencher| bencher.iter(|| black_box(summarize(false))));
+    group.bench_function("with name interning",    |bencher| bencher.iter(|| black_box(summarize(true))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_name_interning);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+//! Persistence of the aggregate stats across games -- see [bll_api::Config::checkpoint_path]
+
+use common::types::Result;
+use dal_api::Quake3ServerEvents;
+use model::{
+    quake3_events::Quake3Events,
+    report::GlobalStats,
+};
+use futures::{Stream, StreamExt};
+use std::{
+    future,
+    path::Path,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
+
+
+/// What is saved after each game: the aggregation so far & where, in the feed, it was at
+#[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Checkpoint {
+    /// The stats of all games aggregated so far
+    pub global_stats: GlobalStats,
+    /// The id of the last event consumed by the aggregation -- the `ShutdownGame` of the last aggregated game.
+    /// When resuming, the events up to it are skipped, so no game is aggregated twice
+    pub last_event_id: Option<u32>,
+}
+
+impl Checkpoint {
+
+    /// The value for `dal_api::Config::skip_lines` sparing the DAL from reading, again, the lines already aggregated
+    /// -- for a feed whose first event id is `event_id_base` (see `dal_api::Config::event_id_base`).\
+    /// Optional: the events up to [Self::last_event_id] are skipped anyway.
+    pub fn skip_lines(&self, event_id_base: u32) -> usize {
+        self.last_event_id
+            .map_or(0, |last_event_id| (last_event_id + 1).saturating_sub(event_id_base) as usize)
+    }
+
+}
+
+/// Restores the [Checkpoint] saved by [save_checkpoint()] -- or the empty one, if `checkpoint_path` doesn't exist yet
+pub fn load_checkpoint(checkpoint_path: impl AsRef<Path>) -> Result<Checkpoint> {
+    let checkpoint_path = checkpoint_path.as_ref();
+    if !checkpoint_path.exists() {
+        return Ok(Checkpoint::default())
+    }
+    let contents = std::fs::read(checkpoint_path)
+        .map_err(|err| format!("load_checkpoint(): Error reading the checkpoint file '{}': {err}", checkpoint_path.display()))?;
+    let checkpoint = serde_json::from_slice(&contents)
+        .map_err(|err| format!("load_checkpoint(): Error parsing the checkpoint file '{}': {err}", checkpoint_path.display()))?;
+    Ok(checkpoint)
+}
+
+/// Saves `checkpoint` into `checkpoint_path`.\
+/// The contents are written to a temporary file first, which then replaces the checkpoint -- so a crash while saving
+/// can't corrupt the previous checkpoint.
+pub fn save_checkpoint(checkpoint_path: impl AsRef<Path>, checkpoint: &Checkpoint) -> Result<()> {
+    let checkpoint_path = checkpoint_path.as_ref();
+    let temporary_path = checkpoint_path.with_extension("tmp");
+    let contents = serde_json::to_vec(checkpoint)
+        .map_err(|err| format!("save_checkpoint(): Error serializing the checkpoint: {err}"))?;
+    std::fs::write(&temporary_path, contents)
+        .map_err(|err| format!("save_checkpoint(): Error writing the temporary checkpoint file '{}': {err}", temporary_path.display()))?;
+    std::fs::rename(&temporary_path, checkpoint_path)
+        .map_err(|err| format!("save_checkpoint(): Error replacing the checkpoint file '{}': {err}", checkpoint_path.display()))?;
+    Ok(())
+}
+
+/// Wraps a feed so it resumes after the `last_event_id` of a [Checkpoint] -- skipping the events up to it -- while keeping,
+/// in `consumed_event_id`, the id of the last event handed out: where the feed is at, for the next checkpoint
+pub(crate) struct ResumedEvents {
+    pub log_dao: Box<dyn Quake3ServerEvents>,
+    pub last_event_id: Option<u32>,
+    pub consumed_event_id: Arc<AtomicU32>,
+}
+
+impl Quake3ServerEvents for ResumedEvents {
+    fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
+        let Self { log_dao, last_event_id, consumed_event_id } = *self;
+        let stream = log_dao.events_stream()?
+            .filter(move |quake3_event| future::ready(last_event_id.is_none_or(|last_event_id| quake3_event.event_id() > last_event_id)))
+            .inspect(move |quake3_event| consumed_event_id.store(quake3_event.event_id(), Ordering::Relaxed));
+        Ok(Box::pin(stream))
+    }
+}
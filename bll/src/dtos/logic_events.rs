@@ -35,26 +35,35 @@ impl CompositeEvent<'_> {
 /// The events the main logic algorithms generates for the composable business logics to process
 #[derive(Debug)]
 pub enum LogicEvents<'a> {
-    /// A game has started
-    NewGame { quake3_event_id: u32 },
-    /// A new player joined the game
-    AddPlayer { quake3_event_id: u32, client_id: u32, name: Cow<'a, str> },
+    /// A game has started -- on the given map, being read from `source`, if known
+    NewGame { quake3_event_id: u32, map_name: Option<Cow<'a, str>>, source: Option<Cow<'a, str>> },
+    /// A new player joined the game -- having connected at the given log time, if known
+    AddPlayer { quake3_event_id: u32, client_id: u32, name: Cow<'a, str>, time_secs: Option<u32> },
     /// An existing player changed its nick name
     RenamePlayer { quake3_event_id: u32, client_id: u32, old_name: Cow<'a, str>, new_name: Cow<'a, str> },
-    /// An existing player quit the game
-    DeletePlayer { quake3_event_id: u32, client_id: u32, name: Cow<'a, str> },
+    /// An existing player quit the game -- at the given log time, if known
+    DeletePlayer { quake3_event_id: u32, client_id: u32, name: Cow<'a, str>, time_secs: Option<u32> },
     /// Reports the cause of the last death
     MeanOfDeath { quake3_event_id: u32, mean_of_death: Cow<'a, str> },
     /// A player killed someone
     IncFrags { quake3_event_id: u32, client_id: u32, name: Cow<'a, str> },
     /// The player committed suicide (was killed by '<world>')
     DecFrags { quake3_event_id: u32, client_id: u32, name: Cow<'a, str> },
+    /// A kill happened at the given log time -- `fragger` is the player credited with a frag, if any
+    KillTime { quake3_event_id: u32, time_secs: u32, fragger: Option<Cow<'a, str>> },
+    /// Echoes a kill, as reported by the game, for auditing purposes
+    RawKill { quake3_event_id: u32, killer_name: Cow<'a, str>, victim_name: Cow<'a, str>, reason_name: Cow<'a, str> },
+    /// A player picked up an item
+    ItemPickup { quake3_event_id: u32 },
+    /// A player sent a chat message -- `speaker` is in the form `<FROM> to <TO>` for private messages
+    ChatMessage { quake3_event_id: u32, speaker: Cow<'a, str>, message: Cow<'a, str> },
     /// The game reported its own account of a player's scored frags
     ReportedScore { quake3_event_id: u32, frags: i32, client_id: u32, name: Cow<'a, str> },
     /// A game has ended in a graceful manner: the match progressed until one of the limits were reached
-    GameEndedGracefully { quake3_event_id: u32 },
+    /// -- `source_hash` is the hash of the game's log lines, if requested
+    GameEndedGracefully { quake3_event_id: u32, source_hash: Option<Cow<'a, str>> },
     /// A game has ended without reaching any of the limits -- most likely due to an operator command
-    GameEndedManually { quake3_event_id: u32 },
+    GameEndedManually { quake3_event_id: u32, source_hash: Option<Cow<'a, str>> },
 
     /// Represents an error on the event processing
     EventModelViolation { quake3_event_id: u32, violation: EventModelViolations<'a> },
@@ -77,6 +86,10 @@ impl LogicEvents<'_> {
             LogicEvents::MeanOfDeath { quake3_event_id, .. } |
             LogicEvents::IncFrags            { quake3_event_id, .. } |
             LogicEvents::DecFrags            { quake3_event_id, .. } |
+            LogicEvents::KillTime            { quake3_event_id, .. } |
+            LogicEvents::RawKill             { quake3_event_id, .. } |
+            LogicEvents::ItemPickup          { quake3_event_id, .. } |
+            LogicEvents::ChatMessage         { quake3_event_id, .. } |
             LogicEvents::ReportedScore       { quake3_event_id, .. } |
             LogicEvents::GameEndedGracefully { quake3_event_id, .. } |
             LogicEvents::GameEndedManually   { quake3_event_id, .. } |
@@ -99,10 +112,17 @@ pub enum EventModelViolations<'a> {
         id: u32,
         name: Cow<'a, str>,
     },
-    /// Occurs when some game events report a name for a player, but others report other -- before a [Quake3Events::ClientUserinfoChanged] in between them
-    DiscrepantPlayerName {
+    /// Occurs when a [Quake3Events::Kill] event states a killer or victim name that differs from the one currently resolved for its numeric id
+    KillIdNameMismatch {
         id: u32,
-        local_name: Cow<'a, str>,
-        game_name: Cow<'a, str>,
-    }
+        expected_name: Cow<'a, str>,
+        actual_name: Cow<'a, str>,
+    },
+    /// Occurs when an event's log time is earlier than the previous one's, within the same game -- indicating a corrupt or concatenated log
+    NonMonotonicTime {
+        previous: u32,
+        current: u32,
+    },
+    /// Occurs when a second [Quake3Events::Exit] event is received before the game's [Quake3Events::ShutdownGame]
+    DoubleExit,
 }
\ No newline at end of file
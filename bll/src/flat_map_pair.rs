@@ -0,0 +1,101 @@
+//! Resting place for the [FlatMapPair] `Stream` combinator
+
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{Stream, ready};
+
+
+/// `Stream` combinator mapping each item into up to two ones -- for the logic processors that add events to the pipeline
+/// without consuming the ones they inspect.\
+/// It gives out the same items as `.map(f).flat_map(stream::iter).filter_map(future::ready)` would, but without creating
+/// & driving a sub-stream per item: the second item, if any, is just held until the next poll.\
+/// The inner `Stream` is boxed once, upon creation, so it needs not be `Unpin`.
+pub(crate) struct FlatMapPair<InnerStream, MapFn, Item> {
+    stream: Pin<Box<InnerStream>>,
+    map_fn: MapFn,
+    /// the second item produced for the last inner item, yet to be given out
+    pending: Option<Item>,
+}
+
+impl<InnerStream, MapFn, Item> FlatMapPair<InnerStream, MapFn, Item>
+where InnerStream: Stream,
+      MapFn: FnMut(InnerStream::Item) -> [Option<Item>; 2] + Unpin {
+
+    pub(crate) fn new(stream: InnerStream, map_fn: MapFn) -> Self {
+        Self {
+            stream: Box::pin(stream),
+            map_fn,
+            pending: None,
+        }
+    }
+
+}
+
+impl<InnerStream, MapFn, Item> Stream for FlatMapPair<InnerStream, MapFn, Item>
+where InnerStream: Stream,
+      MapFn: FnMut(InnerStream::Item) -> [Option<Item>; 2] + Unpin,
+      Item: Unpin {
+
+    type Item = Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Item>> {
+        let this = self.get_mut();
+        if let Some(pending) = this.pending.take() {
+            return Poll::Ready(Some(pending))
+        }
+        loop {
+            let Some(inner_item) = ready!(this.stream.as_mut().poll_next(cx))
+                else {
+                    return Poll::Ready(None)
+                };
+            match (this.map_fn)(inner_item) {
+                [Some(first), second] => {
+                    this.pending = second;
+                    return Poll::Ready(Some(first))
+                },
+                [None, Some(second)] => return Poll::Ready(Some(second)),
+                [None, None] => continue,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.is_some() as usize;
+        let (_lower, upper) = self.stream.size_hint();
+        (pending, upper.and_then(|upper| upper.checked_mul(2)).and_then(|upper| upper.checked_add(pending)))
+    }
+
+}
+
+
+/// Unit tests the [flat_map_pair](super) combinator
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+    use std::future;
+
+
+    /// Tests that the same items of the equivalent `flat_map()` formulation are given out, in the same order
+    #[test]
+    fn same_as_flat_map() {
+        let map_fn = |number: u32| match number % 4 {
+            0 => [None, None],
+            1 => [Some(number), None],
+            2 => [None, Some(number)],
+            _ => [Some(number), Some(number * 100)],
+        };
+        let expected = futures::executor::block_on_stream(stream::iter(0..20)
+            .map(map_fn)
+            .flat_map(stream::iter)
+            .filter_map(future::ready))
+            .collect::<Vec<_>>();
+        let observed = futures::executor::block_on_stream(FlatMapPair::new(stream::iter(0..20), map_fn))
+            .collect::<Vec<_>>();
+        assert_eq!(observed, expected, "`FlatMapPair` diverged from the `flat_map()` formulation");
+    }
+
+}
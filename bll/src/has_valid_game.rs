@@ -0,0 +1,75 @@
+//! Contains [has_valid_game()], a fast check for logs -- suitable for CI validations
+
+use crate::SummaryLogic;
+use bll_api::{Config, SummaryLogicApi};
+use common::types::Result;
+use dal_api::Quake3ServerEvents;
+use std::sync::Arc;
+
+
+/// Returns whether `log_dao` produces, with the given `config`, at least one valid [model::report::GameMatchSummary].\
+/// The events are only consumed up to the end of the first valid game -- the rest of the log is left unread.
+pub fn has_valid_game<IntoArcConfig: Into<Arc<Config>>>(config: IntoArcConfig, log_dao: Box<dyn Quake3ServerEvents>) -> Result<bool> {
+    let summaries_stream = SummaryLogic::new(config).summarize_games(log_dao)
+        .map_err(|err| format!("has_valid_game(): failed at summarizing the games: {err}"))?;
+    Ok(futures::executor::block_on_stream(summaries_stream)
+        .any(|summary_result| summary_result.is_ok()))
+}
+
+
+/// Unit tests the [has_valid_game](super) module
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bll_api::EventAnalyserOperations;
+    use dal::sync_reader::Quake3LogSyncReader;
+    use std::{
+        collections::HashSet,
+        io::{BufRead, Cursor, Read},
+    };
+
+
+    /// Tests that the answer comes right after the first valid game, without reading the remaining of the log
+    #[test]
+    fn first_game_is_valid() {
+        let first_game = "  0:00 InitGame: \\fraglimit\\20\\timelimit\\15\n  0:01 Kill: 1022 2 22: <world> killed Isgalamido by MOD_TRIGGER_HURT\n  0:02 ShutdownGame:\n";
+        let log = Cursor::new(first_game.as_bytes()).chain(UnreadableRemaining);
+        let log_dao = Quake3LogSyncReader::new(Arc::new(dal_api::Config::default()), "<test>", log, 0);
+        let has_valid_game = has_valid_game(config(), log_dao).expect("has_valid_game() shouldn't fail here");
+        assert!(has_valid_game, "The first game should have been considered valid");
+    }
+
+    /// Tests that logs without any complete game are reported as such
+    #[test]
+    fn no_valid_games() {
+        let log = "  0:00 InitGame: \\fraglimit\\20\\timelimit\\15\n  0:01 Kill: 1022 2 22: <world> killed Isgalamido by MOD_TRIGGER_HURT\n";
+        let log_dao = Quake3LogSyncReader::new(Arc::new(dal_api::Config::default()), "<test>", Cursor::new(log.as_bytes()), 0);
+        let has_valid_game = has_valid_game(config(), log_dao).expect("has_valid_game() shouldn't fail here");
+        assert!(!has_valid_game, "An unfinished game should not be considered valid");
+    }
+
+
+    fn config() -> Arc<Config> {
+        Arc::new(Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        })
+    }
+
+    /// Stands for the remaining of a big log file -- which must not be read
+    struct UnreadableRemaining;
+    impl Read for UnreadableRemaining {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            panic!("The log was read past the first valid game")
+        }
+    }
+    impl BufRead for UnreadableRemaining {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            panic!("The log was read past the first valid game")
+        }
+        fn consume(&mut self, _amt: usize) {}
+    }
+
+}
@@ -3,4 +3,21 @@
 mod summary_logic;
 pub use summary_logic::*;
 
+mod self_test;
+pub use self_test::{self_test, SELF_TEST_LOG, SELF_TEST_SOURCE};
+
+mod flat_map_pair;
+
+mod checkpoint;
+pub use checkpoint::{load_checkpoint, save_checkpoint, Checkpoint};
+
+mod has_valid_game;
+pub use has_valid_game::has_valid_game;
+
+mod player_career;
+pub use player_career::summarize_player;
+
+mod weapon_meta;
+pub use weapon_meta::weapon_meta;
+
 pub mod dtos;
\ No newline at end of file
@@ -0,0 +1,92 @@
+//! Contains [summarize_player()], a focused aggregation of a single player's stats across all games
+
+use crate::summary_logic::is_frag;
+use bll_api::Config;
+use common::types::Result;
+use dal_api::Quake3ServerEvents;
+use model::{
+    quake3_events::Quake3Events,
+    report::PlayerCareerStats,
+};
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+};
+
+
+/// Accumulates the [PlayerCareerStats] of `player_name` across all games yielded by `log_dao`.\
+/// Only the events concerning the player are taken into account -- no per-game summaries are built for anyone else.
+/// Feed errors are skipped, unless [Config::stop_on_feed_errors] is set -- in which case the first one is returned.
+pub fn summarize_player<IntoArcConfig: Into<Arc<Config>>>(config: IntoArcConfig, log_dao: Box<dyn Quake3ServerEvents>, player_name: &str) -> Result<PlayerCareerStats> {
+    let config = config.into();
+    let events_stream = log_dao.events_stream()
+        .map_err(|err| format!("summarize_player(): failed at fetching the Quake 3 Server events `Stream`: {err}"))?;
+
+    let mut career_stats = PlayerCareerStats { player_name: player_name.to_owned(), ..PlayerCareerStats::default() };
+    let mut weapons = BTreeMap::<String, u32>::new();
+    // the 1-based index of the current game & of the last one the player was counted in
+    let mut game_index = 0;
+    let mut counted_game_index = 0;
+    for event in futures::executor::block_on_stream(events_stream) {
+        let took_part = match &event {
+            Quake3Events::InitGame { .. } => {
+                game_index += 1;
+                false
+            },
+            Quake3Events::ClientUserinfoChanged { name, .. } => name == player_name,
+            Quake3Events::Kill { killer_id, victim_id, killer_name, victim_name, reason_name, .. } => {
+                if killer_name == player_name && is_frag(config.self_kill_by, *killer_id, *victim_id, killer_name, victim_name) {
+                    career_stats.kills += 1;
+                    *weapons.entry(reason_name.to_string()).or_default() += 1;
+                }
+                if victim_name == player_name {
+                    career_stats.deaths += 1;
+                }
+                killer_name == player_name || victim_name == player_name
+            },
+            Quake3Events::Error { event_id, err } if config.stop_on_feed_errors =>
+                return Err(Box::from(format!("summarize_player(): Event #{event_id}: Feed error: {err}"))),
+            _ => false,
+        };
+        if took_part && counted_game_index != game_index {
+            counted_game_index = game_index;
+            career_stats.games_played += 1;
+        }
+    }
+    // `max_by_key()` keeps the last of the maximums, so we iterate backwards to favour the first name
+    career_stats.favorite_weapon = weapons.into_iter().rev()
+        .max_by_key(|(_weapon, kills)| *kills)
+        .map(|(weapon, _kills)| weapon);
+    Ok(career_stats)
+}
+
+
+/// Unit tests the [player_career](super) module
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dal_api::FileReaderInfo;
+    use dal::sync_file_reader::Quake3LogFileSyncReader;
+    use std::borrow::Cow;
+
+
+    /// A log file with all lines OK -- 20 games
+    const PEDANTIC_LOG_FILE_LOCATION: &str = "tests/resources/qgames_pedantic.log";
+
+
+    /// Tests Isgalamido's totals across all games of the pedantic log
+    #[test]
+    fn isgalamido_career() {
+        let log_dao = Quake3LogFileSyncReader::new(Arc::new(dal_api::Config::default()), FileReaderInfo { log_file_path: Cow::Borrowed(PEDANTIC_LOG_FILE_LOCATION) });
+        let career_stats = summarize_player(Config::default(), log_dao, "Isgalamido")
+            .expect("summarize_player() shouldn't fail here");
+        assert_eq!(career_stats, PlayerCareerStats {
+            player_name: "Isgalamido".to_owned(),
+            games_played: 20,
+            kills: 177,
+            deaths: 143,
+            favorite_weapon: Some("MOD_RAILGUN".to_owned()),
+        }, "Wrong career stats");
+    }
+
+}
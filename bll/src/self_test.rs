@@ -0,0 +1,112 @@
+//! Contains [self_test()], a smoke test to be run by production binaries for deployment verification
+
+use crate::SummaryLogic;
+use bll_api::{Config, EventAnalyserOperations, SummaryLogicApi};
+use common::types::Result;
+use model::report::{GameMatchSummary, IndexMap, IndexSet};
+use dal_api::Quake3ServerEvents;
+use std::collections::{BTreeMap, HashSet};
+
+
+/// The name the self test log should be read under -- showing up as the summary's `source`
+pub const SELF_TEST_SOURCE: &str = "<self-test>";
+
+/// A tiny, but complete, Quake 3 server log -- with a single game
+pub const SELF_TEST_LOG: &str = r#"  0:00 ------------------------------------------------------------
+  0:00 InitGame: \sv_floodProtect\1\fraglimit\20\timelimit\15\capturelimit\8\mapname\q3dm17
+  0:01 ClientConnect: 2
+  0:01 ClientUserinfoChanged: 2 n\Isgalamido\t\0\model\uriel/zael
+  0:02 ClientConnect: 3
+  0:02 ClientUserinfoChanged: 3 n\Zeh\t\0\model\sarge/default
+  1:00 Kill: 2 3 7: Isgalamido killed Zeh by MOD_ROCKET_SPLASH
+  2:00 Kill: 1022 2 22: <world> killed Isgalamido by MOD_TRIGGER_HURT
+  3:00 Kill: 2 3 7: Isgalamido killed Zeh by MOD_ROCKET_SPLASH
+  3:30 Exit: Fraglimit hit.
+  3:30 score: 1  ping: 4  client: 2 Isgalamido
+  3:30 score: 0  ping: 8  client: 3 Zeh
+  3:31 ShutdownGame:
+  3:31 ------------------------------------------------------------
+"#;
+
+/// Runs the events of `log_dao` -- which should be a real DAL reader, built by the caller, reading [SELF_TEST_LOG]
+/// under the name [SELF_TEST_SOURCE] -- through the full, pedantic, logic pipeline, returning `Ok` if the produced summary
+/// is the expected one -- meaning this crate (and the given reader) is functioning
+pub fn self_test(log_dao: Box<dyn Quake3ServerEvents>) -> Result<()> {
+    let logic_config = Config {
+        stop_on_feed_errors: true,
+        stop_on_event_model_violations: true,
+        processor_pipeline: HashSet::from([
+            EventAnalyserOperations::MeansOfDeath,
+            EventAnalyserOperations::KillTimeline,
+            EventAnalyserOperations::Kills,
+            EventAnalyserOperations::PlayerIdsAndNickNamesResolutions,
+            EventAnalyserOperations::GameReportedScores,
+        ]),
+        ..Config::default()
+    };
+    let summaries_stream = SummaryLogic::new(logic_config).summarize_games(log_dao)
+        .map_err(|err| format!("self_test(): failed at summarizing the games: {err}"))?;
+    let summaries = futures::executor::block_on_stream(summaries_stream)
+        .collect::<Result<Vec<GameMatchSummary>>>()
+        .map_err(|err| format!("self_test(): the summaries `Stream` yielded an error: {err}"))?;
+
+    let expected_summaries = vec![
+        GameMatchSummary {
+            total_kills: 3,
+            players: IndexSet::from([
+                "Isgalamido".to_owned(),
+                "Zeh".to_owned(),
+            ]),
+            kills: IndexMap::from([
+                ("Isgalamido".to_owned(), 1),
+            ]),
+            total_participants: 2,
+            map_name: Some("q3dm17".to_owned()),
+            means_of_death: Some(BTreeMap::from([
+                ("MOD_ROCKET_SPLASH".to_owned(), 2),
+                ("MOD_TRIGGER_HURT".to_owned(), 1),
+            ])),
+            game_reported_scores: Some(BTreeMap::from([
+                ("Isgalamido".to_owned(), 1),
+                ("Zeh".to_owned(), 0),
+            ])),
+            avg_frag_gap_minutes: Some(BTreeMap::from([
+                ("Isgalamido".to_owned(), 2.0),
+            ])),
+            busiest_minute: Some((1, 1)),
+            source: Some(SELF_TEST_SOURCE.to_owned()),
+            longest_drought_minutes: Some(1),
+            sessions: Some(BTreeMap::from([
+                ("Isgalamido".to_owned(), vec![(0, None)]),
+                ("Zeh".to_owned(), vec![(0, None)]),
+            ])),
+            ..Default::default()
+        },
+    ];
+    if summaries == expected_summaries {
+        Ok(())
+    } else {
+        Err(Box::from(format!("self_test(): unexpected summaries -- expected {expected_summaries:?}, got {summaries:?}")))
+    }
+}
+
+
+/// Unit tests the [self_test](super) module
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dal::sync_reader::Quake3LogSyncReader;
+    use std::{io::Cursor, sync::Arc};
+
+    #[test]
+    fn self_test_passes() {
+        let dal_config = Arc::new(dal_api::Config {
+            validate_time_ranges: true,
+            ..dal_api::Config::default()
+        });
+        let log_dao = Quake3LogSyncReader::new(dal_config, SELF_TEST_SOURCE, Cursor::new(SELF_TEST_LOG.as_bytes()), 0);
+        let result = self_test(log_dao);
+        assert!(result.is_ok(), "self_test() failed: {}", result.unwrap_err());
+    }
+
+}
@@ -2,60 +2,175 @@
 //!
 //! See [SummaryLogic]
 
-use crate::dtos::{LogicEvents, CompositeEvent, EventModelViolations};
-use bll_api::{Config, EventAnalyserOperations, SummaryLogicApi};
+use crate::{
+    checkpoint::{load_checkpoint, save_checkpoint, Checkpoint, ResumedEvents},
+    flat_map_pair::FlatMapPair,
+    dtos::{LogicEvents, CompositeEvent, EventModelViolations},
+};
+use bll_api::{Config, EventAnalyserOperations, SelfKillKey, SummaryLogicApi, Warning};
 use common::types::Result;
 use model::{
     quake3_events::Quake3Events,
-    report::{GameMatchSummary, GamesSummary},
+    report::{GameMatchSummary, GamesSummary, IndexMap, IndexSet, KillDistribution},
 };
 use dal_api::Quake3ServerEvents;
 use std::{
     borrow::Cow,
     collections::{
         BTreeMap,
-        BTreeSet,
         HashMap,
         HashSet,
     },
-    future,
-    sync::Arc,
+    future::{self, Future},
+    path::PathBuf,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex, PoisonError,
+    },
 };
 use futures::{Stream, stream, StreamExt};
-use log::warn;
+use log::{debug, warn};
 
 
+/// The [CompositeEvent]s stream, as chained by the optional stages of [Config::processor_pipeline]
+type CompositeEvents<'a> = Pin<Box<dyn Stream<Item=CompositeEvent<'a>> + 'a>>;
+
 /// Here you'll find an event-based, decoupled and zero-cost-abstraction strategy for applying business logic rules & requisites:
 ///   1) [Quake3Events] events come in in a `Stream` and [GameMatchSummary] events go out, also in a `Stream` -- able to process data regardless of their size;
 ///   2) Logic processors can be enabled / disabled by adding `Stream` operations -- "only pay for what you use"
 ///   3) The `Stream` operations are nicely packed into their own functions, enabling an easy selection through [Config::processor_pipeline]
 pub struct SummaryLogic {
     config: Arc<Config>,
+    warnings: WarningsSink,
 }
 
 impl SummaryLogicApi for SummaryLogic {
 
     fn new<IntoArcConfig: Into<Arc<Config>>>(config: IntoArcConfig) -> Self {
+        let config = config.into();
         Self {
-            config: config.into(),
+            warnings: WarningsSink { log: config.log_issues, collected: None },
+            config,
         }
     }
 
+    fn summarize_games_with_warnings(&self, log_dao: Box<dyn Quake3ServerEvents>) -> Result<(GamesSummary, Arc<Mutex<Vec<Warning>>>)> {
+        let collected = Arc::new(Mutex::new(Vec::new()));
+        let collecting_logic = Self {
+            config: self.config.clone(),
+            warnings: WarningsSink { log: self.config.log_issues, collected: Some(collected.clone()) },
+        };
+        let games_summary = collecting_logic.summarize_games(log_dao)?;
+        Ok((games_summary, collected))
+    }
+
     fn summarize_games(&self, log_dao: Box<dyn Quake3ServerEvents>) -> Result<GamesSummary> {
         let config = &self.config;
+        // the checkpoint to resume from -- along with where the feed is at, to be saved in the next ones
+        let checkpoint = config.checkpoint_path.as_ref()
+            .map(|checkpoint_path| load_checkpoint(checkpoint_path).map(|checkpoint| (checkpoint_path.to_owned(), checkpoint, Arc::new(AtomicU32::new(0)))))
+            .transpose()?;
+        let log_dao = match &checkpoint {
+            Some((_checkpoint_path, checkpoint, consumed_event_id)) =>
+                Box::new(ResumedEvents { log_dao, last_event_id: checkpoint.last_event_id, consumed_event_id: Arc::clone(consumed_event_id) }),
+            None => log_dao,
+        };
         let stream = self.compose(log_dao)?;
-        if config.processor_pipeline == HashSet::from([EventAnalyserOperations::Kills]) {
-            Ok(Box::pin(self.summarize(self.kills(stream))))
-        } else if config.processor_pipeline == HashSet::from([EventAnalyserOperations::Kills, EventAnalyserOperations::PlayerIdsAndNickNamesResolutions, EventAnalyserOperations::GameReportedScores]) {
-            Ok(Box::pin(self.summarize(self.game_reported_scores(self.player_ids_and_nicknames_resolutions(self.kills(stream))))))
-        } else if config.processor_pipeline == HashSet::from([EventAnalyserOperations::MeansOfDeath, EventAnalyserOperations::Kills, EventAnalyserOperations::PlayerIdsAndNickNamesResolutions, EventAnalyserOperations::GameReportedScores]) {
-            Ok(Box::pin(self.summarize(self.game_reported_scores(self.player_ids_and_nicknames_resolutions(self.kills(self.means_of_death(stream)))))))
-        } else if config.processor_pipeline == HashSet::from([EventAnalyserOperations::Kills, EventAnalyserOperations::PlayerIdsAndNickNamesResolutions]) {
-            Ok(Box::pin(self.summarize(self.player_ids_and_nicknames_resolutions(self.kills(stream)))))
-        } else if config.processor_pipeline == HashSet::from([EventAnalyserOperations::Kills, EventAnalyserOperations::GameReportedScores]) {
-            Ok(Box::pin(self.summarize(self.game_reported_scores(self.kills(stream)))))
+        // the names of the composed stages, in order -- see [Config::log_pipeline_composition]
+        let mut stages = vec!["compose"];
+        if !config.processor_pipeline.contains(&EventAnalyserOperations::Kills) {
+            return Err(Box::from(format!("Summary Logic: Unknown combination of logic operations for the `config.processor_pipeline` of {:?} -- `Kills` is required", config.processor_pipeline)))
+        }
+        // the optional stages are chained in a fixed order, regardless of how the set was built
+        let has = |operation| config.processor_pipeline.contains(&operation);
+        let mut stream: CompositeEvents = Box::pin(stream);
+        if has(EventAnalyserOperations::MeansOfDeath) {
+            stages.push("means_of_death");
+            stream = Box::pin(self.means_of_death(stream));
+        }
+        if has(EventAnalyserOperations::KillTimeline) {
+            stages.push("kill_timeline");
+            stream = Box::pin(self.kill_timeline(stream));
+        }
+        if has(EventAnalyserOperations::KillLog) {
+            stages.push("kill_log");
+            stream = Box::pin(self.kill_log(stream));
+        }
+        if has(EventAnalyserOperations::ItemActivity) {
+            stages.push("item_activity");
+            stream = Box::pin(self.item_activity(stream));
+        }
+        if has(EventAnalyserOperations::Chat) {
+            stages.push("chat");
+            stream = Box::pin(self.chat(stream));
+        }
+        stages.push("kills");
+        stream = Box::pin(self.kills(stream));
+        if has(EventAnalyserOperations::PlayerIdsAndNickNamesResolutions) {
+            stages.push("player_ids_and_nicknames_resolutions");
+            stream = Box::pin(self.player_ids_and_nicknames_resolutions(stream));
+        }
+        if has(EventAnalyserOperations::GameReportedScores) {
+            stages.push("game_reported_scores");
+            stream = Box::pin(self.game_reported_scores(stream));
+        }
+        let games_summary: GamesSummary = Box::pin(self.summarize(stream));
+        stages.push("summarize");
+        let games_summary: GamesSummary = if let Some(max_total_games) = config.max_total_games {
+            stages.push("reject_excess_games");
+            Box::pin(self.reject_excess_games(games_summary, max_total_games))
+        } else {
+            games_summary
+        };
+        let games_summary: GamesSummary = if let Some(min_players) = config.min_players {
+            stages.push("drop_small_games");
+            Box::pin(self.drop_small_games(games_summary, min_players))
+        } else {
+            games_summary
+        };
+        let games_summary: GamesSummary = if config.group_rematches {
+            stages.push("group_rematches");
+            Box::pin(self.group_rematches(games_summary))
+        } else {
+            games_summary
+        };
+        let games_summary: GamesSummary = if let Some((checkpoint_path, checkpoint, consumed_event_id)) = checkpoint {
+            stages.push("checkpoint");
+            Box::pin(self.checkpoint(games_summary, checkpoint_path, checkpoint, consumed_event_id))
         } else {
-            Err(Box::from(format!("Summary Logic: Unknown combination of logic operations for the `config.processor_pipeline` of {:?}", config.processor_pipeline)))
+            games_summary
+        };
+        if config.log_pipeline_composition {
+            debug!("Summary Logic: composed pipeline: {}", stages.join(" -> "));
+        }
+        Ok(games_summary)
+    }
+
+    fn summarize_games_to_sink<Sink, SinkFuture>(&self, log_dao: Box<dyn Quake3ServerEvents>, mut sink: Sink) -> impl Future<Output=Result<()>>
+                                                 where Sink: FnMut(u32, GameMatchSummary) -> SinkFuture,
+                                                       SinkFuture: Future<Output=Result<()>> {
+        let games_summary = self.summarize_games(log_dao);
+        let stop_on_errors = self.config.stop_sink_on_errors;
+        let warnings = self.warnings.clone();
+        async move {
+            let mut games_summary = games_summary?;
+            let mut game_id = 0;
+            while let Some(summary_result) = games_summary.next().await {
+                game_id += 1;
+                let summary = match summary_result {
+                    Ok(summary) => summary,
+                    Err(summary_err) if stop_on_errors =>
+                        return Err(Box::from(format!("Summary Logic: Error while summarizing game_id {game_id}: {summary_err}"))),
+                    Err(summary_err) => {
+                        warnings.report(Warning::SkippedGame { game_index: game_id, map_name: None, reason: summary_err.to_string() });
+                        continue
+                    },
+                };
+                sink(game_id, summary).await
+                    .map_err(|sink_err| format!("Summary Logic: the sink failed to take game_id {game_id}: {sink_err}"))?;
+            }
+            Ok(())
         }
     }
 
@@ -68,85 +183,168 @@ impl SummaryLogic {
     /// The workings of the processing pipelines are as follows:
     ///   1. `Stream` of [Quake3Events], then
     ///   2. [compose()], then
-    ///   3. many pipeline processing functions, such as [means_of_death()], [kills()], [player_ids_and_nicknames_resolutions()] and [game_reported_scores()] -- then
+    ///   3. many pipeline processing functions, such as [means_of_death()], [kill_timeline()], [kill_log()], [kills()], [player_ids_and_nicknames_resolutions()], [game_reported_scores()], [item_activity()] and [chat()] -- then
     ///   4.  [summarize()], then
     ///   5. `Stream` of [GameMatchSummary]
     fn compose<'a>(&self, log_dao: Box<dyn Quake3ServerEvents>) -> Result<impl Stream<Item=CompositeEvent<'a>>> {
 
-        let config = self.config.clone();
+        let warnings = self.warnings.clone();
 
         let stream = log_dao.events_stream()
             .map_err(|err| format!("compose(): failed at fetching the Quake 3 Server events `Stream`: {err}"))?;
 
         let stream = stream
-            .inspect(move |quake3_event| if config.log_issues {
-                if let Quake3Events::Error {event_id, err} = quake3_event {
-                    warn!("Failed to process Quake 3 Server event #{event_id}: {err}");
-                }
+            .inspect(move |quake3_event| if let Quake3Events::Error {event_id, err} = quake3_event {
+                warnings.report(Warning::FeedError { event_id: *event_id, description: err.to_string() });
             });
 
         let mut in_game = false;
         let mut graceful_game_end = false;
+        // a repeated `Exit` is only a violation if pedantic
+        let report_double_exits = self.config.stop_on_event_model_violations;
+        // the log time of the last event of the current game -- tracked if it is to be checked
+        let check_time_monotonicity = self.config.stop_on_event_model_violations;
+        let mut last_time_secs = None;
+        let hash_source_lines = self.config.hash_source_lines;
+
+        // a backwards time is reported before -- and in addition to -- its event, which is still processed: so a bad
+        // `ShutdownGame` time doesn't prevent the game from ending
+        let stream = FlatMapPair::new(stream, move |quake3_event| {
+            let time_violation = (check_time_monotonicity && in_game)
+                .then(|| quake3_event.time_secs()).flatten()
+                .and_then(|current| last_time_secs.replace(current)
+                    .filter(|previous| current < *previous)
+                    .map(|previous| CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: quake3_event.event_id(), violation: EventModelViolations::NonMonotonicTime { previous, current } })));
+            let composite_event = match &quake3_event {
+
+                Quake3Events::InitGame { event_id, time_secs, map_name, source } => {
+                    if in_game {
+                        Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::DoubleInit}))
+                    } else {
+                        in_game = true;
+                        graceful_game_end = false;
+                        last_time_secs = *time_secs;
+                        Some(CompositeEvent::LogicEvent(LogicEvents::NewGame { quake3_event_id: *event_id, map_name: map_name.clone(), source: source.clone() }))
+                    }
+                },
+
+                Quake3Events::Exit { event_id, time_secs: _ } => {
+                    if in_game {
+                        let double_exit = graceful_game_end && report_double_exits;
+                        graceful_game_end = true;
+                        double_exit.then(|| CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::DoubleExit }))
+                    } else {
+                        Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::GameNotStarted }))
+                    }
+                }
 
-        let stream = stream
-            .map(move |quake3_event| {
-                match &quake3_event {
-
-                    Quake3Events::InitGame { event_id } => {
-                        if in_game {
-                            Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::DoubleInit}))
-                        } else {
-                            in_game = true;
-                            graceful_game_end = false;
-                            Some(CompositeEvent::LogicEvent(LogicEvents::NewGame { quake3_event_id: *event_id }))
-                        }
-                    },
-
-                    Quake3Events::Exit { event_id } => {
-                        if in_game {
-                            graceful_game_end = true;
-                            None
+                Quake3Events::ShutdownGame { event_id, time_secs: _, source_hash } => {
+                    if in_game {
+                        in_game = false;
+                        let source_hash = source_hash.clone().filter(|_| hash_source_lines);
+                        if graceful_game_end {
+                            Some(CompositeEvent::LogicEvent(LogicEvents::GameEndedGracefully { quake3_event_id: *event_id, source_hash }))
                         } else {
-                            Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::GameNotStarted }))
+                            Some(CompositeEvent::LogicEvent(LogicEvents::GameEndedManually { quake3_event_id: *event_id, source_hash }))
                         }
+                    } else {
+                        Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::GameNotStarted }))
                     }
+                },
+
+                Quake3Events::Error { event_id, err } => {
+                    // upgrades the error description
+                    let err = Box::from(format!("Event #{event_id}: Feed error: {err}"));
+                    Some(CompositeEvent::GameEvent(Quake3Events::Error { event_id: *event_id, err }))
+                },
+
+                _ => Some(CompositeEvent::GameEvent(quake3_event))
+            };
+            [time_violation, composite_event]
+        });
+        Ok(stream)
 
-                    Quake3Events::ShutdownGame { event_id } => {
-                        if in_game {
-                            in_game = false;
-                            if graceful_game_end {
-                                Some(CompositeEvent::LogicEvent(LogicEvents::GameEndedGracefully { quake3_event_id: *event_id }))
-                            } else {
-                                Some(CompositeEvent::LogicEvent(LogicEvents::GameEndedManually { quake3_event_id: *event_id }))
-                            }
-                        } else {
-                            Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::GameNotStarted }))
-                        }
-                    },
+    }
+
+    /// Logic for extracting the death causes statistics from the [Quake3Events::Kill] events -- empty reasons being
+    /// taken as [Config::unknown_means_label].\
+    /// Must be used before [kills()], because (unlike the mentioned processor), the one here does not consume
+    /// the [Quake3Events::Kill] events.
+    fn means_of_death<'a>(&self, stream: impl Stream<Item=CompositeEvent<'a>>) -> impl Stream<Item=CompositeEvent<'a>> {
+
+        let unknown_means_label = self.config.unknown_means_label.clone();
+
+        // kill-heavy logs make this hot: `FlatMapPair` spares a sub-stream per event -- see `benches/means_of_death.rs`
+        FlatMapPair::new(stream, move |composite_event| {
+
+            // game events -- inspect Quake3 `Kill` events, producing additional `LogicEvent::MeanOfDeath` ones
+            let CompositeEvent::GameEvent(ref game_event) = composite_event
+                else {
+                    return [Some(composite_event), None]
+                };
+
+            match game_event {
+
+                Quake3Events::Kill { event_id, time_secs: _, killer_id: _, victim_id: _, reason_id: _, killer_name: _, victim_name: _, reason_name } =>
+                    [
+                        Some(CompositeEvent::LogicEvent(LogicEvents::MeanOfDeath {
+                            quake3_event_id: *event_id,
+                            mean_of_death: if reason_name.is_empty() { Cow::Owned(unknown_means_label.clone()) } else { reason_name.clone() },
+                        })),
+                        // doesn't consume the Kill event
+                        Some(composite_event)
+                    ],
+
+                _ => [Some(composite_event), None]
+            }
+        })
+
+    }
+
+    /// Logic for tracking the log time of the [Quake3Events::Kill] events, producing [LogicEvents::KillTime] for the ones with a known time.\
+    /// Like [means_of_death()], must be used before [kills()], as it does not consume the [Quake3Events::Kill] events.
+    fn kill_timeline<'a>(&self, stream: impl Stream<Item=CompositeEvent<'a>>) -> impl Stream<Item=CompositeEvent<'a>> {
+
+        let self_kill_by = self.config.self_kill_by;
+        let empty_name_label = self.config.empty_name_label.clone();
+
+        stream
+            .map(move |composite_event| {
+
+                // game events -- inspect Quake3 `Kill` events, producing additional `LogicEvent::KillTime` ones
+                let CompositeEvent::GameEvent(ref game_event) = composite_event
+                    else {
+                        return [Some(composite_event), None]
+                    };
+
+                match game_event {
 
-                    Quake3Events::Error { event_id, err } => {
-                        // upgrades the error description
-                        let err = Box::from(format!("Event #{event_id}: Feed error: {err}"));
-                        Some(CompositeEvent::GameEvent(Quake3Events::Error { event_id: *event_id, err }))
+                    Quake3Events::Kill { event_id, time_secs: Some(time_secs), killer_id, victim_id, reason_id: _, killer_name, victim_name, reason_name: _ } => {
+                        let fragger = is_frag(self_kill_by, *killer_id, *victim_id, killer_name, victim_name)
+                            .then(|| labeled_name(killer_name, &empty_name_label));
+                        [
+                            Some(CompositeEvent::LogicEvent(LogicEvents::KillTime { quake3_event_id: *event_id, time_secs: *time_secs, fragger })),
+                            // doesn't consume the Kill event
+                            Some(composite_event)
+                        ]
                     },
 
-                    _ => Some(CompositeEvent::GameEvent(quake3_event))
+                    _ => [Some(composite_event), None]
                 }
             })
-            .filter_map(|composite_event_option| future::ready(composite_event_option));
-        Ok(stream)
+            .flat_map(stream::iter)
+            .filter_map(future::ready)
 
     }
 
-    /// Logic for extracting the death causes statistics from the [Quake3Events::Kill] events.\
-    /// Must be used before [kills()], because (unlike the mentioned processor), the one here does not consume
-    /// the [Quake3Events::Kill] events.
-    fn means_of_death<'a>(&self, stream: impl Stream<Item=CompositeEvent<'a>>) -> impl Stream<Item=CompositeEvent<'a>> {
+    /// Logic for echoing the [Quake3Events::Kill] events as [LogicEvents::RawKill], for auditing purposes.\
+    /// Like [means_of_death()], must be used before [kills()], as it does not consume the [Quake3Events::Kill] events.
+    fn kill_log<'a>(&self, stream: impl Stream<Item=CompositeEvent<'a>>) -> impl Stream<Item=CompositeEvent<'a>> {
 
         stream
             .map(|composite_event| {
 
-                // game events -- inspect Quake3 `Kill` events, producing additional `LogicEvent::MeanOfDeath` ones
+                // game events -- inspect Quake3 `Kill` events, producing additional `LogicEvent::RawKill` ones
                 let CompositeEvent::GameEvent(ref game_event) = composite_event
                     else {
                         return [Some(composite_event), None]
@@ -154,9 +352,9 @@ impl SummaryLogic {
 
                 match game_event {
 
-                    Quake3Events::Kill { event_id, killer_id: _, victim_id: _, reason_id: _, killer_name: _, victim_name: _, reason_name } =>
+                    Quake3Events::Kill { event_id, time_secs: _, killer_id: _, victim_id: _, reason_id: _, killer_name, victim_name, reason_name } =>
                         [
-                            Some(CompositeEvent::LogicEvent(LogicEvents::MeanOfDeath { quake3_event_id: *event_id, mean_of_death: reason_name.to_owned() })),
+                            Some(CompositeEvent::LogicEvent(LogicEvents::RawKill { quake3_event_id: *event_id, killer_name: killer_name.clone(), victim_name: victim_name.clone(), reason_name: reason_name.clone() })),
                             // doesn't consume the Kill event
                             Some(composite_event)
                         ],
@@ -164,8 +362,8 @@ impl SummaryLogic {
                     _ => [Some(composite_event), None]
                 }
             })
-            .flat_map(|multiple_events| stream::iter(multiple_events))
-            .filter_map(|composite_event_option| future::ready(composite_event_option))
+            .flat_map(stream::iter)
+            .filter_map(future::ready)
 
     }
 
@@ -173,31 +371,38 @@ impl SummaryLogic {
     /// according to the "frags rules":
     ///   1) killers get a frag up;
     ///   2) if killed by '<world>', the victim gets a frag down.
+    /// If [Config::stop_on_event_model_violations] is set, the [Quake3Events::Kill] events are not consumed,
+    /// so [player_ids_and_nicknames_resolutions()] is able to validate their ids & names.
     /// NOTE: should be applied before [player_ids_and_nicknames_resolutions()] and after [means_of_death()]
     fn kills<'a>(&self, stream: impl Stream<Item=CompositeEvent<'a>>) -> impl Stream<Item=CompositeEvent<'a>> {
 
+        let keep_kill_events = self.config.stop_on_event_model_violations;
+        let empty_name_label = self.config.empty_name_label.clone();
+
         stream
-            .map(|composite_event| {
+            .map(move |composite_event| {
 
                 // game events -- map some of the Quake3 events to `LogicEvent::IncFrags`, `LogicEvent::DecFrags`,
                 let CompositeEvent::GameEvent(ref game_event) = composite_event
                     else {
-                        return Some(composite_event)
+                        return [Some(composite_event), None]
                     };
 
                 match game_event {
 
-                    Quake3Events::Kill { event_id, killer_id, victim_id, reason_id: _, killer_name, victim_name, reason_name: _ } => {
-                        if killer_name != "<world>" {
-                            Some(CompositeEvent::LogicEvent(LogicEvents::IncFrags { quake3_event_id: *event_id, client_id: *killer_id, name: killer_name.to_owned() }))
+                    Quake3Events::Kill { event_id, time_secs: _, killer_id, victim_id, reason_id: _, killer_name, victim_name, reason_name: _ } => {
+                        let frags_event = if killer_name != "<world>" {
+                            CompositeEvent::LogicEvent(LogicEvents::IncFrags { quake3_event_id: *event_id, client_id: *killer_id, name: labeled_name(killer_name, &empty_name_label) })
                         } else {
-                            Some(CompositeEvent::LogicEvent(LogicEvents::DecFrags { quake3_event_id: *event_id, client_id: *victim_id, name: victim_name.to_owned() }))
-                        }
+                            CompositeEvent::LogicEvent(LogicEvents::DecFrags { quake3_event_id: *event_id, client_id: *victim_id, name: labeled_name(victim_name, &empty_name_label) })
+                        };
+                        [Some(frags_event), keep_kill_events.then_some(composite_event)]
                     },
 
-                    _ => Some(composite_event)
+                    _ => [Some(composite_event), None]
                 }
             })
+            .flat_map(stream::iter)
             .filter_map(|composite_event_option| future::ready(composite_event_option))
 
     }
@@ -205,7 +410,9 @@ impl SummaryLogic {
     /// Logic for resolving client ids & client names & validating the ones resolved by the game.\
     /// Also, consumes [Quake3Events::ClientConnect], [Quake3Events::ClientUserinfoChanged] and [Quake3Events::ClientDisconnect]
     /// to produced their enriched versions [LogicEvent::AddPlayer], [LogicEvent::RenamePlayer] & [LogicEvent::DeletePlayer],
-    /// containing both the `client_id` and client name.
+    /// containing both the `client_id` and client name.\
+    /// When [Config::stop_on_event_model_violations] is set, the killer & victim ids of [Quake3Events::Kill] events are
+    /// cross-checked against their currently resolved names, yielding [EventModelViolations::KillIdNameMismatch] on discrepancies.
     /// NOTE: should be applied after [kills()]
     fn player_ids_and_nicknames_resolutions<'a>(&self, stream: impl Stream<Item=CompositeEvent<'a>>) -> impl Stream<Item=CompositeEvent<'a>> {
 
@@ -213,6 +420,10 @@ impl SummaryLogic {
 
         let default_local_name = String::from("NONE");
         let mut player_ids_and_nicks = HashMap::<u32, Option<String>>::new();
+        // ids that disconnected (and didn't connect again) in the current game -- see [Config::tolerate_duplicate_disconnects]
+        let mut disconnected_ids = HashSet::<u32>::new();
+        // the log time each connected id connected at, if known -- see [GameMatchSummary::sessions]
+        let mut connect_times = HashMap::<u32, u32>::new();
 
         stream
             .map(move |composite_event| {
@@ -221,6 +432,7 @@ impl SummaryLogic {
                 if let CompositeEvent::LogicEvent(ref logic_event) = composite_event {
 
                     // common code for the match arms bellow: reports if there are discrepancies in the player names for Inc and Dec frag events
+                    // -- as they come out of `Kill` events, the discrepancies are reported as a `Kill` id / name mismatch
                     let react_to_nicknames_discrepancy = |quake3_event_id, id, name: String, composite_event| {
                         player_ids_and_nicks.get(&id)
                             .and_then(|stored_name| if stored_name == &Some(name.clone()) {
@@ -229,10 +441,10 @@ impl SummaryLogic {
                                     Some(CompositeEvent::LogicEvent(
                                         LogicEvents::EventModelViolation {
                                             quake3_event_id,
-                                            violation: EventModelViolations::DiscrepantPlayerName {
+                                            violation: EventModelViolations::KillIdNameMismatch {
                                                 id,
-                                                local_name: Cow::Owned(stored_name.as_ref().unwrap_or(&default_local_name).to_owned()),
-                                                game_name: Cow::Owned(name),
+                                                expected_name: Cow::Owned(stored_name.as_ref().unwrap_or(&default_local_name).clone()),
+                                                actual_name: Cow::Owned(name),
                                             }
                                         }
                                     ))
@@ -243,6 +455,8 @@ impl SummaryLogic {
                     return match logic_event {
                         LogicEvents::NewGame { .. } => {
                             player_ids_and_nicks.clear();
+                            disconnected_ids.clear();
+                            connect_times.clear();
                             Some(composite_event)
                         },
                         LogicEvents::IncFrags { quake3_event_id, client_id: id, name } if config.stop_on_event_model_violations => react_to_nicknames_discrepancy(*quake3_event_id, *id, name.to_string(), composite_event),
@@ -258,27 +472,66 @@ impl SummaryLogic {
                     };
                 match game_event {
 
-                    Quake3Events::ClientConnect { event_id, client_id: id } => {
+                    Quake3Events::ClientConnect { event_id, time_secs, client_id: id } => {
+                        disconnected_ids.remove(id);
+                        match time_secs {
+                            Some(time_secs) => connect_times.insert(*id, *time_secs),
+                            None => connect_times.remove(id),
+                        };
                         player_ids_and_nicks.insert(*id, None)
                             .map_or_else(|| None,
                                         |_old_nick| Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::DoubleConnect})))
                     },
 
-                    Quake3Events::ClientUserinfoChanged { event_id, client_id: id, name: new_name } => {
+                    Quake3Events::ClientUserinfoChanged { event_id, time_secs, client_id: id, name: new_name } if config.userinfo_implies_connect && !player_ids_and_nicks.contains_key(id) => {
+                        // implicit connect
+                        let new_name = labeled_name(new_name, &config.empty_name_label);
+                        player_ids_and_nicks.insert(*id, Some(new_name.to_string()));
+                        Some(CompositeEvent::LogicEvent(LogicEvents::AddPlayer { quake3_event_id: *event_id, client_id: *id, name: new_name, time_secs: *time_secs }))
+                    },
+
+                    Quake3Events::ClientUserinfoChanged { event_id, time_secs: _, client_id: id, name: new_name } => {
+                        let connect_time = connect_times.get(id).copied();
+                        let new_name = labeled_name(new_name, &config.empty_name_label);
                         player_ids_and_nicks.get_mut(&id)
                             //.map_or_else(|| Some(Err(Box::from(format!("Event #{}: `ClientUserinfoChanged` event received before a `ClientConnect`", event_id+1)))),
-                            .map_or_else(|| Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::ClientNotConnected {id: *id, name: new_name.to_owned()}})),
+                            .map_or_else(|| Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::ClientNotConnected {id: *id, name: new_name.clone()}})),
                                         |old_name| old_name.replace(new_name.to_string())
-                                                .and_then(|old_name| Some(CompositeEvent::LogicEvent(LogicEvents::RenamePlayer { quake3_event_id: *event_id, client_id: *id, old_name: Cow::Owned(old_name), new_name: new_name.to_owned() })) )
-                                                .or_else(|| Some(CompositeEvent::LogicEvent(LogicEvents::AddPlayer { quake3_event_id: *event_id, client_id: 0, name: new_name.to_owned() })) ) )
+                                                .and_then(|old_name| Some(CompositeEvent::LogicEvent(LogicEvents::RenamePlayer { quake3_event_id: *event_id, client_id: *id, old_name: Cow::Owned(old_name), new_name: new_name.clone() })) )
+                                                .or_else(|| Some(CompositeEvent::LogicEvent(LogicEvents::AddPlayer { quake3_event_id: *event_id, client_id: 0, name: new_name.clone(), time_secs: connect_time })) ) )
                     },
 
-                    Quake3Events::ClientDisconnect { event_id, client_id: id } => {
+                    Quake3Events::ClientDisconnect { event_id: _, time_secs: _, client_id: id } if config.tolerate_duplicate_disconnects && disconnected_ids.contains(id) => {
+                        // duplicate disconnect
+                        None
+                    },
+
+                    Quake3Events::ClientDisconnect { event_id, time_secs, client_id: id } => {
+                        connect_times.remove(id);
                         player_ids_and_nicks.remove(id)
-                            .and_then(|name| Some(CompositeEvent::LogicEvent(LogicEvents::DeletePlayer { quake3_event_id: *event_id, client_id: *id, name: Cow::Owned(name.unwrap_or(default_local_name.to_owned()))})))
+                            .inspect(|_name| { disconnected_ids.insert(*id); })
+                            .and_then(|name| Some(CompositeEvent::LogicEvent(LogicEvents::DeletePlayer { quake3_event_id: *event_id, client_id: *id, name: Cow::Owned(name.unwrap_or(default_local_name.to_owned())), time_secs: *time_secs })))
                             .or_else(|| Some(CompositeEvent::LogicEvent(LogicEvents::EventModelViolation { quake3_event_id: *event_id, violation: EventModelViolations::ClientNotConnected {id: *id, name: Cow::Borrowed("<unknown>")}})))
                     }
 
+                    // only present if `config.stop_on_event_model_violations` is set -- see [kills()]
+                    Quake3Events::Kill { event_id, time_secs: _, killer_id, victim_id, reason_id: _, killer_name, victim_name, reason_name: _ } => {
+                        // the `IncFrags` (for the killer) or `DecFrags` (for the victim) produced by [kills()] out of this very event
+                        // had its name checked already: only the other player is left to be checked here
+                        let (id, name) = if killer_name != "<world>" { (victim_id, victim_name) } else { (killer_id, killer_name) };
+                        player_ids_and_nicks.get(id)
+                            .and_then(|stored_name| stored_name.as_ref())
+                            .filter(|stored_name| stored_name.as_str() != labeled_name(name, &config.empty_name_label))
+                            .map(|stored_name| CompositeEvent::LogicEvent(LogicEvents::EventModelViolation {
+                                quake3_event_id: *event_id,
+                                violation: EventModelViolations::KillIdNameMismatch {
+                                    id: *id,
+                                    expected_name: Cow::Owned(stored_name.to_owned()),
+                                    actual_name: name.clone(),
+                                }
+                            }))
+                    }
+
                     _ => Some(composite_event)
                 }
             })
@@ -300,7 +553,7 @@ impl SummaryLogic {
 
                 match game_event {
 
-                    Quake3Events::Score { event_id, frags, client_id, name } =>
+                    Quake3Events::Score { event_id, time_secs: _, frags, client_id, name } =>
                         Some(CompositeEvent::LogicEvent(LogicEvents::ReportedScore { quake3_event_id: *event_id, frags: *frags, client_id: *client_id, name: name.to_owned() })),
 
                     _ => Some(composite_event)
@@ -309,11 +562,70 @@ impl SummaryLogic {
             .filter_map(|composite_event_option| future::ready(composite_event_option))
     }
 
+    /// Logic for measuring the game activity through the Quake3 `Item` (pickup) events
+    fn item_activity<'a>(&self, stream: impl Stream<Item=CompositeEvent<'a>>) -> impl Stream<Item=CompositeEvent<'a>> {
+
+        stream
+            .map(|composite_event| {
+
+                // game events -- map the Quake3 `Item` event into `LogicEvent::ItemPickup`
+                let CompositeEvent::GameEvent(ref game_event) = composite_event
+                    else {
+                        return Some(composite_event)
+                    };
+
+                match game_event {
+
+                    Quake3Events::Item { event_id, time_secs: _ } =>
+                        Some(CompositeEvent::LogicEvent(LogicEvents::ItemPickup { quake3_event_id: *event_id })),
+
+                    _ => Some(composite_event)
+                }
+            })
+            .filter_map(future::ready)
+    }
+
+    /// Logic for keeping the chat transcript out of the Quake3 `say` & `tell` events
+    fn chat<'a>(&self, stream: impl Stream<Item=CompositeEvent<'a>>) -> impl Stream<Item=CompositeEvent<'a>> {
+
+        stream
+            .map(|composite_event| {
+
+                // game events -- map the Quake3 `Say` & `Tell` events into `LogicEvent::ChatMessage`
+                let CompositeEvent::GameEvent(ref game_event) = composite_event
+                    else {
+                        return Some(composite_event)
+                    };
+
+                match game_event {
+
+                    Quake3Events::Say { event_id, time_secs: _, name, message } =>
+                        Some(CompositeEvent::LogicEvent(LogicEvents::ChatMessage { quake3_event_id: *event_id, speaker: name.clone(), message: message.clone() })),
+
+                    Quake3Events::Tell { event_id, time_secs: _, from, to, message } =>
+                        Some(CompositeEvent::LogicEvent(LogicEvents::ChatMessage { quake3_event_id: *event_id, speaker: Cow::Owned(format!("{from} to {to}")), message: message.clone() })),
+
+                    _ => Some(composite_event)
+                }
+            })
+            .filter_map(future::ready)
+    }
+
     /// Ties together the Logic Events in the operated `stream` into a [GameMatchSummary] ready to be presented to the user.
     /// See [compose()] for docs.
     fn summarize<'a>(&self, stream: impl Stream<Item=CompositeEvent<'a>> + 'a) -> impl Stream<Item=Result<GameMatchSummary>> + 'a {
 
+        let config = Arc::clone(&self.config);
+        let warnings = self.warnings.clone();
+        let canonicalize_map_names = self.config.canonicalize_map_names;
+        let count_item_events = self.config.processor_pipeline.contains(&EventAnalyserOperations::ItemActivity);
+        let keep_chat = self.config.processor_pipeline.contains(&EventAnalyserOperations::Chat);
+        let mut name_pool = NamePool::new(self.config.intern_names);
         let mut current_game_summary = None;
+        // every name that ever was one of the `players` in the current game -- see [GameMatchSummary::total_participants]
+        let mut current_participants = HashSet::<Arc<str>>::new();
+        // `(time_secs, fragger)` for the kills in the current game -- only present if [kill_timeline()] is in the pipeline
+        let mut current_kill_timeline: Option<Vec<(u32, Option<Arc<str>>)>> = None;
 
         stream
             .map(move |composite_event| {
@@ -322,68 +634,134 @@ impl SummaryLogic {
                 if let CompositeEvent::LogicEvent(logic_event) = composite_event {
                     match logic_event {
 
-                        LogicEvents::NewGame { quake3_event_id } => {
+                        LogicEvents::NewGame { quake3_event_id, map_name, source } => {
+                            current_kill_timeline = None;
+                            current_participants.clear();
+                            name_pool.clear();
                             current_game_summary
                                 .replace(GameMatchSummary {
                                     total_kills: 0,
-                                    players: BTreeSet::new(),
-                                    kills: BTreeMap::new(),
+                                    players: IndexSet::new(),
+                                    kills: IndexMap::new(),
+                                    total_participants: 0,
+                                    map_name: map_name.map(|map_name| if canonicalize_map_names { map_name.to_lowercase() } else { map_name.into_owned() }),
                                     means_of_death: None,
                                     game_reported_scores: None,
                                     disconnected_players: None,
+                                    avg_frag_gap_minutes: None,
+                                    busiest_minute: None,
+                                    series_id: None,
+                                    raw_kills: None,
+                                    head_to_head: None,
+                                    flawless: None,
+                                    item_events: count_item_events.then_some(0),
+                                    chat: keep_chat.then(Vec::new),
+                                    source: source.map(Cow::into_owned),
+                                    longest_drought_minutes: None,
+                                    source_hash: None,
+                                    sessions: None,
+                                    kill_distribution: None,
                                 })
                                 .and_then(|_previous| Some(Err(Box::from(format!("Quake3 Event #{quake3_event_id}: Two `InitGame` events received before a `ShutdownGame`")))) )
                         },
 
-                        LogicEvents::AddPlayer { quake3_event_id, client_id: id, name } => {
+                        LogicEvents::AddPlayer { quake3_event_id, client_id: id, name, time_secs } => {
                             let current_game_summary = current_game_summary.as_mut()?;
+                            add_participant(&mut current_participants, &mut name_pool, &name);
+                            if let Some(time_secs) = time_secs {
+                                current_game_summary.sessions.get_or_insert_with(BTreeMap::new)
+                                    .entry(name.to_string())
+                                    .or_default()
+                                    .push((time_secs / 60, None));
+                            }
                             (!current_game_summary.players.insert(name.to_string()))
                                 .then(|| Err(Box::from(format!("Event #{quake3_event_id}: Player id: {id}, name: {name:?} is already registered"))))
                         },
 
                         LogicEvents::RenamePlayer { quake3_event_id: _, client_id: _, old_name, new_name } => {
                             let current_game_summary = current_game_summary.as_mut()?;
-                            current_game_summary.players.remove(old_name.as_ref());
+                            current_game_summary.players.shift_remove(old_name.as_ref());
                             current_game_summary.players.insert(new_name.to_string());
-                            current_game_summary.kills.remove(old_name.as_ref())
+                            let new_name = name_pool.name(&new_name);
+                            current_participants.insert(Arc::clone(&new_name));
+                            current_game_summary.kills.shift_remove(old_name.as_ref())
                                 .and_then(|frags| current_game_summary.kills.insert(new_name.to_string(), frags));
+                            if let Some(sessions) = current_game_summary.sessions.as_mut() {
+                                if let Some(old_sessions) = sessions.remove(old_name.as_ref()) {
+                                    sessions.entry(new_name.to_string()).or_default().extend(old_sessions);
+                                }
+                            }
+                            current_kill_timeline.iter_mut().flatten()
+                                .filter_map(|(_time_min, fragger)| fragger.as_mut())
+                                .filter(|fragger| fragger.as_ref() == old_name.as_ref())
+                                .for_each(|fragger| *fragger = Arc::clone(&new_name));
                             None
                         },
 
-                        LogicEvents::DeletePlayer { quake3_event_id, client_id: id, name } => {
+                        LogicEvents::DeletePlayer { quake3_event_id, client_id: id, name, time_secs } => {
                             let current_game_summary = current_game_summary.as_mut()?;
-                            current_game_summary.kills.remove(name.as_ref())
+                            // closes the player's open session -- dropping it, if the disconnection time is unknown
+                            if let Some(player_sessions) = current_game_summary.sessions.as_mut().and_then(|sessions| sessions.get_mut(name.as_ref())) {
+                                match (player_sessions.last_mut(), time_secs) {
+                                    (Some((_connect_minute, disconnect_minute @ None)), Some(time_secs)) => *disconnect_minute = Some(time_secs / 60),
+                                    (Some((_connect_minute, None)), None) => { player_sessions.pop(); },
+                                    _ => (),
+                                }
+                            }
+                            current_game_summary.kills.shift_remove(name.as_ref())
                                 .map(|frags| current_game_summary.disconnected_players.get_or_insert_with(|| Vec::new())
                                     .push((id, name.to_string(), frags)));
-                            (!current_game_summary.players.remove(name.as_ref()))
+                            (!current_game_summary.players.shift_remove(name.as_ref()))
                                 .then(|| Err(Box::from(format!("Event #{quake3_event_id}: Player id: {id}, name: {name:?} was not registered"))))
                         },
 
                         LogicEvents::MeanOfDeath { quake3_event_id: _, mean_of_death } => {
-                            current_game_summary.as_mut()?.means_of_death.get_or_insert_with(|| BTreeMap::new())
-                                .entry(mean_of_death.to_string())
-                                .and_modify(|frags| *frags += 1)
-                                .or_insert(1);
+                            let means_of_death = current_game_summary.as_mut()?.means_of_death.get_or_insert_with(BTreeMap::new);
+                            // the key is only allocated for the first kill by each mean
+                            match means_of_death.get_mut(mean_of_death.as_ref()) {
+                                Some(frags) => *frags += 1,
+                                None => { means_of_death.insert(mean_of_death.into_owned(), 1); },
+                            }
                             None
                         },
 
                         LogicEvents::IncFrags { quake3_event_id: _, client_id: _, name } => {
                             let current_game_summary = current_game_summary.as_mut()?;
                             current_game_summary.total_kills += 1;
-                            current_game_summary.players.insert(name.to_string());
-                            current_game_summary.kills.entry(name.to_string())
-                                .and_modify(|frags| *frags += 1)
-                                .or_insert(1);
+                            add_frags(current_game_summary, &name, 1);
+                            add_participant(&mut current_participants, &mut name_pool, &name);
                             None
                         },
 
                         LogicEvents::DecFrags { quake3_event_id: _, client_id: _, name } => {
                             let current_game_summary = current_game_summary.as_mut()?;
                             current_game_summary.total_kills += 1;
-                            current_game_summary.players.insert(name.to_string());
-                            current_game_summary.kills.entry(name.to_string())
-                                .and_modify(|frags| *frags -= 1)
-                                .or_insert(-1);
+                            add_frags(current_game_summary, &name, -1);
+                            add_participant(&mut current_participants, &mut name_pool, &name);
+                            None
+                        },
+
+                        LogicEvents::KillTime { quake3_event_id: _, time_secs, fragger } => {
+                            current_game_summary.as_ref()?;
+                            current_kill_timeline.get_or_insert_with(Vec::new)
+                                .push((time_secs, fragger.map(|fragger| name_pool.name(&fragger))));
+                            None
+                        },
+
+                        LogicEvents::RawKill { quake3_event_id, killer_name, victim_name, reason_name } => {
+                            current_game_summary.as_mut()?.raw_kills.get_or_insert_with(Vec::new)
+                                .push((quake3_event_id, killer_name.to_string(), victim_name.to_string(), reason_name.to_string()));
+                            None
+                        },
+
+                        LogicEvents::ItemPickup { quake3_event_id: _ } => {
+                            *current_game_summary.as_mut()?.item_events.get_or_insert(0) += 1;
+                            None
+                        },
+
+                        LogicEvents::ChatMessage { quake3_event_id, speaker, message } => {
+                            current_game_summary.as_mut()?.chat.get_or_insert_with(Vec::new)
+                                .push((quake3_event_id, speaker.into_owned(), message.into_owned()));
                             None
                         },
 
@@ -394,12 +772,14 @@ impl SummaryLogic {
                             None
                         },
 
-                        LogicEvents::GameEndedManually { quake3_event_id } =>
+                        LogicEvents::GameEndedManually { quake3_event_id, source_hash } =>
                             Some(current_game_summary.take()
+                                .map(|summary| finish_game(summary, current_participants.drain().count() as u32, source_hash, current_kill_timeline.take(), &config, &warnings, quake3_event_id))
                                 .ok_or_else(|| Box::from(format!("Event #{quake3_event_id}: Game ended, but it was never started"))) ),
 
-                        LogicEvents::GameEndedGracefully { quake3_event_id } =>
+                        LogicEvents::GameEndedGracefully { quake3_event_id, source_hash } =>
                             Some(current_game_summary.take()
+                                .map(|summary| finish_game(summary, current_participants.drain().count() as u32, source_hash, current_kill_timeline.take(), &config, &warnings, quake3_event_id))
                                 .ok_or_else(|| Box::from(format!("Event #{quake3_event_id}: Game ended gracefully, but it was never started"))) ),
 
                         LogicEvents::EventModelViolation { quake3_event_id, violation } =>
@@ -414,6 +794,324 @@ impl SummaryLogic {
             .filter_map(|composite_event_option| future::ready(composite_event_option))
     }
 
+    /// Post-[summarize()] stage ending the summaries with an error in place of the first game past `max_total_games`.
+    /// Errors don't count as games.
+    fn reject_excess_games<'a>(&self, games_summary: impl Stream<Item=Result<GameMatchSummary>> + 'a, max_total_games: usize) -> impl Stream<Item=Result<GameMatchSummary>> + 'a {
+        games_summary
+            .scan(0, move |games, summary_result| {
+                if *games > max_total_games {
+                    return future::ready(None)
+                }
+                if summary_result.is_ok() {
+                    *games += 1;
+                }
+                if *games > max_total_games {
+                    future::ready(Some(Err(Box::from(format!("Summary Logic: the log has more than the `max_total_games` of {max_total_games} game(s) -- it was rejected")))))
+                } else {
+                    future::ready(Some(summary_result))
+                }
+            })
+    }
+
+    /// Post-[summarize()] stage dropping the games having less than `min_players` [GameMatchSummary::total_participants].
+    /// Errors are kept, but count as games when reporting the position of the skipped ones.
+    fn drop_small_games<'a>(&self, games_summary: impl Stream<Item=Result<GameMatchSummary>> + 'a, min_players: usize) -> impl Stream<Item=Result<GameMatchSummary>> + 'a {
+
+        let warnings = self.warnings.clone();
+        let mut game_index = 0;
+
+        games_summary
+            .filter(move |summary_result| {
+                game_index += 1;
+                let Ok(summary) = summary_result
+                    else {
+                        return future::ready(true)
+                    };
+                let keep = summary.total_participants as usize >= min_players;
+                if !keep {
+                    warnings.report(Warning::SkippedGame {
+                        game_index,
+                        map_name: summary.map_name.clone(),
+                        reason: format!("it had {} participant(s), less than the minimum of {min_players}", summary.total_participants),
+                    });
+                }
+                future::ready(keep)
+            })
+    }
+
+    /// Post-[summarize()] stage tagging each [GameMatchSummary] with a [GameMatchSummary::series_id]: consecutive games
+    /// sharing the exact same set of players (rematches) get the same id. Errors don't interrupt a series.
+    fn group_rematches<'a>(&self, games_summary: impl Stream<Item=Result<GameMatchSummary>> + 'a) -> impl Stream<Item=Result<GameMatchSummary>> + 'a {
+
+        let mut series_id = 0;
+        let mut previous_players = None;
+
+        games_summary
+            .map(move |summary_result| summary_result
+                .map(|mut summary| {
+                    if previous_players.as_ref() != Some(&summary.players) {
+                        series_id += 1;
+                        previous_players = Some(summary.players.clone());
+                    }
+                    summary.series_id = Some(series_id);
+                    summary
+                }))
+    }
+
+    /// Post-[summarize()] stage aggregating each [GameMatchSummary] into the `checkpoint` -- which is saved to `checkpoint_path`
+    /// after each game, along with the `consumed_event_id` (the game's `ShutdownGame`, as the summaries are produced as their
+    /// events are pulled). Failures in saving are yielded as errors, right after the game that couldn't be checkpointed.
+    fn checkpoint<'a>(&self, games_summary: impl Stream<Item=Result<GameMatchSummary>> + 'a, checkpoint_path: PathBuf, mut checkpoint: Checkpoint, consumed_event_id: Arc<AtomicU32>) -> impl Stream<Item=Result<GameMatchSummary>> + 'a {
+        games_summary
+            .map(move |summary_result| {
+                let save_error = summary_result.as_ref().ok()
+                    .and_then(|summary| {
+                        checkpoint.global_stats.add(summary);
+                        checkpoint.last_event_id = Some(consumed_event_id.load(Ordering::Relaxed));
+                        save_checkpoint(&checkpoint_path, &checkpoint).err()
+                    });
+                [Some(summary_result), save_error.map(Err)]
+            })
+            .flat_map(stream::iter)
+            .filter_map(future::ready)
+    }
+
+}
+
+/// Where the [Warning]s go: to the log, if [Config::log_issues] is set, and to the `collected` ones, if requested
+/// through [SummaryLogicApi::summarize_games_with_warnings()]
+#[derive(Clone)]
+struct WarningsSink {
+    log: bool,
+    collected: Option<Arc<Mutex<Vec<Warning>>>>,
+}
+
+impl WarningsSink {
+
+    fn report(&self, warning: Warning) {
+        if self.log {
+            warn!("{warning}");
+        }
+        if let Some(collected) = &self.collected {
+            collected.lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(warning);
+        }
+    }
+
+}
+
+/// Tells if a `Kill` is a self-kill (suicide), according to `self_kill_by` -- see [Config::self_kill_by]
+pub(crate) fn is_self_kill(self_kill_by: SelfKillKey, killer_id: u32, victim_id: u32, killer_name: &str, victim_name: &str) -> bool {
+    match self_kill_by {
+        SelfKillKey::Id => killer_id == victim_id,
+        SelfKillKey::Name => killer_name == victim_name,
+    }
+}
+
+/// Returns true if the killer is to be credited with a frag in the per-player kill statistics -- such as the kill timeline
+/// -- false if the victim was killed by '<world>' or by themselves (see [is_self_kill()]).\
+/// NOTE: the game scores, built by [SummaryLogic::kills()], still credit the killer of a self-kill with a frag
+pub(crate) fn is_frag(self_kill_by: SelfKillKey, killer_id: u32, victim_id: u32, killer_name: &str, victim_name: &str) -> bool {
+    killer_name != "<world>" && !is_self_kill(self_kill_by, killer_id, victim_id, killer_name, victim_name)
+}
+
+/// Returns `name` -- or `empty_name_label` in its place, if `name` is empty or made of whitespace only: see [Config::empty_name_label]
+fn labeled_name<'a>(name: &Cow<'a, str>, empty_name_label: &str) -> Cow<'a, str> {
+    if name.trim().is_empty() {
+        Cow::Owned(empty_name_label.to_owned())
+    } else {
+        name.clone()
+    }
+}
+
+/// Returns a description of the inconsistent state `summary` is in, if any -- which no log should be able to cause,
+/// therefore indicating a bug in the pipeline. The invariants are:
+///   1) kills may only be attributed if there are players;
+///   2) no player may have more frags than the [GameMatchSummary::total_kills] -- as every frag is also a kill
+fn summary_inconsistency(summary: &GameMatchSummary) -> Option<String> {
+    (summary.players.is_empty() && !summary.kills.is_empty())
+        .then(|| format!("kills were attributed to {:?}, but the game has no players", summary.kills.keys().collect::<Vec<_>>()))
+        .or_else(|| summary.kills.iter()
+            .find(|(_player, frags)| i64::from(**frags) > i64::from(summary.total_kills))
+            .map(|(player, frags)| format!("{player:?} has {frags} frags, more than the game's total_kills of {}", summary.total_kills)))
+}
+
+/// Finishes the `summary` of the game ended by `quake3_event_id`: fills in what is only known at its end -- its
+/// `total_participants`, `source_hash` & the stats out of its `kill_timeline` -- then applies the post-game stats &
+/// ordering enabled in `config`, reporting any inconsistencies to `warnings`
+fn finish_game(summary: GameMatchSummary, total_participants: u32, source_hash: Option<Cow<str>>, kill_timeline: Option<Vec<(u32, Option<Arc<str>>)>>, config: &Config, warnings: &WarningsSink, quake3_event_id: u32) -> GameMatchSummary {
+    let summary = GameMatchSummary { total_participants, source_hash: source_hash.map(Cow::into_owned), ..summary };
+    let summary = with_kill_timeline_stats(summary, kill_timeline);
+    let summary = with_players_ordering(summary, config.preserve_player_order);
+    let summary = if config.kill_distribution { with_kill_distribution(summary) } else { summary };
+    let summary = with_flawless(with_head_to_head(summary));
+    report_inconsistencies(warnings, quake3_event_id, &summary);
+    summary
+}
+
+/// Reports, as [Warning::Inconsistency], any [summary_inconsistency()] of the `summary` for the game ended by `quake3_event_id`
+fn report_inconsistencies(warnings: &WarningsSink, quake3_event_id: u32, summary: &GameMatchSummary) {
+    if let Some(inconsistency) = summary_inconsistency(summary) {
+        warnings.report(Warning::Inconsistency { event_id: quake3_event_id, description: inconsistency });
+    }
+}
+
+/// Fills in [GameMatchSummary::head_to_head] out of the [GameMatchSummary::raw_kills] -- if [kill_log()] is in the pipeline
+fn with_head_to_head(mut summary: GameMatchSummary) -> GameMatchSummary {
+    let Some(raw_kills) = &summary.raw_kills
+        else {
+            return summary
+        };
+    let mut head_to_head = BTreeMap::<String, BTreeMap<String, i32>>::new();
+    for (_event_id, killer, victim, _reason) in raw_kills {
+        if killer == "<world>" || killer == victim {
+            continue
+        }
+        *head_to_head.entry(killer.to_owned()).or_default().entry(victim.to_owned()).or_default() += 1;
+        *head_to_head.entry(victim.to_owned()).or_default().entry(killer.to_owned()).or_default() -= 1;
+    }
+    summary.head_to_head = Some(head_to_head);
+    summary
+}
+
+/// Fills in [GameMatchSummary::kill_distribution] out of the [GameMatchSummary::kills] -- left absent if there are none
+fn with_kill_distribution(mut summary: GameMatchSummary) -> GameMatchSummary {
+    let mut frags = summary.kills.values().map(|&frags| frags as f64).collect::<Vec<_>>();
+    if frags.is_empty() {
+        return summary
+    }
+    frags.sort_unstable_by(f64::total_cmp);
+    let count = frags.len() as f64;
+    let mean = frags.iter().sum::<f64>() / count;
+    let middle = frags.len() / 2;
+    let median = if frags.len() % 2 == 0 { (frags[middle - 1] + frags[middle]) / 2.0 } else { frags[middle] };
+    let variance = frags.iter().map(|frags| (frags - mean).powi(2)).sum::<f64>() / count;
+    summary.kill_distribution = Some(KillDistribution { mean, median, stddev: variance.sqrt() });
+    summary
+}
+
+/// Fills in [GameMatchSummary::flawless] out of the [GameMatchSummary::raw_kills] -- if [kill_log()] is in the pipeline
+/// and there is a single top scorer
+fn with_flawless(mut summary: GameMatchSummary) -> GameMatchSummary {
+    let Some(raw_kills) = &summary.raw_kills
+        else {
+            return summary
+        };
+    let Some(top_frags) = summary.kills.values().max()
+        else {
+            return summary
+        };
+    let mut top_scorers = summary.kills.iter()
+        .filter(|(_player, frags)| *frags == top_frags)
+        .map(|(player, _frags)| player);
+    let (Some(winner), None) = (top_scorers.next(), top_scorers.next())
+        else {
+            return summary
+        };
+    summary.flawless = Some(raw_kills.iter()
+        .all(|(_event_id, _killer, victim, _reason)| victim != winner));
+    summary
+}
+
+/// Sorts the [GameMatchSummary::players] & [GameMatchSummary::kills] by name -- unless `preserve_player_order` is set,
+/// in which case they are left in their order of appearance
+fn with_players_ordering(mut summary: GameMatchSummary, preserve_player_order: bool) -> GameMatchSummary {
+    if !preserve_player_order {
+        summary.players.sort_unstable();
+        summary.kills.sort_unstable_keys();
+    }
+    summary
+}
+
+/// Adds `frags` (possibly negative) to the score of `name`, registering the player if needed
+/// -- the name is only allocated if it is not there yet
+fn add_frags(summary: &mut GameMatchSummary, name: &str, frags: i32) {
+    if !summary.players.contains(name) {
+        summary.players.insert(name.to_owned());
+    }
+    match summary.kills.get_mut(name) {
+        Some(player_frags) => *player_frags += frags,
+        None => { summary.kills.insert(name.to_owned(), frags); },
+    }
+}
+
+/// Registers `name` as one of the `participants` of the current game -- the name is only allocated (or taken from
+/// the `name_pool`) if it is not there yet
+fn add_participant(participants: &mut HashSet<Arc<str>>, name_pool: &mut NamePool, name: &str) {
+    if !participants.contains(name) {
+        participants.insert(name_pool.name(name));
+    }
+}
+
+/// Hands out shared copies of the player names -- see [Config::intern_names]. If disabled, each name gets its own allocation.\
+/// Names are only shared within a game: the pool is [NamePool::clear()]ed at each new one, so it doesn't grow for as long as
+/// the (possibly endless, when following a log) stream lasts
+struct NamePool {
+    interned: Option<HashSet<Arc<str>>>,
+}
+
+impl NamePool {
+
+    fn new(intern_names: bool) -> Self {
+        Self { interned: intern_names.then(HashSet::new) }
+    }
+
+    fn name(&mut self, name: &str) -> Arc<str> {
+        let Some(interned) = self.interned.as_mut()
+            else {
+                return Arc::from(name)
+            };
+        if let Some(name) = interned.get(name) {
+            return Arc::clone(name)
+        }
+        let name = Arc::<str>::from(name);
+        interned.insert(Arc::clone(&name));
+        name
+    }
+
+    fn clear(&mut self) {
+        if let Some(interned) = self.interned.as_mut() {
+            interned.clear();
+        }
+    }
+
+}
+
+/// Fills in the [GameMatchSummary] statistics derived from the `kill_timeline` gathered by [SummaryLogic::summarize()]
+/// -- `None` if [SummaryLogic::kill_timeline()] wasn't in the pipeline
+fn with_kill_timeline_stats(mut summary: GameMatchSummary, kill_timeline: Option<Vec<(u32, Option<Arc<str>>)>>) -> GameMatchSummary {
+    let Some(kill_timeline) = kill_timeline
+        else {
+            return summary
+        };
+    let mut kills_per_minute = BTreeMap::<u32, u32>::new();
+    for (time_secs, _fragger) in &kill_timeline {
+        *kills_per_minute.entry(time_secs / 60).or_default() += 1;
+    }
+    // `max_by_key()` keeps the last of the maximums, so we iterate backwards to favour the earliest minute
+    summary.busiest_minute = kills_per_minute.into_iter().rev()
+        .max_by_key(|(_minute, kills)| *kills);
+    let mut kill_times = kill_timeline.iter().map(|(time_secs, _fragger)| *time_secs).collect::<Vec<_>>();
+    kill_times.sort_unstable();
+    summary.longest_drought_minutes = kill_times.windows(2)
+        .map(|pair| (pair[1] - pair[0]) / 60)
+        .max();
+    let mut frag_times = BTreeMap::<&str, Vec<u32>>::new();
+    for (time_secs, fragger) in &kill_timeline {
+        if let Some(fragger) = fragger {
+            frag_times.entry(fragger).or_default().push(*time_secs);
+        }
+    }
+    summary.avg_frag_gap_minutes = Some(frag_times.into_iter()
+        .filter(|(_fragger, times)| times.len() >= 2)
+        .map(|(fragger, mut times)| {
+            times.sort_unstable();
+            let gaps_sum_secs: u32 = times.windows(2).map(|pair| pair[1] - pair[0]).sum();
+            (fragger.to_owned(), gaps_sum_secs as f64 / 60.0 / (times.len() - 1) as f64)
+        })
+        .collect());
+    summary
 }
 
 
@@ -421,9 +1119,9 @@ impl SummaryLogic {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use model::report::GlobalStats;
     use dal_api::FileReaderInfo;
     use dal::sync_file_reader::Quake3LogFileSyncReader;
-    use std::pin::Pin;
 
 
     // unit-isolated tests section
@@ -435,14 +1133,14 @@ mod tests {
     #[test]
     fn composition() {
         let events = vec![
-            Quake3Events::InitGame     { event_id: 1 },
-            Quake3Events::Kill         { event_id: 2, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 3, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
-            Quake3Events::ShutdownGame { event_id: 4 },
-            Quake3Events::InitGame     { event_id: 1 },
-            Quake3Events::Kill         { event_id: 2, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 3, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
-            Quake3Events::ShutdownGame { event_id: 4 },
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: None, source_hash: None },
         ];
         let events_count = events.len();
         let log_dao = TestDAL::new(events);
@@ -461,25 +1159,24 @@ mod tests {
     #[test]
     fn simple_working_case() {
         let events = vec![
-            Quake3Events::InitGame     { event_id: 1 },
-            Quake3Events::Kill         { event_id: 2, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 3, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
-            Quake3Events::ShutdownGame { event_id: 4 },
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: None, source_hash: None },
         ];
         let expected_summaries = vec![
             GameMatchSummary {
                 total_kills: 2,
-                players: BTreeSet::from([
+                players: IndexSet::from([
                     "Player1".to_owned(),
                     "Player2".to_owned(),
                 ]),
-                kills: BTreeMap::from([
+                kills: IndexMap::from([
                     ("Player1".to_owned(), 1),
                     ("Player2".to_owned(), 1),
                 ]),
-                means_of_death: None,
-                game_reported_scores: None,
-                disconnected_players: None,
+                total_participants: 2,
+                ..Default::default()
             },
         ];
         assert_mock_summaries(basic_logic_config(), events, expected_summaries)
@@ -489,28 +1186,28 @@ mod tests {
     #[test]
     fn means_of_death() {
         let events = vec![
-            Quake3Events::InitGame     { event_id: 1 },
-            Quake3Events::Kill         { event_id: 2, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "Reason 1".into() },
-            Quake3Events::Kill         { event_id: 3, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "Reason 2".into() },
-            Quake3Events::ShutdownGame { event_id: 4 },
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "Reason 1".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "Reason 2".into() },
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: None, source_hash: None },
         ];
         let expected_summaries = vec![
             GameMatchSummary {
                 total_kills: 2,
-                players: BTreeSet::from([
+                players: IndexSet::from([
                     "Player1".to_owned(),
                     "Player2".to_owned(),
                 ]),
-                kills: BTreeMap::from([
+                kills: IndexMap::from([
                     ("Player1".to_owned(), 1),
                     ("Player2".to_owned(), 1),
                 ]),
+                total_participants: 2,
                 means_of_death: Some(BTreeMap::from([
                     ("Reason 1".to_owned(), 1),
                     ("Reason 2".to_owned(), 1),
                 ])),
-                game_reported_scores: None,
-                disconnected_players: None,
+                ..Default::default()
             },
         ];
         assert_mock_summaries(full_logic_config(), events, expected_summaries)
@@ -525,26 +1222,25 @@ mod tests {
         /////////////////////////////////
 
         let events = vec![
-            Quake3Events::InitGame     { event_id: 1 } ,
-            Quake3Events::Kill         { event_id: 2, killer_id: 1022, victim_id: 2, reason_id: 1, killer_name: "<world>".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 3, killer_id: 2022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 4, killer_id: 2022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
-            Quake3Events::ShutdownGame { event_id: 5 },
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None } ,
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 1, killer_name: "<world>".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 2022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 4, time_secs: None, killer_id: 2022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id: 5, time_secs: None, source_hash: None },
         ];
         let expected_summaries = vec![
             GameMatchSummary {
                 total_kills: 3,
-                players: BTreeSet::from([
+                players: IndexSet::from([
                     "Player1".to_owned(),
                     "Player2".to_owned(),
                 ]),
-                kills: BTreeMap::from([
+                kills: IndexMap::from([
                     ("Player1".to_owned(), -2),
                     ("Player2".to_owned(), -1),
                 ]),
-                means_of_death: None,
-                game_reported_scores: None,
-                disconnected_players: None,
+                total_participants: 2,
+                ..Default::default()
             },
         ];
         assert_mock_summaries(basic_logic_config(), events, expected_summaries);
@@ -553,29 +1249,28 @@ mod tests {
         //////////////////////////////////////////////////////////
 
         let events = vec![
-            Quake3Events::InitGame     { event_id: 1 },
-            Quake3Events::Kill         { event_id: 2, killer_id: 1022, victim_id: 2, reason_id: 1, killer_name: "<world>".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 3, killer_id: 2022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 4, killer_id: 2022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 5, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 6, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
-            Quake3Events::Kill         { event_id: 7, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
-            Quake3Events::ShutdownGame { event_id: 8 },
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 1, killer_name: "<world>".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 2022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 4, time_secs: None, killer_id: 2022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 5, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 6, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 7, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id: 8, time_secs: None, source_hash: None },
         ];
         let expected_summaries = vec![
             GameMatchSummary {
                 total_kills: 6,
-                players: BTreeSet::from([
+                players: IndexSet::from([
                     "Player1".to_owned(),
                     "Player2".to_owned(),
                 ]),
-                kills: BTreeMap::from([
+                kills: IndexMap::from([
                     ("Player1".to_owned(), 0),
                     ("Player2".to_owned(), 0),
                 ]),
-                means_of_death: None,
-                game_reported_scores: None,
-                disconnected_players: None,
+                total_participants: 2,
+                ..Default::default()
             },
         ];
         assert_mock_summaries(basic_logic_config(), events, expected_summaries)
@@ -588,34 +1283,34 @@ mod tests {
 
         // scenario: user disconnects and does not appear on the final summary
         //////////////////////////////////////////////////////////////////////
-        // they will still appear in the `disconnected_players` field
-        // and `total_kills` will be kept
+        // they will still appear in the `disconnected_players` field,
+        // `total_kills` will be kept and they still count in `total_participants`
 
         let events = vec![
-            Quake3Events::InitGame              { event_id: 1 },
-            Quake3Events::ClientConnect         { event_id: 2, client_id: 1 },
-            Quake3Events::ClientUserinfoChanged { event_id: 3, client_id: 1, name: "Bartolo".into() },
-            Quake3Events::ClientConnect         { event_id: 4, client_id: 2 },
-            Quake3Events::ClientUserinfoChanged { event_id: 5, client_id: 2, name: "Mielina".into() },
-            Quake3Events::Kill                  { event_id: 6, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
-            Quake3Events::Kill                  { event_id: 7, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Mielina".into(), victim_name: "Bartolo".into(), reason_name: "ANY".into() },
-            Quake3Events::ClientDisconnect      { event_id: 8, client_id: 1 },
-            Quake3Events::ShutdownGame          { event_id: 9 },
+            Quake3Events::InitGame              { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::ClientConnect         { event_id: 2, time_secs: None, client_id: 1 },
+            Quake3Events::ClientUserinfoChanged { event_id: 3, time_secs: None, client_id: 1, name: "Bartolo".into() },
+            Quake3Events::ClientConnect         { event_id: 4, time_secs: None, client_id: 2 },
+            Quake3Events::ClientUserinfoChanged { event_id: 5, time_secs: None, client_id: 2, name: "Mielina".into() },
+            Quake3Events::Kill                  { event_id: 6, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::Kill                  { event_id: 7, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Mielina".into(), victim_name: "Bartolo".into(), reason_name: "ANY".into() },
+            Quake3Events::ClientDisconnect      { event_id: 8, time_secs: None, client_id: 1 },
+            Quake3Events::ShutdownGame          { event_id: 9, time_secs: None, source_hash: None },
         ];
         let expected_summaries = vec![
             GameMatchSummary {
                 total_kills: 2,
-                players: BTreeSet::from([
+                players: IndexSet::from([
                     "Mielina".to_owned(),
                 ]),
-                kills: BTreeMap::from([
+                kills: IndexMap::from([
                     ("Mielina".to_owned(), 1),
                 ]),
-                means_of_death: None,
-                game_reported_scores: None,
+                total_participants: 2,
                 disconnected_players: Some(vec![
                     (1, "Bartolo".to_owned(), 1),
                 ]),
+                ..Default::default()
             },
         ];
         assert_mock_summaries(all_but_means_of_death_config(), events, expected_summaries);
@@ -627,77 +1322,186 @@ mod tests {
         // and `total_kills` will be kept
 
         let events = vec![
-            Quake3Events::InitGame              { event_id: 1 },
-            Quake3Events::ClientConnect         { event_id: 2,  client_id: 1 },
-            Quake3Events::ClientUserinfoChanged { event_id: 3,  client_id: 1, name: "Bartolo".into() },
-            Quake3Events::ClientConnect         { event_id: 4,  client_id: 2 },
-            Quake3Events::ClientUserinfoChanged { event_id: 5,  client_id: 2, name: "Mielina".into() },
-            Quake3Events::Kill                  { event_id: 6,  killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
-            Quake3Events::Kill                  { event_id: 7,  killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Mielina".into(), victim_name: "Bartolo".into(), reason_name: "ANY".into() },
-            Quake3Events::ClientDisconnect      { event_id: 8,  client_id: 1 },
-            Quake3Events::ClientConnect         { event_id: 9,  client_id: 3 },
-            Quake3Events::ClientUserinfoChanged { event_id: 10, client_id: 3, name: "Bartolo".into() },
-            Quake3Events::Kill                  { event_id: 11, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
-            Quake3Events::Kill                  { event_id: 12, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
-            Quake3Events::ShutdownGame          { event_id: 13 },
+            Quake3Events::InitGame              { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::ClientConnect         { event_id: 2, time_secs: None,  client_id: 1 },
+            Quake3Events::ClientUserinfoChanged { event_id: 3, time_secs: None,  client_id: 1, name: "Bartolo".into() },
+            Quake3Events::ClientConnect         { event_id: 4, time_secs: None,  client_id: 2 },
+            Quake3Events::ClientUserinfoChanged { event_id: 5, time_secs: None,  client_id: 2, name: "Mielina".into() },
+            Quake3Events::Kill                  { event_id: 6, time_secs: None,  killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::Kill                  { event_id: 7, time_secs: None,  killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Mielina".into(), victim_name: "Bartolo".into(), reason_name: "ANY".into() },
+            Quake3Events::ClientDisconnect      { event_id: 8, time_secs: None,  client_id: 1 },
+            Quake3Events::ClientConnect         { event_id: 9, time_secs: None,  client_id: 3 },
+            Quake3Events::ClientUserinfoChanged { event_id: 10, time_secs: None, client_id: 3, name: "Bartolo".into() },
+            Quake3Events::Kill                  { event_id: 11, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::Kill                  { event_id: 12, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::ShutdownGame          { event_id: 13, time_secs: None, source_hash: None },
         ];
         let expected_summaries = vec![
             GameMatchSummary {
                 total_kills: 4,
-                players: BTreeSet::from([
+                players: IndexSet::from([
                     "Bartolo".to_owned(),
                     "Mielina".to_owned(),
                 ]),
-                kills: BTreeMap::from([
+                kills: IndexMap::from([
                     ("Bartolo".to_owned(), 2),
                     ("Mielina".to_owned(), 1),
                 ]),
-                means_of_death: None,
-                game_reported_scores: None,
+                total_participants: 2,
                 disconnected_players: Some(vec![
                     (1, "Bartolo".to_owned(), 1),
                 ]),
+                ..Default::default()
             },
         ];
         assert_mock_summaries(all_but_means_of_death_config(), events, expected_summaries)
 
     }
 
+    /// Tests that, by default, self-kills are detected by the ids -- so distinct players sharing the same name killing each other
+    /// is not a suicide -- and that the game scores are the same regardless of how self-kills are detected
+    #[test]
+    fn self_kills() {
+        assert!(is_self_kill(SelfKillKey::Id, 1, 1, "Player1", "Player1"), "Same ids are a self-kill");
+        assert!(!is_self_kill(SelfKillKey::Id, 2, 3, "Twin", "Twin"), "Distinct ids sharing the same name are not a self-kill");
+        assert!(is_self_kill(SelfKillKey::Name, 2, 3, "Twin", "Twin"), "Same names are a self-kill when detecting by name");
+        assert!(!is_frag(SelfKillKey::Id, 1, 1, "Player1", "Player1"), "Self-kills are not frags");
+        assert!(is_frag(SelfKillKey::Id, 2, 3, "Twin", "Twin"), "Distinct ids sharing the same name are frags");
+
+        let events = || vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1, victim_id: 1, reason_id: 7, killer_name: "Player1".into(), victim_name: "Player1".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 2, victim_id: 3, reason_id: 6, killer_name: "Twin".into(), victim_name: "Twin".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: None, source_hash: None },
+        ];
+        let expected_summaries = || vec![
+            GameMatchSummary {
+                total_kills: 2,
+                players: IndexSet::from([
+                    "Player1".to_owned(),
+                    "Twin".to_owned(),
+                ]),
+                kills: IndexMap::from([
+                    ("Player1".to_owned(), 1),
+                    ("Twin".to_owned(), 1),
+                ]),
+                total_participants: 2,
+                ..Default::default()
+            },
+        ];
+        assert_mock_summaries(basic_logic_config(), events(), expected_summaries());
+        let by_name_config = Config {
+            self_kill_by: SelfKillKey::Name,
+            ..Arc::into_inner(basic_logic_config()).unwrap()
+        };
+        assert_mock_summaries(by_name_config.into(), events(), expected_summaries());
+    }
+
     /// Tests that user scores are correctly preserved after user nickname changes
     #[test]
     fn nick_renamings() {
         let events = vec![
-            Quake3Events::InitGame              { event_id:  1 },
-            Quake3Events::ClientConnect         { event_id:  2, client_id: 1 },
-            Quake3Events::ClientUserinfoChanged { event_id:  3, client_id: 1, name: "Bartolo".into() },
-            Quake3Events::ClientConnect         { event_id:  4, client_id: 2 },
-            Quake3Events::ClientUserinfoChanged { event_id:  5, client_id: 2, name: "Mielina".into() },
-            Quake3Events::Kill                  { event_id:  6, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
-            Quake3Events::Kill                  { event_id:  7, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Mielina".into(), victim_name: "Bartolo".into(), reason_name: "ANY".into() },
-            Quake3Events::ClientUserinfoChanged { event_id:  8, client_id: 1, name: "Bartholo".into() },
-            Quake3Events::Kill                  { event_id:  9, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartholo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
-            Quake3Events::Kill                  { event_id: 10, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartholo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
-            Quake3Events::ShutdownGame          { event_id: 11 },
+            Quake3Events::InitGame              { event_id:  1, time_secs: None, map_name: None, source: None },
+            Quake3Events::ClientConnect         { event_id:  2, time_secs: None, client_id: 1 },
+            Quake3Events::ClientUserinfoChanged { event_id:  3, time_secs: None, client_id: 1, name: "Bartolo".into() },
+            Quake3Events::ClientConnect         { event_id:  4, time_secs: None, client_id: 2 },
+            Quake3Events::ClientUserinfoChanged { event_id:  5, time_secs: None, client_id: 2, name: "Mielina".into() },
+            Quake3Events::Kill                  { event_id:  6, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::Kill                  { event_id:  7, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Mielina".into(), victim_name: "Bartolo".into(), reason_name: "ANY".into() },
+            Quake3Events::ClientUserinfoChanged { event_id:  8, time_secs: None, client_id: 1, name: "Bartholo".into() },
+            Quake3Events::Kill                  { event_id:  9, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartholo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::Kill                  { event_id: 10, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartholo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::ShutdownGame          { event_id: 11, time_secs: None, source_hash: None },
         ];
         let expected_summaries = vec![
             GameMatchSummary {
                 total_kills: 4,
-                players: BTreeSet::from([
+                players: IndexSet::from([
                     "Bartholo".to_owned(),
                     "Mielina".to_owned(),
                 ]),
-                kills: BTreeMap::from([
+                kills: IndexMap::from([
                     ("Bartholo".to_owned(), 3),
                     ("Mielina".to_owned(), 1),
                 ]),
-                means_of_death: None,
-                game_reported_scores: None,
-                disconnected_players: None,
+                total_participants: 3,
+                ..Default::default()
             },
         ];
         assert_mock_summaries(all_but_means_of_death_config(), events, expected_summaries)
     }
 
+    /// Tests that, in pedantic mode, `Kill` events whose ids don't match the resolved player names are reported -- once per mismatch,
+    /// be it on the killer or on the victim side
+    #[test]
+    fn kill_id_name_mismatch() {
+        let events = vec![
+            Quake3Events::InitGame              { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::ClientConnect         { event_id: 2, time_secs: None, client_id: 1 },
+            Quake3Events::ClientUserinfoChanged { event_id: 3, time_secs: None, client_id: 1, name: "Bartolo".into() },
+            Quake3Events::ClientConnect         { event_id: 4, time_secs: None, client_id: 2 },
+            Quake3Events::ClientUserinfoChanged { event_id: 5, time_secs: None, client_id: 2, name: "Mielina".into() },
+            Quake3Events::Kill                  { event_id: 6, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Bartolo".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::Kill                  { event_id: 7, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Impostor".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::Kill                  { event_id: 8, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 2, killer_name: "Mielina".into(), victim_name: "Bartholo".into(), reason_name: "ANY".into() },
+            Quake3Events::ShutdownGame          { event_id: 9, time_secs: None, source_hash: None },
+        ];
+        let pedantic_config = Config {
+            stop_on_event_model_violations: true,
+            ..Arc::into_inner(all_but_means_of_death_config()).unwrap()
+        };
+        let log_dao = TestDAL::new(events);
+        let logic = SummaryLogic::new(pedantic_config);
+        let summaries_stream = logic.summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
+        let errors: Vec<String> = futures::executor::block_on_stream(summaries_stream)
+            .filter_map(|summary_result| summary_result.err())
+            .map(|err| err.to_string())
+            .collect();
+        // each mismatch is reported once: the killer's by the `IncFrags` check & the victim's by the `Kill` one
+        assert_eq!(errors, vec![
+            r#"Event #7: violated the event model: KillIdNameMismatch { id: 1, expected_name: "Bartolo", actual_name: "Impostor" }"#.to_string(),
+            r#"Event #8: violated the event model: KillIdNameMismatch { id: 1, expected_name: "Bartolo", actual_name: "Bartholo" }"#.to_string(),
+        ], "Wrong id / name mismatches reported");
+    }
+
+
+    /// Tests that players with empty or whitespace-only names are resolved as [Config::empty_name_label] -- consistently
+    /// across their userinfo & kills, so neither a blank key shows up nor name discrepancies are reported in pedantic mode
+    #[test]
+    fn empty_names() {
+        let events = vec![
+            Quake3Events::InitGame              { event_id:  1, time_secs: None, map_name: None, source: None },
+            Quake3Events::ClientConnect         { event_id:  2, time_secs: None, client_id: 1 },
+            Quake3Events::ClientUserinfoChanged { event_id:  3, time_secs: None, client_id: 1, name: "".into() },
+            Quake3Events::ClientConnect         { event_id:  4, time_secs: None, client_id: 2 },
+            Quake3Events::ClientUserinfoChanged { event_id:  5, time_secs: None, client_id: 2, name: "Mielina".into() },
+            Quake3Events::Kill                  { event_id:  6, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::Kill                  { event_id:  7, time_secs: None, killer_id: 1022, victim_id: 1, reason_id: 22, killer_name: "<world>".into(), victim_name: "".into(), reason_name: "ANY".into() },
+            Quake3Events::ClientUserinfoChanged { event_id:  8, time_secs: None, client_id: 1, name: "   ".into() },
+            Quake3Events::Kill                  { event_id:  9, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "   ".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::Kill                  { event_id: 10, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "   ".into(), victim_name: "Mielina".into(), reason_name: "ANY".into() },
+            Quake3Events::ShutdownGame          { event_id: 11, time_secs: None, source_hash: None },
+        ];
+        let pedantic_config = Arc::new(Config {
+            stop_on_event_model_violations: true,
+            ..Arc::into_inner(all_but_means_of_death_config()).unwrap()
+        });
+        let expected_summaries = vec![
+            GameMatchSummary {
+                total_kills: 4,
+                players: IndexSet::from([
+                    "<unnamed>".to_owned(),
+                    "Mielina".to_owned(),
+                ]),
+                kills: IndexMap::from([
+                    ("<unnamed>".to_owned(), 2),
+                ]),
+                total_participants: 2,
+                ..Default::default()
+            },
+        ];
+        assert_mock_summaries(pedantic_config, events, expected_summaries)
+    }
 
     // production use cases
     ///////////////////////
@@ -708,127 +1512,127 @@ mod tests {
     #[test]
     fn discrepant_kills_and_scores() {
         let events = vec![
-            Quake3Events::InitGame              { event_id:   1 },
-            Quake3Events::ClientConnect         { event_id:   2, client_id: 2 },
-            Quake3Events::ClientUserinfoChanged { event_id:   3, client_id: 2, name: "Dono da Bola".into() },
-            Quake3Events::ClientConnect         { event_id:   4, client_id: 3 },
-            Quake3Events::ClientUserinfoChanged { event_id:   5, client_id: 3, name: "Isgalamido".into() },
-            Quake3Events::ClientConnect         { event_id:   6, client_id: 4 },
-            Quake3Events::ClientUserinfoChanged { event_id:   7, client_id: 4, name: "Zeh".into() },
-            Quake3Events::Kill                  { event_id:   8, killer_id: 1022, victim_id: 3, reason_id: 22, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_TRIGGER_HURT".into() },
-            Quake3Events::Kill                  { event_id:   9, killer_id: 1022, victim_id: 2, reason_id: 19, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id:  10, killer_id: 1022, victim_id: 3, reason_id: 19, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id:  11, killer_id: 2, victim_id: 4, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  12, killer_id: 3, victim_id: 2, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_RAILGUN".into() },
-            Quake3Events::Kill                  { event_id:  13, killer_id: 3, victim_id: 4, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
-            Quake3Events::Kill                  { event_id:  14, killer_id: 3, victim_id: 2, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_RAILGUN".into() },
-            Quake3Events::Kill                  { event_id:  15, killer_id: 3, victim_id: 4, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
-            Quake3Events::Kill                  { event_id:  16, killer_id: 3, victim_id: 4, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
-            Quake3Events::Kill                  { event_id:  17, killer_id: 2, victim_id: 4, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  18, killer_id: 3, victim_id: 2, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  19, killer_id: 1022, victim_id: 3, reason_id: 22, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_TRIGGER_HURT".into() },
-            Quake3Events::Kill                  { event_id:  20, killer_id: 4, victim_id: 2, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  21, killer_id: 3, victim_id: 4, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  22, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  23, killer_id: 2, victim_id: 3, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::ClientConnect         { event_id:  24, client_id: 5 },
-            Quake3Events::ClientUserinfoChanged { event_id:  25, client_id: 5, name: "Assasinu Credi".into() },
-            Quake3Events::ClientUserinfoChanged { event_id:  26, client_id: 5, name: "Assasinu Credi".into() },
-            Quake3Events::Kill                  { event_id:  27, killer_id: 1022, victim_id: 2, reason_id: 19, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id:  28, killer_id: 4, victim_id: 5, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  29, killer_id: 4, victim_id: 2, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  30, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  31, killer_id: 1022, victim_id: 5, reason_id: 19, killer_name: "<world>".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id:  32, killer_id: 1022, victim_id: 3, reason_id: 22, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_TRIGGER_HURT".into() },
-            Quake3Events::Kill                  { event_id:  33, killer_id: 4, victim_id: 2, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  34, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  35, killer_id: 3, victim_id: 5, reason_id: 3, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_MACHINEGUN".into() },
-            Quake3Events::Kill                  { event_id:  36, killer_id: 3, victim_id: 4, reason_id: 3, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_MACHINEGUN".into() },
-            Quake3Events::Kill                  { event_id:  37, killer_id: 2, victim_id: 3, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  38, killer_id: 2, victim_id: 2, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  39, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  40, killer_id: 4, victim_id: 5, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  41, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  42, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  43, killer_id: 1022, victim_id: 3, reason_id: 19, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id:  44, killer_id: 4, victim_id: 2, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  45, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  46, killer_id: 3, victim_id: 2, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  47, killer_id: 1022, victim_id: 3, reason_id: 19, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id:  48, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  49, killer_id: 5, victim_id: 2, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  50, killer_id: 5, victim_id: 3, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  51, killer_id: 2, victim_id: 3, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  52, killer_id: 1022, victim_id: 2, reason_id: 22, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_TRIGGER_HURT".into() },
-            Quake3Events::Kill                  { event_id:  53, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  54, killer_id: 1022, victim_id: 2, reason_id: 22, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_TRIGGER_HURT".into() },
-            Quake3Events::Kill                  { event_id:  55, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  56, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  57, killer_id: 2, victim_id: 3, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  58, killer_id: 2, victim_id: 4, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  59, killer_id: 2, victim_id: 2, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  60, killer_id: 5, victim_id: 3, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  61, killer_id: 1022, victim_id: 2, reason_id: 22, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_TRIGGER_HURT".into() },
-            Quake3Events::Kill                  { event_id:  62, killer_id: 1022, victim_id: 3, reason_id: 22, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_TRIGGER_HURT".into() },
-            Quake3Events::Kill                  { event_id:  63, killer_id: 4, victim_id: 2, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  64, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  65, killer_id: 4, victim_id: 2, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  66, killer_id: 2, victim_id: 2, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  67, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  68, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  69, killer_id: 1022, victim_id: 2, reason_id: 19, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id:  70, killer_id: 1022, victim_id: 4, reason_id: 19, killer_name: "<world>".into(), victim_name: "Zeh".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id:  71, killer_id: 5, victim_id: 2, reason_id: 6, killer_name: "Assasinu Credi".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  72, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  73, killer_id: 3, victim_id: 5, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  74, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  75, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  76, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  77, killer_id: 2, victim_id: 2, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  78, killer_id: 3, victim_id: 4, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
-            Quake3Events::Kill                  { event_id:  79, killer_id: 3, victim_id: 5, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_RAILGUN".into() },
-            Quake3Events::Kill                  { event_id:  80, killer_id: 4, victim_id: 3, reason_id: 1, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_SHOTGUN".into() },
-            Quake3Events::Kill                  { event_id:  81, killer_id: 2, victim_id: 4, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  82, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  83, killer_id: 3, victim_id: 2, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  84, killer_id: 4, victim_id: 5, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  85, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  86, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  87, killer_id: 1022, victim_id: 2, reason_id: 19, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id:  88, killer_id: 3, victim_id: 2, reason_id: 3, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_MACHINEGUN".into() },
-            Quake3Events::Kill                  { event_id:  89, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  90, killer_id: 3, victim_id: 2, reason_id: 1, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_SHOTGUN".into() },
-            Quake3Events::Kill                  { event_id:  91, killer_id: 5, victim_id: 3, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  92, killer_id: 2, victim_id: 5, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  93, killer_id: 2, victim_id: 4, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  94, killer_id: 5, victim_id: 2, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  95, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  96, killer_id: 3, victim_id: 5, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  97, killer_id: 3, victim_id: 2, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id:  98, killer_id: 5, victim_id: 3, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id:  99, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id: 100, killer_id: 3, victim_id: 5, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id: 101, killer_id: 3, victim_id: 4, reason_id: 3, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_MACHINEGUN".into() },
-            Quake3Events::Kill                  { event_id: 102, killer_id: 1022, victim_id: 5, reason_id: 22, killer_name: "<world>".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_TRIGGER_HURT".into() },
-            Quake3Events::Kill                  { event_id: 103, killer_id: 2, victim_id: 4, reason_id: 10, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
-            Quake3Events::Kill                  { event_id: 104, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id: 105, killer_id: 5, victim_id: 2, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id: 106, killer_id: 1022, victim_id: 3, reason_id: 19, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id: 107, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id: 108, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id: 109, killer_id: 5, victim_id: 5, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id: 110, killer_id: 3, victim_id: 2, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id: 111, killer_id: 1022, victim_id: 4, reason_id: 22, killer_name: "<world>".into(), victim_name: "Zeh".into(), reason_name: "MOD_TRIGGER_HURT".into() },
-            Quake3Events::Kill                  { event_id: 112, killer_id: 1022, victim_id: 5, reason_id: 19, killer_name: "<world>".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_FALLING".into() },
-            Quake3Events::Kill                  { event_id: 113, killer_id: 4, victim_id: 2, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Kill                  { event_id: 114, killer_id: 3, victim_id: 5, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
-            Quake3Events::Kill                  { event_id: 115, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
-            Quake3Events::Exit                  { event_id: 116 },
-            Quake3Events::Score                 { event_id: 117, frags: 20, client_id: 4, name: "Zeh".into() },
-            Quake3Events::Score                 { event_id: 118, frags: 19, client_id: 3, name: "Isgalamido".into() },
-            Quake3Events::Score                 { event_id: 119, frags: 11, client_id: 5, name: "Assasinu Credi".into() },
-            Quake3Events::Score                 { event_id: 120, frags: 5, client_id: 2, name: "Dono da Bola".into() },
-            Quake3Events::ShutdownGame          { event_id: 121 },
+            Quake3Events::InitGame              { event_id:   1, time_secs: None, map_name: None, source: None },
+            Quake3Events::ClientConnect         { event_id:   2, time_secs: None, client_id: 2 },
+            Quake3Events::ClientUserinfoChanged { event_id:   3, time_secs: None, client_id: 2, name: "Dono da Bola".into() },
+            Quake3Events::ClientConnect         { event_id:   4, time_secs: None, client_id: 3 },
+            Quake3Events::ClientUserinfoChanged { event_id:   5, time_secs: None, client_id: 3, name: "Isgalamido".into() },
+            Quake3Events::ClientConnect         { event_id:   6, time_secs: None, client_id: 4 },
+            Quake3Events::ClientUserinfoChanged { event_id:   7, time_secs: None, client_id: 4, name: "Zeh".into() },
+            Quake3Events::Kill                  { event_id:   8, time_secs: None, killer_id: 1022, victim_id: 3, reason_id: 22, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::Kill                  { event_id:   9, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 19, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id:  10, time_secs: None, killer_id: 1022, victim_id: 3, reason_id: 19, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id:  11, time_secs: None, killer_id: 2, victim_id: 4, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  12, time_secs: None, killer_id: 3, victim_id: 2, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill                  { event_id:  13, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill                  { event_id:  14, time_secs: None, killer_id: 3, victim_id: 2, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill                  { event_id:  15, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill                  { event_id:  16, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill                  { event_id:  17, time_secs: None, killer_id: 2, victim_id: 4, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  18, time_secs: None, killer_id: 3, victim_id: 2, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  19, time_secs: None, killer_id: 1022, victim_id: 3, reason_id: 22, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::Kill                  { event_id:  20, time_secs: None, killer_id: 4, victim_id: 2, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  21, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  22, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  23, time_secs: None, killer_id: 2, victim_id: 3, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::ClientConnect         { event_id:  24, time_secs: None, client_id: 5 },
+            Quake3Events::ClientUserinfoChanged { event_id:  25, time_secs: None, client_id: 5, name: "Assasinu Credi".into() },
+            Quake3Events::ClientUserinfoChanged { event_id:  26, time_secs: None, client_id: 5, name: "Assasinu Credi".into() },
+            Quake3Events::Kill                  { event_id:  27, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 19, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id:  28, time_secs: None, killer_id: 4, victim_id: 5, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  29, time_secs: None, killer_id: 4, victim_id: 2, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  30, time_secs: None, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  31, time_secs: None, killer_id: 1022, victim_id: 5, reason_id: 19, killer_name: "<world>".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id:  32, time_secs: None, killer_id: 1022, victim_id: 3, reason_id: 22, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::Kill                  { event_id:  33, time_secs: None, killer_id: 4, victim_id: 2, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  34, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  35, time_secs: None, killer_id: 3, victim_id: 5, reason_id: 3, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_MACHINEGUN".into() },
+            Quake3Events::Kill                  { event_id:  36, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 3, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_MACHINEGUN".into() },
+            Quake3Events::Kill                  { event_id:  37, time_secs: None, killer_id: 2, victim_id: 3, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  38, time_secs: None, killer_id: 2, victim_id: 2, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  39, time_secs: None, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  40, time_secs: None, killer_id: 4, victim_id: 5, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  41, time_secs: None, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  42, time_secs: None, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  43, time_secs: None, killer_id: 1022, victim_id: 3, reason_id: 19, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id:  44, time_secs: None, killer_id: 4, victim_id: 2, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  45, time_secs: None, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  46, time_secs: None, killer_id: 3, victim_id: 2, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  47, time_secs: None, killer_id: 1022, victim_id: 3, reason_id: 19, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id:  48, time_secs: None, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  49, time_secs: None, killer_id: 5, victim_id: 2, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  50, time_secs: None, killer_id: 5, victim_id: 3, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  51, time_secs: None, killer_id: 2, victim_id: 3, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  52, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 22, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::Kill                  { event_id:  53, time_secs: None, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  54, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 22, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::Kill                  { event_id:  55, time_secs: None, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  56, time_secs: None, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  57, time_secs: None, killer_id: 2, victim_id: 3, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  58, time_secs: None, killer_id: 2, victim_id: 4, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  59, time_secs: None, killer_id: 2, victim_id: 2, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  60, time_secs: None, killer_id: 5, victim_id: 3, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  61, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 22, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::Kill                  { event_id:  62, time_secs: None, killer_id: 1022, victim_id: 3, reason_id: 22, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::Kill                  { event_id:  63, time_secs: None, killer_id: 4, victim_id: 2, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  64, time_secs: None, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  65, time_secs: None, killer_id: 4, victim_id: 2, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  66, time_secs: None, killer_id: 2, victim_id: 2, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  67, time_secs: None, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  68, time_secs: None, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  69, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 19, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id:  70, time_secs: None, killer_id: 1022, victim_id: 4, reason_id: 19, killer_name: "<world>".into(), victim_name: "Zeh".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id:  71, time_secs: None, killer_id: 5, victim_id: 2, reason_id: 6, killer_name: "Assasinu Credi".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  72, time_secs: None, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  73, time_secs: None, killer_id: 3, victim_id: 5, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  74, time_secs: None, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  75, time_secs: None, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  76, time_secs: None, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  77, time_secs: None, killer_id: 2, victim_id: 2, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  78, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill                  { event_id:  79, time_secs: None, killer_id: 3, victim_id: 5, reason_id: 10, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill                  { event_id:  80, time_secs: None, killer_id: 4, victim_id: 3, reason_id: 1, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_SHOTGUN".into() },
+            Quake3Events::Kill                  { event_id:  81, time_secs: None, killer_id: 2, victim_id: 4, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  82, time_secs: None, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  83, time_secs: None, killer_id: 3, victim_id: 2, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  84, time_secs: None, killer_id: 4, victim_id: 5, reason_id: 6, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  85, time_secs: None, killer_id: 4, victim_id: 5, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  86, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  87, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 19, killer_name: "<world>".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id:  88, time_secs: None, killer_id: 3, victim_id: 2, reason_id: 3, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_MACHINEGUN".into() },
+            Quake3Events::Kill                  { event_id:  89, time_secs: None, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  90, time_secs: None, killer_id: 3, victim_id: 2, reason_id: 1, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_SHOTGUN".into() },
+            Quake3Events::Kill                  { event_id:  91, time_secs: None, killer_id: 5, victim_id: 3, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  92, time_secs: None, killer_id: 2, victim_id: 5, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  93, time_secs: None, killer_id: 2, victim_id: 4, reason_id: 6, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  94, time_secs: None, killer_id: 5, victim_id: 2, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  95, time_secs: None, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  96, time_secs: None, killer_id: 3, victim_id: 5, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  97, time_secs: None, killer_id: 3, victim_id: 2, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id:  98, time_secs: None, killer_id: 5, victim_id: 3, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id:  99, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id: 100, time_secs: None, killer_id: 3, victim_id: 5, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id: 101, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 3, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_MACHINEGUN".into() },
+            Quake3Events::Kill                  { event_id: 102, time_secs: None, killer_id: 1022, victim_id: 5, reason_id: 22, killer_name: "<world>".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::Kill                  { event_id: 103, time_secs: None, killer_id: 2, victim_id: 4, reason_id: 10, killer_name: "Dono da Bola".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill                  { event_id: 104, time_secs: None, killer_id: 3, victim_id: 4, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id: 105, time_secs: None, killer_id: 5, victim_id: 2, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id: 106, time_secs: None, killer_id: 1022, victim_id: 3, reason_id: 19, killer_name: "<world>".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id: 107, time_secs: None, killer_id: 2, victim_id: 5, reason_id: 7, killer_name: "Dono da Bola".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id: 108, time_secs: None, killer_id: 5, victim_id: 4, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Zeh".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id: 109, time_secs: None, killer_id: 5, victim_id: 5, reason_id: 7, killer_name: "Assasinu Credi".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id: 110, time_secs: None, killer_id: 3, victim_id: 2, reason_id: 7, killer_name: "Isgalamido".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id: 111, time_secs: None, killer_id: 1022, victim_id: 4, reason_id: 22, killer_name: "<world>".into(), victim_name: "Zeh".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::Kill                  { event_id: 112, time_secs: None, killer_id: 1022, victim_id: 5, reason_id: 19, killer_name: "<world>".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill                  { event_id: 113, time_secs: None, killer_id: 4, victim_id: 2, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Dono da Bola".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Kill                  { event_id: 114, time_secs: None, killer_id: 3, victim_id: 5, reason_id: 6, killer_name: "Isgalamido".into(), victim_name: "Assasinu Credi".into(), reason_name: "MOD_ROCKET".into() },
+            Quake3Events::Kill                  { event_id: 115, time_secs: None, killer_id: 4, victim_id: 3, reason_id: 7, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_ROCKET_SPLASH".into() },
+            Quake3Events::Exit                  { event_id: 116, time_secs: None },
+            Quake3Events::Score                 { event_id: 117, time_secs: None, frags: 20, client_id: 4, name: "Zeh".into() },
+            Quake3Events::Score                 { event_id: 118, time_secs: None, frags: 19, client_id: 3, name: "Isgalamido".into() },
+            Quake3Events::Score                 { event_id: 119, time_secs: None, frags: 11, client_id: 5, name: "Assasinu Credi".into() },
+            Quake3Events::Score                 { event_id: 120, time_secs: None, frags: 5, client_id: 2, name: "Dono da Bola".into() },
+            Quake3Events::ShutdownGame          { event_id: 121, time_secs: None, source_hash: None },
         ];
         println!("Number of kills: {}", events.iter().filter(|event| matches!(event, Quake3Events::Kill {..})).count());
         println!("Number of '<world>' kills: {}", events.iter().filter(|event| matches!(event, Quake3Events::Kill { killer_id: 1022, .. })).count());
@@ -840,18 +1644,19 @@ mod tests {
         let expected_summaries = vec![
             GameMatchSummary {
                 total_kills: 105,
-                players: BTreeSet::from([
+                players: IndexSet::from([
                     "Assasinu Credi".to_owned(),
                     "Dono da Bola".to_owned(),
                     "Isgalamido".to_owned(),
                     "Zeh".to_owned(),
                 ]),
-                kills: BTreeMap::from([
+                kills: IndexMap::from([
                     ("Assasinu Credi".to_owned(), 13),
                     ("Dono da Bola".to_owned(), 13),
                     ("Isgalamido".to_owned(), 19),
                     ("Zeh".to_owned(), 20),
                 ]),
+                total_participants: 4,
                 means_of_death: Some(BTreeMap::from([
                     ("MOD_FALLING".to_owned(), 11),
                     ("MOD_MACHINEGUN".to_owned(), 4),
@@ -867,12 +1672,963 @@ mod tests {
                     ("Isgalamido".to_owned(), 19),
                     ("Zeh".to_owned(), 20),
                 ])),
-                disconnected_players: None,
+                ..Default::default()
             },
         ];
         assert_mock_summaries(full_logic_config(), events, expected_summaries)
     }
 
+    /// Tests the average log minutes between each player's frags -- which only counts for players with 2 or more frags
+    #[test]
+    fn avg_frag_gap() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: Some(0), map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: Some(60), killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: Some(180), killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 4, time_secs: Some(240), killer_id: 1022, victim_id: 1, reason_id: 1, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 5, time_secs: Some(300), killer_id: 2, victim_id: 1, reason_id: 1, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 6, time_secs: Some(420), killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id: 7, time_secs: Some(480), source_hash: None },
+        ];
+        let expected_summaries = vec![
+            GameMatchSummary {
+                total_kills: 5,
+                players: IndexSet::from([
+                    "Player1".to_owned(),
+                    "Player2".to_owned(),
+                ]),
+                kills: IndexMap::from([
+                    ("Player1".to_owned(), 2),
+                    ("Player2".to_owned(), 1),
+                ]),
+                total_participants: 2,
+                avg_frag_gap_minutes: Some(BTreeMap::from([
+                    ("Player1".to_owned(), 3.0),
+                ])),
+                busiest_minute: Some((1, 1)),
+                longest_drought_minutes: Some(2),
+                ..Default::default()
+            },
+        ];
+        let kill_timeline_config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::KillTimeline,
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        };
+        assert_mock_summaries(kill_timeline_config.into(), events, expected_summaries)
+    }
+
+
+    /// Tests that the minute having the most kills is elected the busiest one -- the earliest, in case of ties
+    #[test]
+    fn busiest_minute() {
+        let kill_at = |event_id, time_secs| Quake3Events::Kill { event_id, time_secs: Some(time_secs), killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() };
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: Some(0), map_name: None, source: None },
+            kill_at(2, 30),
+            kill_at(3, 2*60+1),
+            kill_at(4, 2*60+15),
+            kill_at(5, 2*60+59),
+            kill_at(6, 5*60+10),
+            kill_at(7, 5*60+20),
+            kill_at(8, 5*60+30),
+            Quake3Events::ShutdownGame { event_id: 9, time_secs: Some(6*60), source_hash: None },
+        ];
+        let kill_timeline_config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::KillTimeline,
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        };
+        let summaries_stream = SummaryLogic::new(kill_timeline_config).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let summaries: Vec<GameMatchSummary> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected"))
+            .collect();
+        assert_eq!(summaries[0].busiest_minute, Some((2, 3)), "Wrong busiest minute");
+    }
+
+    /// Tests that the longest gap between consecutive kills is measured in log minutes -- and left out for games with less than 2 kills
+    #[test]
+    fn longest_drought() {
+        let kill_at = |event_id, time_secs| Quake3Events::Kill { event_id, time_secs: Some(time_secs), killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() };
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: Some(0), map_name: None, source: None },
+            kill_at(2, 60),
+            kill_at(3, 10*60),
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: Some(11*60), source_hash: None },
+            Quake3Events::InitGame     { event_id: 5, time_secs: Some(0), map_name: None, source: None },
+            kill_at(6, 60),
+            Quake3Events::ShutdownGame { event_id: 7, time_secs: Some(11*60), source_hash: None },
+        ];
+        let kill_timeline_config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::KillTimeline,
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        };
+        let summaries_stream = SummaryLogic::new(kill_timeline_config).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let droughts: Vec<Option<u32>> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected").longest_drought_minutes)
+            .collect();
+        assert_eq!(droughts, vec![Some(9), None], "Wrong longest droughts");
+    }
+
+    /// Tests that each player's connected intervals are tracked in log minutes -- a reconnection opening a new one &
+    /// the ones still open when the game ends having no end
+    #[test]
+    fn sessions() {
+        let events = vec![
+            Quake3Events::InitGame              { event_id: 1, time_secs: Some(0), map_name: None, source: None },
+            Quake3Events::ClientConnect         { event_id: 2, time_secs: Some(30), client_id: 1 },
+            Quake3Events::ClientUserinfoChanged { event_id: 3, time_secs: Some(31), client_id: 1, name: "Bartolo".into() },
+            Quake3Events::ClientConnect         { event_id: 4, time_secs: Some(60), client_id: 2 },
+            Quake3Events::ClientUserinfoChanged { event_id: 5, time_secs: Some(61), client_id: 2, name: "Zeh".into() },
+            Quake3Events::ClientDisconnect      { event_id: 6, time_secs: Some(3*60), client_id: 1 },
+            Quake3Events::ClientConnect         { event_id: 7, time_secs: Some(5*60+10), client_id: 1 },
+            Quake3Events::ClientUserinfoChanged { event_id: 8, time_secs: Some(5*60+11), client_id: 1, name: "Bartolo".into() },
+            Quake3Events::ClientDisconnect      { event_id: 9, time_secs: Some(7*60), client_id: 2 },
+            Quake3Events::ShutdownGame          { event_id: 10, time_secs: Some(9*60), source_hash: None },
+        ];
+        let summaries_stream = SummaryLogic::new(all_but_means_of_death_config()).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let sessions: Vec<_> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected").sessions)
+            .collect();
+        assert_eq!(sessions, vec![Some(BTreeMap::from([
+            (String::from("Bartolo"), vec![(0, Some(3)), (5, None)]),
+            (String::from("Zeh"),     vec![(1, Some(7))]),
+        ]))], "Wrong sessions");
+    }
+
+    /// Tests that, under [Config::tolerate_duplicate_disconnects], repeating a `ClientDisconnect` is not a violation
+    #[test]
+    fn duplicate_disconnects() {
+        let events = || vec![
+            Quake3Events::InitGame              { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::ClientConnect         { event_id: 2, time_secs: None, client_id: 1 },
+            Quake3Events::ClientUserinfoChanged { event_id: 3, time_secs: None, client_id: 1, name: "Bartolo".into() },
+            Quake3Events::ClientDisconnect      { event_id: 4, time_secs: None, client_id: 1 },
+            Quake3Events::ClientDisconnect      { event_id: 5, time_secs: None, client_id: 1 },
+            Quake3Events::ShutdownGame          { event_id: 6, time_secs: None, source_hash: None },
+        ];
+        let errors = |config| {
+            let summaries_stream = SummaryLogic::new(config).summarize_games(TestDAL::new(events())).expect("sumarize_games() shouldn't fail here");
+            futures::executor::block_on_stream(summaries_stream)
+                .filter_map(|summary_result| summary_result.err())
+                .map(|err| err.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        // scenario: the default -- a violation is reported
+        assert_eq!(errors(all_but_means_of_death_config()), vec![r#"Event #5: violated the event model: ClientNotConnected { id: 1, name: "<unknown>" }"#], "The duplicate disconnect went unreported");
+
+        // scenario: duplicate disconnects are tolerated
+        let tolerant_config = Config {
+            tolerate_duplicate_disconnects: true,
+            ..Arc::into_inner(all_but_means_of_death_config()).unwrap()
+        };
+        assert_eq!(errors(tolerant_config.into()), Vec::<String>::new(), "The duplicate disconnect should have been tolerated");
+    }
+
+    /// Tests that, under [Config::stop_on_event_model_violations], an event logged earlier than its predecessor in the same game
+    /// is reported -- but not across games, as each one starts its own clock
+    #[test]
+    fn non_monotonic_time() {
+        let events = || vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: Some(0), map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: Some(60), killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: Some(120), killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill         { event_id: 4, time_secs: Some(60), killer_id: 2, victim_id: 1, reason_id: 1, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::ShutdownGame { event_id: 5, time_secs: Some(180), source_hash: None },
+            Quake3Events::InitGame     { event_id: 6, time_secs: Some(0), map_name: None, source: None },
+            Quake3Events::ShutdownGame { event_id: 7, time_secs: Some(10), source_hash: None },
+        ];
+        let errors = |config| {
+            let summaries_stream = SummaryLogic::new(config).summarize_games(TestDAL::new(events())).expect("sumarize_games() shouldn't fail here");
+            futures::executor::block_on_stream(summaries_stream)
+                .filter_map(|summary_result| summary_result.err())
+                .map(|err| err.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        // scenario: the default -- times are not checked
+        assert_eq!(errors(basic_logic_config()), Vec::<String>::new(), "Times should only be checked in pedantic mode");
+
+        // scenario: pedantic -- the backwards jump is reported
+        let pedantic_config = Config {
+            stop_on_event_model_violations: true,
+            ..Arc::into_inner(basic_logic_config()).unwrap()
+        };
+        assert_eq!(errors(pedantic_config.into()), vec!["Event #4: violated the event model: NonMonotonicTime { previous: 120, current: 60 }"], "The backwards time jump went unreported");
+    }
+
+    /// Tests that a backwards time on the `ShutdownGame` line is reported without swallowing the event: the game still ends
+    /// -- so the next one is not taken as a `DoubleInit`
+    #[test]
+    fn non_monotonic_shutdown_time() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: Some(0), map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: Some(120), killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::ShutdownGame { event_id: 3, time_secs: Some(60), source_hash: None },
+            Quake3Events::InitGame     { event_id: 4, time_secs: Some(0), map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 5, time_secs: Some(10), killer_id: 2, victim_id: 1, reason_id: 1, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::ShutdownGame { event_id: 6, time_secs: Some(20), source_hash: None },
+        ];
+        let pedantic_config = Config {
+            stop_on_event_model_violations: true,
+            ..Arc::into_inner(basic_logic_config()).unwrap()
+        };
+        let summaries_stream = SummaryLogic::new(pedantic_config).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let results = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result
+                .map(|summary| summary.total_kills)
+                .map_err(|err| err.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec![
+            Err("Event #3: violated the event model: NonMonotonicTime { previous: 120, current: 60 }".to_string()),
+            Ok(1),
+            Ok(1),
+        ], "Both games should have been summarized, along with the backwards time violation");
+    }
+
+    /// Tests that, under [Config::stop_on_event_model_violations], a second `Exit` before the `ShutdownGame` is reported
+    /// -- and that it is otherwise ignored, the game still ending gracefully
+    #[test]
+    fn double_exit() {
+        let events = || vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Exit         { event_id: 3, time_secs: None },
+            Quake3Events::Exit         { event_id: 4, time_secs: None },
+            Quake3Events::ShutdownGame { event_id: 5, time_secs: None, source_hash: None },
+        ];
+        let results = |config| {
+            let summaries_stream = SummaryLogic::new(config).summarize_games(TestDAL::new(events())).expect("sumarize_games() shouldn't fail here");
+            futures::executor::block_on_stream(summaries_stream)
+                .map(|summary_result| summary_result.map(|summary| summary.total_kills).map_err(|err| err.to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        // scenario: the default -- the second `Exit` is ignored
+        assert_eq!(results(basic_logic_config()), vec![Ok(1)], "A repeated `Exit` should only be reported in pedantic mode");
+
+        // scenario: pedantic -- the second `Exit` is reported
+        let pedantic_config = Config {
+            stop_on_event_model_violations: true,
+            ..Arc::into_inner(basic_logic_config()).unwrap()
+        };
+        assert_eq!(results(pedantic_config.into()), vec![Err("Event #4: violated the event model: DoubleExit".to_owned()), Ok(1)], "The repeated `Exit` went unreported");
+    }
+
+    /// Tests that, under [Config::userinfo_implies_connect], a `ClientUserinfoChanged` without a prior `ClientConnect` registers the player
+    #[test]
+    fn userinfo_implies_connect() {
+        let events = || vec![
+            Quake3Events::InitGame              { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::ClientUserinfoChanged { event_id: 2, time_secs: None, client_id: 1, name: "Bartolo".into() },
+            Quake3Events::ClientUserinfoChanged { event_id: 3, time_secs: None, client_id: 1, name: "Bartholo".into() },
+            Quake3Events::ShutdownGame          { event_id: 4, time_secs: None, source_hash: None },
+        ];
+
+        // scenario: the default -- a violation is reported
+        ////////////////////////////////////////////////////
+
+        let summaries_stream = SummaryLogic::new(all_but_means_of_death_config()).summarize_games(TestDAL::new(events())).expect("sumarize_games() shouldn't fail here");
+        let errors: Vec<String> = futures::executor::block_on_stream(summaries_stream)
+            .filter_map(|summary_result| summary_result.err())
+            .map(|err| err.to_string())
+            .collect();
+        assert_eq!(errors.first().map(String::as_str), Some(r#"Event #2: violated the event model: ClientNotConnected { id: 1, name: "Bartolo" }"#), "The missing `ClientConnect` went unreported");
+
+        // scenario: userinfo implies connect
+        /////////////////////////////////////
+
+        let expected_summaries = vec![
+            GameMatchSummary {
+                total_kills: 0,
+                players: IndexSet::from([
+                    "Bartholo".to_owned(),
+                ]),
+                kills: IndexMap::new(),
+                total_participants: 2,
+                ..Default::default()
+            },
+        ];
+        let implicit_connect_config = Config {
+            userinfo_implies_connect: true,
+            ..Arc::into_inner(all_but_means_of_death_config()).unwrap()
+        };
+        assert_mock_summaries(implicit_connect_config.into(), events(), expected_summaries);
+    }
+
+    /// Tests that players are sorted by name, unless [Config::preserve_player_order] asks for their order of appearance
+    #[test]
+    fn players_order() {
+        let events = || vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 1, killer_name: "Zeh".into(), victim_name: "Isgalamido".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: None, source_hash: None },
+        ];
+        let players_order_of = |config: Config| {
+            let summaries_stream = SummaryLogic::new(config).summarize_games(TestDAL::new(events())).expect("sumarize_games() shouldn't fail here");
+            let summary = futures::executor::block_on_stream(summaries_stream).next()
+                .expect("No summary was produced")
+                .expect("The summary shouldn't be an error");
+            (summary.players.into_iter().collect::<Vec<_>>(), summary.kills.into_keys().collect::<Vec<_>>())
+        };
+
+        // scenario: the default -- sorted by name
+        let (players, killers) = players_order_of(Arc::into_inner(basic_logic_config()).unwrap());
+        assert_eq!(players, vec!["Isgalamido", "Zeh"], "Players are not sorted by name");
+        assert_eq!(killers, vec!["Isgalamido", "Zeh"], "Kills are not sorted by name");
+
+        // scenario: in order of appearance
+        let (players, killers) = players_order_of(Config {
+            preserve_player_order: true,
+            ..Arc::into_inner(basic_logic_config()).unwrap()
+        });
+        assert_eq!(players, vec!["Zeh", "Isgalamido"], "Players are not in their order of appearance");
+        assert_eq!(killers, vec!["Zeh", "Isgalamido"], "Kills are not in their order of appearance");
+    }
+
+    /// Tests that summaries with kills, but no players, are detected as inconsistent
+    #[test]
+    fn players_and_kills_inconsistency() {
+        let summary = |players: &[&str]| GameMatchSummary {
+            total_kills: 1,
+            players: players.iter().map(|player| player.to_string()).collect(),
+            kills: IndexMap::from([
+                ("Zeh".to_owned(), 1),
+            ]),
+            total_participants: 0,
+            ..Default::default()
+        };
+        assert_eq!(summary_inconsistency(&summary(&["Zeh"])), None, "A consistent summary was flagged");
+        assert_eq!(summary_inconsistency(&summary(&[])).as_deref(), Some(r#"kills were attributed to ["Zeh"], but the game has no players"#), "The inconsistency went undetected");
+    }
+
+    /// Tests that summaries where a player has more frags than the game's `total_kills` are detected as inconsistent
+    #[test]
+    fn frags_over_total_kills_inconsistency() {
+        let summary = |total_kills: u32| GameMatchSummary {
+            total_kills,
+            players: IndexSet::from(["Zeh".to_owned(), "Isgalamido".to_owned()]),
+            kills: IndexMap::from([
+                ("Zeh".to_owned(), 3),
+                ("Isgalamido".to_owned(), -1),
+            ]),
+            total_participants: 2,
+            ..Default::default()
+        };
+        assert_eq!(summary_inconsistency(&summary(4)), None, "A consistent summary was flagged");
+        assert_eq!(summary_inconsistency(&summary(2)).as_deref(), Some(r#""Zeh" has 3 frags, more than the game's total_kills of 2"#), "The inconsistency went undetected");
+    }
+
+    /// Tests that every `Kill` event is echoed, unaggregated, by [kill_log()]
+    #[test]
+    fn raw_kills() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 1022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill         { event_id: 4, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::ShutdownGame { event_id: 5, time_secs: None, source_hash: None },
+        ];
+        let kill_events_count = events.iter().filter(|event| matches!(event, Quake3Events::Kill { .. })).count();
+        let kill_log_config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::KillLog,
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        };
+        let summaries_stream = SummaryLogic::new(kill_log_config).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let summaries: Vec<GameMatchSummary> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected"))
+            .collect();
+        let raw_kills = summaries[0].raw_kills.as_ref().expect("`raw_kills` should be present");
+        assert_eq!(raw_kills.len(), kill_events_count, "Every `Kill` event should have been echoed");
+        assert_eq!(raw_kills[1], (3, "<world>".to_owned(), "Player1".to_owned(), "MOD_FALLING".to_owned()), "Raw kill not echoed verbatim");
+    }
+
+    /// Tests [GameMatchSummary::head_to_head] against the production data: on the 3rd game, Isgalamido killed Zeh 12 times
+    /// and was killed by him 7 times
+    #[test]
+    fn head_to_head() {
+        let kill_log_config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::KillLog,
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        };
+        let log_dao = Quake3LogFileSyncReader::new(Arc::new(dal_api::Config::default()), FileReaderInfo { log_file_path: Cow::Borrowed(PEDANTIC_LOG_FILE_LOCATION) });
+        let summaries_stream = SummaryLogic::new(kill_log_config).summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
+        let summaries: Vec<GameMatchSummary> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected"))
+            .collect();
+        let head_to_head = summaries[2].head_to_head.as_ref().expect("`head_to_head` should be present");
+        assert_eq!(head_to_head["Isgalamido"]["Zeh"], 5, "Wrong net frags of Isgalamido over Zeh");
+        for (player, opponents) in head_to_head {
+            assert!(player != "<world>", "`<world>` should have been left out");
+            for (opponent, net_frags) in opponents {
+                assert_ne!(player, opponent, "Self kills should have been left out");
+                assert_eq!(head_to_head[opponent][player], -net_frags, "`head_to_head` is not antisymmetric for {player} & {opponent}");
+            }
+        }
+    }
+
+    /// Tests [GameMatchSummary::flawless]: the top scorer of the 1st game never died, while the one of the 2nd game did
+    #[test]
+    fn flawless() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player2".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill         { event_id: 4, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::ShutdownGame { event_id: 5, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 6, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 7, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill         { event_id: 8, time_secs: None, killer_id: 1022, victim_id: 1, reason_id: 2, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::Kill         { event_id: 9, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::ShutdownGame { event_id: 10, time_secs: None, source_hash: None },
+        ];
+        let kill_log_config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::KillLog,
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        };
+        let summaries_stream = SummaryLogic::new(kill_log_config).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let flawless: Vec<Option<bool>> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected").flawless)
+            .collect();
+        assert_eq!(flawless, vec![Some(true), Some(false)], "Wrong `flawless` flags");
+    }
+
+    /// Tests that [GameMatchSummary::item_events] account for all the `Item` lines of the production data
+    #[test]
+    fn item_activity() {
+        let item_activity_config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::ItemActivity,
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        };
+        let log_dao = Quake3LogFileSyncReader::new(Arc::new(dal_api::Config::default()), FileReaderInfo { log_file_path: Cow::Borrowed(PEDANTIC_LOG_FILE_LOCATION) });
+        let summaries_stream = SummaryLogic::new(item_activity_config).summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
+        let item_events: Vec<u32> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected").item_events.expect("`item_events` should be present"))
+            .collect();
+        let log = std::fs::read_to_string(PEDANTIC_LOG_FILE_LOCATION).expect("Couldn't read the log file");
+        let item_lines = log.lines().filter(|line| line.contains(" Item: ")).count() as u32;
+        assert_eq!(item_events.iter().sum::<u32>(), item_lines, "Every `Item` line should have been counted");
+        assert_eq!(item_events[0], 0, "The 1st game has no `Item` lines");
+    }
+
+    /// Tests that a `Kill` line whose text description ends in ` by ` is accounted for under [Config::unknown_means_label]
+    #[test]
+    fn empty_reason_name() {
+        let log = "  0:00 InitGame: \\mapname\\q3dm17\n  \
+                   0:01 ClientConnect: 2\n  \
+                   0:02 ClientUserinfoChanged: 2 n\\Isgalamido\\t\\0\n  \
+                   0:03 Kill: 1022 2 22: <world> killed Isgalamido by \n  \
+                   0:04 Kill: 1022 2 22: <world> killed Isgalamido by MOD_TRIGGER_HURT\n  \
+                   0:05 ShutdownGame:\n";
+        let means_of_death = |unknown_means_label: &str| {
+            let config = Config {
+                processor_pipeline: HashSet::from([
+                    EventAnalyserOperations::MeansOfDeath,
+                    EventAnalyserOperations::Kills,
+                    EventAnalyserOperations::PlayerIdsAndNickNamesResolutions,
+                    EventAnalyserOperations::GameReportedScores,
+                ]),
+                unknown_means_label: unknown_means_label.to_owned(),
+                ..Config::default()
+            };
+            let log_dao = dal::sync_reader::Quake3LogSyncReader::new(Arc::new(dal_api::Config::default()), "<test>", std::io::Cursor::new(log.as_bytes()), 0);
+            let summaries_stream = SummaryLogic::new(config).summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
+            futures::executor::block_on_stream(summaries_stream)
+                .map(|summary_result| summary_result.expect("No errors were expected").means_of_death)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(means_of_death("UNKNOWN"), vec![Some(BTreeMap::from([("MOD_TRIGGER_HURT".to_owned(), 1), ("UNKNOWN".to_owned(), 1)]))], "The default placeholder wasn't used");
+        assert_eq!(means_of_death("MOD_?"), vec![Some(BTreeMap::from([("MOD_?".to_owned(), 1), ("MOD_TRIGGER_HURT".to_owned(), 1)]))], "The configured placeholder wasn't used");
+    }
+
+    /// Tests that, under [Config::hash_source_lines], each game's `source_hash` is stable for identical lines -- regardless
+    /// of what comes around the game -- and differs when any of its lines change
+    #[test]
+    fn hash_source_lines() {
+        let game = |kill_reason: &str| format!("  0:00 InitGame: \\mapname\\q3dm17\n  \
+                                                 0:01 ClientConnect: 2\n  \
+                                                 0:02 ClientUserinfoChanged: 2 n\\Isgalamido\\t\\0\n  \
+                                                 0:03 Kill: 1022 2 22: <world> killed Isgalamido by {kill_reason}\n  \
+                                                 0:04 ShutdownGame:\n");
+        let source_hashes = |log: String, hash_source_lines: bool| {
+            let config = Config {
+                hash_source_lines,
+                ..Config::default()
+            };
+            let dal_config = dal_api::Config {
+                hash_source_lines: true,
+                ..dal_api::Config::default()
+            };
+            let log_dao = dal::sync_reader::Quake3LogSyncReader::new(Arc::new(dal_config), "<test>", std::io::Cursor::new(log.into_bytes()), 0);
+            let summaries_stream = SummaryLogic::new(config).summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
+            futures::executor::block_on_stream(summaries_stream)
+                .map(|summary_result| summary_result.expect("No errors were expected").source_hash)
+                .collect::<Vec<_>>()
+        };
+        let hashes = source_hashes(format!("{}  0:05 ------------------------------------------------------------\n{}{}", game("MOD_TRIGGER_HURT"), game("MOD_TRIGGER_HURT"), game("MOD_FALLING")), true);
+        assert!(hashes.iter().all(|hash| hash.as_ref().is_some_and(|hash| hash.len() == 64)), "Every game should have a SHA-256 hex hash: {hashes:?}");
+        assert_eq!(hashes[0], hashes[1], "Identical games should have the same hash");
+        assert_ne!(hashes[1], hashes[2], "Games differing in a line should have different hashes");
+        assert_eq!(source_hashes(game("MOD_FALLING"), true)[0], hashes[2], "The hash should be stable across runs");
+        assert_eq!(source_hashes(game("MOD_FALLING"), false), vec![None], "The hash should only be present if requested");
+    }
+
+    /// Tests that [Config::intern_names] doesn't change the summaries of the pedantic log -- renames & kill timings included
+    #[test]
+    fn intern_names() {
+        let summaries = |intern_names| {
+            let config = Config {
+                processor_pipeline: HashSet::from([
+                    EventAnalyserOperations::MeansOfDeath,
+                    EventAnalyserOperations::KillTimeline,
+                    EventAnalyserOperations::Kills,
+                    EventAnalyserOperations::PlayerIdsAndNickNamesResolutions,
+                    EventAnalyserOperations::GameReportedScores,
+                ]),
+                intern_names,
+                ..Config::default()
+            };
+            let log_dao = Quake3LogFileSyncReader::new(Arc::new(dal_api::Config::default()), FileReaderInfo { log_file_path: Cow::Borrowed(PEDANTIC_LOG_FILE_LOCATION) });
+            let summaries_stream = SummaryLogic::new(config).summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
+            futures::executor::block_on_stream(summaries_stream)
+                .map(|summary_result| summary_result.expect("No errors were expected"))
+                .collect::<Vec<GameMatchSummary>>()
+        };
+        let interned_summaries = summaries(true);
+        assert_eq!(interned_summaries.len(), 20, "Unexpected number of games");
+        assert_eq!(interned_summaries, summaries(false), "Interning the names changed the summaries");
+    }
+
+    /// Tests that the [NamePool] shares the names handed out until it is cleared -- as it is at every new game
+    #[test]
+    fn name_pool() {
+        let mut name_pool = NamePool::new(true);
+        let zeh = name_pool.name("Zeh");
+        assert!(Arc::ptr_eq(&zeh, &name_pool.name("Zeh")), "Repeated names should share their storage");
+        name_pool.name("Isgalamido");
+        assert_eq!(name_pool.interned.as_ref().map(HashSet::len), Some(2), "Wrong number of interned names");
+        name_pool.clear();
+        assert_eq!(name_pool.interned.as_ref().map(HashSet::len), Some(0), "Clearing should release the interned names");
+        assert!(!Arc::ptr_eq(&zeh, &name_pool.name("Zeh")), "Names from previous games shouldn't be reused");
+    }
+
+    /// Tests the [GameMatchSummary::chat] transcript: public & private messages are kept in order, while other games' are left out
+    #[test]
+    fn chat() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Say          { event_id: 2, time_secs: None, name: "Isgalamido".into(), message: "team blue".into() },
+            Quake3Events::Kill         { event_id: 3, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Isgalamido".into(), victim_name: "Zeh".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Tell         { event_id: 4, time_secs: None, from: "Zeh".into(), to: "Isgalamido".into(), message: "meet me at the rail: now".into() },
+            Quake3Events::Say          { event_id: 5, time_secs: None, name: "Zeh".into(), message: "gg".into() },
+            Quake3Events::ShutdownGame { event_id: 6, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 7, time_secs: None, map_name: None, source: None },
+            Quake3Events::ShutdownGame { event_id: 8, time_secs: None, source_hash: None },
+        ];
+        let chat_config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::Chat,
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        };
+        let summaries_stream = SummaryLogic::new(chat_config).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let chats: Vec<Vec<(u32, String, String)>> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected").chat.expect("`chat` should be present"))
+            .collect();
+        assert_eq!(chats, vec![
+            vec![
+                (2, "Isgalamido".to_owned(), "team blue".to_owned()),
+                (4, "Zeh to Isgalamido".to_owned(), "meet me at the rail: now".to_owned()),
+                (5, "Zeh".to_owned(), "gg".to_owned()),
+            ],
+            vec![],
+        ], "Wrong chat transcripts");
+    }
+
+    /// Tests that [EventAnalyserOperations::Chat] & [EventAnalyserOperations::ItemActivity] may be combined in the same pipeline
+    #[test]
+    fn chat_and_item_activity() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Item         { event_id: 2, time_secs: None },
+            Quake3Events::Say          { event_id: 3, time_secs: None, name: "Isgalamido".into(), message: "mine".into() },
+            Quake3Events::Item         { event_id: 4, time_secs: None },
+            Quake3Events::ShutdownGame { event_id: 5, time_secs: None, source_hash: None },
+        ];
+        let config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::Chat,
+                EventAnalyserOperations::ItemActivity,
+                EventAnalyserOperations::Kills,
+            ]),
+            ..Config::default()
+        };
+        let summaries_stream = SummaryLogic::new(config).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let summaries: Vec<GameMatchSummary> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected"))
+            .collect();
+        assert_eq!(summaries.len(), 1, "Wrong number of games");
+        assert_eq!(summaries[0].item_events, Some(2), "Wrong `item_events`");
+        assert_eq!(summaries[0].chat, Some(vec![(3, "Isgalamido".to_owned(), "mine".to_owned())]), "Wrong chat transcript");
+    }
+
+    /// Tests that, under [Config::canonicalize_map_names], case variants of a map name are merged in the by-map stats
+    #[test]
+    fn canonical_map_names() {
+        let events = || vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: Some("q3dm17".into()), source: None },
+            Quake3Events::ShutdownGame { event_id: 2, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 3, time_secs: None, map_name: Some("Q3DM17".into()), source: None },
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: None, source_hash: None },
+        ];
+        let games_by_map = |config: Config| {
+            let summaries_stream = SummaryLogic::new(config).summarize_games(TestDAL::new(events())).expect("sumarize_games() shouldn't fail here");
+            let mut global_stats = GlobalStats::default();
+            futures::executor::block_on_stream(summaries_stream)
+                .for_each(|summary_result| global_stats.add(&summary_result.expect("No errors were expected")));
+            global_stats.games_by_map
+        };
+
+        // scenario: the default -- map names are kept as they are
+        assert_eq!(games_by_map(Arc::into_inner(basic_logic_config()).unwrap()),
+                   BTreeMap::from([("Q3DM17".to_owned(), 1), ("q3dm17".to_owned(), 1)]),
+                   "Map names should have been kept as they are");
+
+        // scenario: canonical map names
+        assert_eq!(games_by_map(Config { canonicalize_map_names: true, ..Arc::into_inner(basic_logic_config()).unwrap() }),
+                   BTreeMap::from([("q3dm17".to_owned(), 2)]),
+                   "Case variants of the map name should have been merged");
+    }
+
+    /// Tests that [Config::checkpoint_path] gets saved after each game -- and that a new run, replaying the same feed,
+    /// resumes from it without aggregating the checkpointed games twice
+    #[test]
+    fn checkpoints() {
+        let game = |first_event_id, killer_name: &'static str| vec![
+            Quake3Events::InitGame     { event_id: first_event_id,   time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: first_event_id+1, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: killer_name.into(), victim_name: "Victim".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id: first_event_id+2, time_secs: None, source_hash: None },
+        ];
+        let checkpoint_path = std::env::temp_dir().join(format!("bll_checkpoints_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&checkpoint_path);
+        let checkpoint_config = || Arc::new(Config {
+            checkpoint_path: Some(checkpoint_path.clone()),
+            ..Arc::into_inner(basic_logic_config()).unwrap()
+        });
+
+        // scenario: the checkpoint is written mid-stream
+        let events = game(1, "Player1").into_iter().chain(game(4, "Player1")).collect();
+        let summaries_stream = SummaryLogic::new(checkpoint_config()).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let mut summaries = futures::executor::block_on_stream(summaries_stream);
+        summaries.next().expect("No summary was produced").expect("The summary shouldn't be an error");
+        let checkpoint = load_checkpoint(&checkpoint_path).expect("Couldn't load the checkpoint");
+        assert_eq!((checkpoint.global_stats.total_games, checkpoint.global_stats.total_kills), (1, 1), "The checkpoint wasn't written after the first game");
+        assert_eq!(checkpoint.last_event_id, Some(3), "The checkpoint should be at the first game's `ShutdownGame`");
+        drop(summaries);
+
+        // scenario: a new run (after a restart) replays the same feed -- which got a new game meanwhile
+        let events = game(1, "Player1").into_iter().chain(game(4, "Player1")).chain(game(7, "Player2")).collect();
+        let summaries_stream = SummaryLogic::new(checkpoint_config()).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        assert_eq!(futures::executor::block_on_stream(summaries_stream).count(), 2, "Only the games after the checkpoint should have been summarized");
+        let checkpoint = load_checkpoint(&checkpoint_path).expect("Couldn't load the checkpoint");
+        assert_eq!(checkpoint, Checkpoint {
+            global_stats: GlobalStats {
+                total_games: 3,
+                total_kills: 3,
+                player_frags: BTreeMap::from([
+                    ("Player1".to_owned(), 2),
+                    ("Player2".to_owned(), 1),
+                ]),
+                means_of_death: None,
+                games_by_map: BTreeMap::new(),
+            },
+            last_event_id: Some(9),
+        }, "The aggregation wasn't resumed from the checkpoint");
+        assert_eq!(checkpoint.skip_lines(1), 9, "All lines up to the last `ShutdownGame` could be skipped by the DAL");
+        let _ = std::fs::remove_file(&checkpoint_path);
+    }
+
+    /// Tests that every one of the [bll_api::supported_pipelines()] is accepted -- so it can't drift apart from the implementation
+    #[test]
+    fn supported_pipelines() {
+        for processor_pipeline in bll_api::supported_pipelines() {
+            let description = format!("{processor_pipeline:?}");
+            let logic = SummaryLogic::new(Config { processor_pipeline, ..Config::default() });
+            assert!(logic.summarize_games(TestDAL::new(vec![])).is_ok(), "The supported pipeline {description} was refused");
+        }
+        let logic = SummaryLogic::new(Config { processor_pipeline: HashSet::from([EventAnalyserOperations::MeansOfDeath]), ..Config::default() });
+        assert!(logic.summarize_games(TestDAL::new(vec![])).is_err(), "An unsupported pipeline was accepted");
+    }
+
+    /// Tests that [Config::min_players] drops the single-player game -- but not the following one
+    #[test]
+    fn min_players() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 2, time_secs: None, killer_id: 1022, victim_id: 1, reason_id: 1, killer_name: "<world>".into(), victim_name: "Player1".into(), reason_name: "MOD_FALLING".into() },
+            Quake3Events::ShutdownGame { event_id: 3, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 4, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 5, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::Kill         { event_id: 6, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 1, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "MOD_RAILGUN".into() },
+            Quake3Events::ShutdownGame { event_id: 7, time_secs: None, source_hash: None },
+        ];
+        let expected_summaries = vec![
+            GameMatchSummary {
+                total_kills: 2,
+                players: IndexSet::from(["Player1".to_owned(), "Player2".to_owned()]),
+                kills: IndexMap::from([("Player1".to_owned(), 1), ("Player2".to_owned(), 1)]),
+                total_participants: 2,
+                ..Default::default()
+            },
+        ];
+        let min_players_config = Config {
+            min_players: Some(2),
+            ..Config::default()
+        };
+        assert_mock_summaries(min_players_config.into(), events, expected_summaries)
+    }
+
+    /// Tests that [Config::max_total_games] rejects a log with more games than allowed -- erroring on the first one past the limit
+    #[test]
+    fn max_total_games() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: None, source: None },
+            Quake3Events::ShutdownGame { event_id: 2, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 3, time_secs: None, map_name: None, source: None },
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 5, time_secs: None, map_name: None, source: None },
+            Quake3Events::ShutdownGame { event_id: 6, time_secs: None, source_hash: None },
+        ];
+        let max_total_games_config = Config {
+            max_total_games: Some(2),
+            ..Config::default()
+        };
+        let summaries_stream = SummaryLogic::new(max_total_games_config).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let results: Vec<std::result::Result<u32, String>> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.map(|summary| summary.total_kills).map_err(|err| err.to_string()))
+            .collect();
+        assert_eq!(results, vec![
+            Ok(0),
+            Ok(0),
+            Err(String::from("Summary Logic: the log has more than the `max_total_games` of 2 game(s) -- it was rejected")),
+        ], "The third game should have been rejected");
+    }
+
+    /// Tests that [SummaryLogicApi::summarize_games_to_sink()] delivers all games, in order, to a slow async sink --
+    /// each delivery being awaited before the next game is summarized -- and that a failing sink stops the deliveries
+    #[tokio::test]
+    async fn slow_sink() {
+        let events = || vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: Some("q3dm1".into()), source: None },
+            Quake3Events::ShutdownGame { event_id: 2, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 3, time_secs: None, map_name: Some("q3dm2".into()), source: None },
+            Quake3Events::ShutdownGame { event_id: 4, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 5, time_secs: None, map_name: Some("q3dm3".into()), source: None },
+            Quake3Events::ShutdownGame { event_id: 6, time_secs: None, source_hash: None },
+        ];
+        let logic = SummaryLogic::new(basic_logic_config());
+        let delivered = Arc::new(Mutex::new(Vec::new()));
+        // how many deliveries are in progress -- backpressure means never more than one
+        let in_progress = Arc::new(Mutex::new(0));
+
+        logic.summarize_games_to_sink(TestDAL::new(events()), |game_id, summary| {
+            let delivered = Arc::clone(&delivered);
+            let in_progress = Arc::clone(&in_progress);
+            async move {
+                *in_progress.lock().unwrap() += 1;
+                assert_eq!(*in_progress.lock().unwrap(), 1, "A game was delivered while the sink was still busy with the previous one");
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                delivered.lock().unwrap().push((game_id, summary.map_name));
+                *in_progress.lock().unwrap() -= 1;
+                Ok(())
+            }
+        }).await.expect("All games should have been delivered");
+        assert_eq!(*delivered.lock().unwrap(), vec![
+            (1, Some("q3dm1".to_owned())),
+            (2, Some("q3dm2".to_owned())),
+            (3, Some("q3dm3".to_owned())),
+        ], "The games weren't delivered in order");
+
+        let mut delivered_count = 0;
+        let result = logic.summarize_games_to_sink(TestDAL::new(events()), |game_id, _summary| {
+            delivered_count += 1;
+            async move { if game_id == 2 { Err(Box::from("sink is gone")) } else { Ok(()) } }
+        }).await;
+        assert_eq!(result.map_err(|err| err.to_string()), Err(String::from("Summary Logic: the sink failed to take game_id 2: sink is gone")), "The sink error went unreported");
+        assert_eq!(delivered_count, 2, "No games should have been delivered after the sink failed");
+    }
+
+    /// Tests that [SummaryLogicApi::summarize_games_to_sink()] skips a game that couldn't be summarized -- going on with
+    /// the next ones, which keep their game ids -- unless [Config::stop_sink_on_errors] is set
+    #[tokio::test]
+    async fn sink_with_errored_game() {
+        let events = || vec![
+            Quake3Events::InitGame     { event_id: 1, time_secs: None, map_name: Some("q3dm1".into()), source: None },
+            Quake3Events::ShutdownGame { event_id: 2, time_secs: None, source_hash: None },
+            Quake3Events::ShutdownGame { event_id: 3, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 4, time_secs: None, map_name: Some("q3dm3".into()), source: None },
+            Quake3Events::ShutdownGame { event_id: 5, time_secs: None, source_hash: None },
+        ];
+
+        let mut delivered = Vec::new();
+        SummaryLogic::new(basic_logic_config()).summarize_games_to_sink(TestDAL::new(events()), |game_id, summary| {
+            delivered.push((game_id, summary.map_name));
+            async { Ok(()) }
+        }).await.expect("The errored game should have been skipped");
+        assert_eq!(delivered, vec![
+            (1, Some("q3dm1".to_owned())),
+            (3, Some("q3dm3".to_owned())),
+        ], "The games around the errored one weren't delivered");
+
+        let stopping_config = Config {
+            stop_sink_on_errors: true,
+            ..Config::default()
+        };
+        let mut delivered_count = 0;
+        let result = SummaryLogic::new(stopping_config).summarize_games_to_sink(TestDAL::new(events()), |_game_id, _summary| {
+            delivered_count += 1;
+            async { Ok(()) }
+        }).await;
+        assert_eq!(result.map_err(|err| err.to_string()),
+                   Err(String::from("Summary Logic: Error while summarizing game_id 2: Event #3: violated the event model: GameNotStarted")),
+                   "The summarizing error went unreported");
+        assert_eq!(delivered_count, 1, "No games should have been delivered after the errored one");
+    }
+
+    /// Tests that, under [Config::kill_distribution], the per-player frags' mean, median & standard deviation are computed
+    /// -- a single player game having no deviation & an empty game having no distribution at all
+    #[test]
+    fn kill_distribution() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id:  1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id:  2, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id:  3, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id:  4, time_secs: None, killer_id: 1, victim_id: 3, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player3".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id:  5, time_secs: None, killer_id: 3, victim_id: 1, reason_id: 1, killer_name: "Player3".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id:  6, time_secs: None, killer_id: 1022, victim_id: 2, reason_id: 22, killer_name: "<world>".into(), victim_name: "Player2".into(), reason_name: "MOD_TRIGGER_HURT".into() },
+            Quake3Events::ShutdownGame { event_id:  7, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id:  8, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id:  9, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id: 10, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id: 11, time_secs: None, map_name: None, source: None },
+            Quake3Events::ShutdownGame { event_id: 12, time_secs: None, source_hash: None },
+        ];
+        let kill_distribution_config = Config {
+            kill_distribution: true,
+            ..Config::default()
+        };
+        let summaries_stream = SummaryLogic::new(kill_distribution_config).summarize_games(TestDAL::new(events)).expect("sumarize_games() shouldn't fail here");
+        let kill_distributions: Vec<_> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected").kill_distribution)
+            .collect();
+        assert_eq!(kill_distributions, vec![
+            // frags: Player1: 3, Player2: -1, Player3: 1
+            Some(KillDistribution { mean: 1.0, median: 1.0, stddev: (8.0_f64 / 3.0).sqrt() }),
+            Some(KillDistribution { mean: 1.0, median: 1.0, stddev: 0.0 }),
+            None,
+        ], "Wrong kill distributions");
+    }
+
+    /// Tests that, under [Config::log_pipeline_composition], the composed stages are logged in the order they are applied
+    #[test]
+    fn log_pipeline_composition() {
+        static CAPTURED_LOGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+        struct CapturingLogger;
+        impl log::Log for CapturingLogger {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.level() <= log::Level::Debug
+            }
+            fn log(&self, record: &log::Record) {
+                if self.enabled(record.metadata()) {
+                    CAPTURED_LOGS.lock().unwrap().push(record.args().to_string());
+                }
+            }
+            fn flush(&self) {}
+        }
+        static LOGGER: CapturingLogger = CapturingLogger;
+        if log::set_logger(&LOGGER).is_ok() {
+            log::set_max_level(log::LevelFilter::Debug);
+        }
+
+        let full_pipeline_config = Config {
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::MeansOfDeath,
+                EventAnalyserOperations::KillTimeline,
+                EventAnalyserOperations::KillLog,
+                EventAnalyserOperations::Kills,
+                EventAnalyserOperations::PlayerIdsAndNickNamesResolutions,
+                EventAnalyserOperations::GameReportedScores,
+            ]),
+            min_players: Some(2),
+            group_rematches: true,
+            log_pipeline_composition: true,
+            ..Config::default()
+        };
+        let _summaries_stream = SummaryLogic::new(full_pipeline_config).summarize_games(TestDAL::new(vec![])).expect("sumarize_games() shouldn't fail here");
+        let expected_log = "Summary Logic: composed pipeline: compose -> means_of_death -> kill_timeline -> kill_log -> kills -> player_ids_and_nicknames_resolutions -> game_reported_scores -> summarize -> drop_small_games -> group_rematches";
+        // as the logger is global, other tests' logs may be captured as well
+        assert!(CAPTURED_LOGS.lock().unwrap().iter().any(|log| log == expected_log), "The composed pipeline wasn't logged as expected: {:?}", CAPTURED_LOGS.lock().unwrap());
+    }
+
+    /// Tests that consecutive games with the same set of players share the same series id
+    #[test]
+    fn rematches() {
+        let events = vec![
+            Quake3Events::InitGame     { event_id:  1, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id:  2, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id:  3, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 1, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id:  4, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id:  5, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id:  6, time_secs: None, killer_id: 2, victim_id: 1, reason_id: 1, killer_name: "Player2".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id:  7, time_secs: None, killer_id: 1, victim_id: 2, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player2".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id:  8, time_secs: None, source_hash: None },
+            Quake3Events::InitGame     { event_id:  9, time_secs: None, map_name: None, source: None },
+            Quake3Events::Kill         { event_id: 10, time_secs: None, killer_id: 1, victim_id: 3, reason_id: 1, killer_name: "Player1".into(), victim_name: "Player3".into(), reason_name: "NONE".into() },
+            Quake3Events::Kill         { event_id: 11, time_secs: None, killer_id: 3, victim_id: 1, reason_id: 1, killer_name: "Player3".into(), victim_name: "Player1".into(), reason_name: "NONE".into() },
+            Quake3Events::ShutdownGame { event_id: 12, time_secs: None, source_hash: None },
+        ];
+        let summary = |players: [&str; 2], series_id| GameMatchSummary {
+            total_kills: 2,
+            players: IndexSet::from(players.map(|name| name.to_owned())),
+            kills: IndexMap::from(players.map(|name| (name.to_owned(), 1))),
+            total_participants: 2,
+            series_id: Some(series_id),
+            ..Default::default()
+        };
+        let expected_summaries = vec![
+            summary(["Player1", "Player2"], 1),
+            summary(["Player1", "Player2"], 1),
+            summary(["Player1", "Player3"], 2),
+        ];
+        let rematches_config = Config {
+            group_rematches: true,
+            ..Config::default()
+        };
+        assert_mock_summaries(rematches_config.into(), events, expected_summaries)
+    }
+
 
 
 
@@ -883,6 +2639,8 @@ mod tests {
     /// The location of a log file suitable for a pedantic analysis, where all log lines should be parsed OK
     /// and the event structure must adhere 100% to the model
     const PEDANTIC_LOG_FILE_LOCATION: &str = "tests/resources/qgames_pedantic.log";
+    /// The original log file, with some broken lines
+    const PERMISSIVE_LOG_FILE_LOCATION: &str = "tests/resources/qgames_permissive.log";
 
 
     /// Assures that big log files fully correct -- fully respecting the log syntax and the events model --
@@ -896,7 +2654,11 @@ mod tests {
             ..Arc::into_inner(full_logic_config()).unwrap()
         };
 
-        let log_dao = Quake3LogFileSyncReader::new(dal_api::Config { debug: false }.into(), dal_api::FileReaderInfo { log_file_path: Cow::Borrowed(PEDANTIC_LOG_FILE_LOCATION) });
+        let dal_config = dal_api::Config {
+            validate_time_ranges: true,
+            ..dal_api::Config::default()
+        };
+        let log_dao = Quake3LogFileSyncReader::new(dal_config.into(), dal_api::FileReaderInfo { log_file_path: Cow::Borrowed(PEDANTIC_LOG_FILE_LOCATION) });
         let logic = SummaryLogic::new(pedantic_config);
         let summaries_stream = logic.summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
         let summaries: Vec<GameMatchSummary> = futures::executor::block_on_stream(summaries_stream).enumerate()
@@ -909,9 +2671,79 @@ mod tests {
     }
 
 
+    /// Tests that every game is tagged with the log file it was read from
+    #[test]
+    fn sources() {
+        let log_dao = Quake3LogFileSyncReader::new(Arc::new(dal_api::Config::default()), FileReaderInfo { log_file_path: Cow::Borrowed(PEDANTIC_LOG_FILE_LOCATION) });
+        let summaries_stream = SummaryLogic::new(basic_logic_config()).summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
+        let sources: Vec<Option<String>> = futures::executor::block_on_stream(summaries_stream)
+            .map(|summary_result| summary_result.expect("No errors were expected").source)
+            .collect();
+        assert_eq!(sources.len(), 20, "Number of game summaries don't match");
+        assert!(sources.iter().all(|source| source.as_deref() == Some(PEDANTIC_LOG_FILE_LOCATION)), "Games not tagged with their log file: {sources:?}");
+    }
+
+    /// Tests that the issues of the original log file are collected by [SummaryLogic::summarize_games_with_warnings()]
+    /// -- even if not logging them
+    #[test]
+    fn warnings_on_permissive_log() {
+        let permissive_config = Config {
+            log_issues: false,
+            ..Arc::into_inner(full_logic_config()).unwrap()
+        };
+        let log_dao = Quake3LogFileSyncReader::new(Arc::new(dal_api::Config::default()), dal_api::FileReaderInfo { log_file_path: Cow::Borrowed(PERMISSIVE_LOG_FILE_LOCATION) });
+        let (summaries_stream, warnings) = SummaryLogic::new(permissive_config).summarize_games_with_warnings(log_dao).expect("sumarize_games_with_warnings() shouldn't fail here");
+        assert!(warnings.lock().unwrap().is_empty(), "Warnings should only be collected as the `Stream` is consumed");
+        futures::executor::block_on_stream(summaries_stream).for_each(drop);
+        assert_eq!(*warnings.lock().unwrap(), vec![
+            Warning::FeedError {
+                event_id: 97,
+                description: r#"`LogParsingError` when processing log file 'tests/resources/qgames_permissive.log' at line 97: EventParsingError { event_name: " 0", event_parsing_error: UnknownEventName }"#.to_owned(),
+            },
+        ], "Unexpected warnings");
+    }
+
     // helper functions
     ///////////////////
 
+    /// Tests that [means_of_death()] yields the very same summaries for the permissive log as its former formulation --
+    /// a sub-stream per event, through `flat_map()` -- which [FlatMapPair] replaced for performance reasons
+    #[test]
+    fn means_of_death_as_flat_map() {
+        type CompositeStream = Pin<Box<dyn Stream<Item=CompositeEvent<'static>>>>;
+        let flat_mapped_means_of_death = |stream: CompositeStream| stream
+            .map(|composite_event| {
+                let mean_of_death = match &composite_event {
+                    CompositeEvent::GameEvent(Quake3Events::Kill { event_id, reason_name, .. }) =>
+                        Some(CompositeEvent::LogicEvent(LogicEvents::MeanOfDeath {
+                            quake3_event_id: *event_id,
+                            mean_of_death: if reason_name.is_empty() { Cow::Borrowed("UNKNOWN") } else { reason_name.clone() },
+                        })),
+                    _ => None,
+                };
+                match mean_of_death {
+                    Some(mean_of_death) => [Some(mean_of_death), Some(composite_event)],
+                    None => [Some(composite_event), None],
+                }
+            })
+            .flat_map(stream::iter)
+            .filter_map(future::ready);
+
+        let logic = SummaryLogic::new(full_logic_config());
+        let summaries = |means_of_death: &dyn Fn(CompositeStream) -> CompositeStream| {
+            let log_dao = Quake3LogFileSyncReader::new(dal_api::Config::default().into(), FileReaderInfo { log_file_path: Cow::Borrowed(PERMISSIVE_LOG_FILE_LOCATION) });
+            let stream = means_of_death(Box::pin(logic.compose(log_dao).expect("compose() shouldn't fail here")));
+            let summaries_stream = logic.summarize(logic.game_reported_scores(logic.player_ids_and_nicknames_resolutions(logic.kills(stream))));
+            futures::executor::block_on_stream(summaries_stream)
+                .map(|summary_result| summary_result.map_err(|err| err.to_string()))
+                .collect::<Vec<_>>()
+        };
+        let expected_summaries = summaries(&|stream| Box::pin(flat_mapped_means_of_death(stream)));
+        let observed_summaries = summaries(&|stream| Box::pin(logic.means_of_death(stream)));
+        assert!(expected_summaries.iter().any(|summary| summary.as_ref().is_ok_and(|summary| summary.means_of_death.is_some())), "The means of death should have been summarized");
+        assert_eq!(observed_summaries, expected_summaries, "The means of death processor diverged from its `flat_map()` formulation");
+    }
+
     fn full_logic_config() -> Arc<Config> {
         Arc::new(Config {
             processor_pipeline: HashSet::from([
@@ -958,7 +2790,7 @@ mod tests {
     }
 
     fn _assert_integrated_summaries(config: Arc<Config>, log_file_path: &'static str, expected_summaries: Vec<GameMatchSummary>) {
-        let log_dao = Quake3LogFileSyncReader::new(dal_api::Config { debug: false }.into(), FileReaderInfo { log_file_path: Cow::Borrowed(log_file_path) });
+        let log_dao = Quake3LogFileSyncReader::new(dal_api::Config::default().into(), FileReaderInfo { log_file_path: Cow::Borrowed(log_file_path) });
         let logic = SummaryLogic::new(config);
         let summaries_stream = logic.summarize_games(log_dao).expect("sumarize_games() shouldn't fail here");
         let summaries: Vec<GameMatchSummary> = futures::executor::block_on_stream(summaries_stream).enumerate()
@@ -0,0 +1,68 @@
+//! Contains [weapon_meta()], the global means of death distribution across all games
+
+use bll_api::Config;
+use common::types::Result;
+use dal_api::Quake3ServerEvents;
+use model::quake3_events::Quake3Events;
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+};
+
+
+/// Counts the means of death of all kills yielded by `log_dao` -- across every game -- returning, for each mean,
+/// its number of kills & its percentage of the total.\
+/// As in the means of death operation, empty reasons are counted as [Config::unknown_means_label].
+/// Feed errors are skipped, unless [Config::stop_on_feed_errors] is set -- in which case the first one is returned.
+pub fn weapon_meta<IntoArcConfig: Into<Arc<Config>>>(config: IntoArcConfig, log_dao: Box<dyn Quake3ServerEvents>) -> Result<BTreeMap<String, (u32, f64)>> {
+    let config = config.into();
+    let events_stream = log_dao.events_stream()
+        .map_err(|err| format!("weapon_meta(): failed at fetching the Quake 3 Server events `Stream`: {err}"))?;
+
+    let mut kills_per_means = BTreeMap::<String, u32>::new();
+    for event in futures::executor::block_on_stream(events_stream) {
+        match event {
+            Quake3Events::Kill { reason_name, .. } => {
+                let mean_of_death = if reason_name.is_empty() { config.unknown_means_label.clone() } else { reason_name.into_owned() };
+                *kills_per_means.entry(mean_of_death).or_default() += 1;
+            },
+            Quake3Events::Error { event_id, err } if config.stop_on_feed_errors =>
+                return Err(Box::from(format!("weapon_meta(): Event #{event_id}: Feed error: {err}"))),
+            _ => (),
+        }
+    }
+    let total_kills = kills_per_means.values().sum::<u32>();
+    Ok(kills_per_means.into_iter()
+        .map(|(mean_of_death, kills)| (mean_of_death, (kills, 100.0 * kills as f64 / total_kills as f64)))
+        .collect())
+}
+
+
+/// Unit tests the [weapon_meta](super) module
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dal_api::FileReaderInfo;
+    use dal::sync_file_reader::Quake3LogFileSyncReader;
+    use std::borrow::Cow;
+
+
+    /// A log file with all lines OK -- 20 games
+    const PEDANTIC_LOG_FILE_LOCATION: &str = "tests/resources/qgames_pedantic.log";
+
+
+    /// Tests the distribution of the means of death across all games of the pedantic log
+    #[test]
+    fn pedantic_weapon_meta() {
+        let log_dao = Quake3LogFileSyncReader::new(Arc::new(dal_api::Config::default()), FileReaderInfo { log_file_path: Cow::Borrowed(PEDANTIC_LOG_FILE_LOCATION) });
+        let weapon_meta = weapon_meta(Config::default(), log_dao)
+            .expect("weapon_meta() shouldn't fail here");
+        let (most_common_means, (kills, _percentage)) = weapon_meta.iter()
+            .max_by_key(|(_mean_of_death, (kills, _percentage))| *kills)
+            .expect("No means of death were found");
+        assert_eq!((most_common_means.as_str(), *kills), ("MOD_ROCKET_SPLASH", 346), "Wrong most common means of death");
+        let total_percentage = weapon_meta.values().map(|(_kills, percentage)| percentage).sum::<f64>();
+        assert!((total_percentage - 100.0).abs() < 1e-9, "Percentages should sum up to 100, not {total_percentage}");
+    }
+
+}
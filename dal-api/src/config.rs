@@ -1,13 +1,74 @@
 //! Resting place for DAL's [Config] & friends
 
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    path::PathBuf,
+    time::Duration,
+};
 
 /// Configuration for the DAL crate
+#[derive(Clone)]
 pub struct Config {
 
     /// Outputs the data given out to users
     pub debug: bool,
 
+    /// If true, each log line's `MM:SS` time must be present, with minutes up to [Self::max_log_minutes]
+    /// -- otherwise, the line yields an `UnparseableTime` error. Seconds beyond 59 are always rejected that way.\
+    /// Suitable for pedantic processing.
+    pub validate_time_ranges: bool,
+
+    /// The maximum minutes accepted in log line times when [Self::validate_time_ranges] is set -- `None` for no limit
+    pub max_log_minutes: Option<u32>,
+
+    /// If true, `InitGame` lines must carry their key/value data -- with, at least, the `mapname` -- otherwise yielding
+    /// an `AbsentKey` error instead of an `InitGame` event with no info. Suitable for pedantic processing.
+    pub strict_init_game: bool,
+
+    /// If set, lines longer than this many bytes are truncated -- their remaining bytes are skipped, without being buffered --
+    /// and yield an error instead of an event. Guards against corrupt logs having giant lines.\
+    /// Limitation: ignored by the watched directory reader.
+    pub max_line_length: Option<usize>,
+
+    /// If true, bytes not forming valid UTF-8 -- such as the Latin-1 player names some servers write -- are decoded as
+    /// the `U+FFFD` replacement character, rather than failing the whole line with an IO read error.\
+    /// Limitation: ignored by the framed socket & watched directory readers.
+    pub lossy_utf8: bool,
+
+    /// The id of the first event (and the number of the first line, in error messages) -- 1 by default.
+    /// Set it to 0 to cross-reference with tools using 0-based line numbers
+    pub event_id_base: u32,
+
+    /// The number of leading lines to skip -- for resuming an analysis. The event ids (& line numbers) of the remaining lines
+    /// are kept aligned to their positions in the original feed -- for chained files, the lines are counted across all of them.\
+    /// Limitation: ignored by the framed socket & watched directory readers.
+    pub skip_lines: usize,
+
+    /// If set, the log lines are split into their parts by this regex, instead of the standard `MM:SS EVENT: DATA` format --
+    /// for nonstandard logs, having different times or prefixes. The named groups `minute`, `event_name` & `data` are required,
+    /// while `hour` & `second` are optional -- readers fail at `Stream` creation if any required group is missing.\
+    /// [Self::validate_time_ranges] & [Self::strict_init_game] apply just as well, the former limiting the total minutes.\
+    /// Requires the `regex` feature of the `dal` crate: readers fail at `Stream` creation without it.
+    pub log_line_pattern: Option<String>,
+
+    /// If true, the raw lines of each game -- from its `InitGame` through its `ShutdownGame`, unparseable ones included --
+    /// are hashed with SHA-256, for provenance: the lowercase hex digest is given out in the `ShutdownGame` event's `source_hash`
+    pub hash_source_lines: bool,
+
+    /// If true, a `StreamEnd` event -- carrying the number of lines read -- is given out as the last event of `Stream`s
+    /// that reach the end of their input: consumers not seeing it know the `Stream` was aborted.\
+    /// Limitation: never given out by the framed socket & watched directory readers.
+    pub stream_end: bool,
+
+    /// If set, the sync file reader doesn't end its `Stream` upon reaching the end of the file: like `tail -f`, it keeps the file
+    /// open, polling for appended lines with this interval between attempts -- so the `Stream` never ends.\
+    /// Limitation: the async file reader fails at `Stream` creation if this is set.
+    pub follow: Option<Duration>,
+
+    /// Size, in bytes, for buffering the IO of the file & stdin readers -- the larger, more RAM is used, but fewer
+    /// system calls / context switches / hardware requests are required. 1MB by default
+    pub buffer_size: usize,
+
 }
 
 /// Here are some implementations -- real and imaginary examples of the flexibility this architecture brings
@@ -18,6 +79,13 @@ pub enum Quake3ServerEventsImplementations<'a> {
     SyncLogFileReader(FileReaderInfo<'a>),
     /// Reads events from Quake 3 server log files, using the async / tokio APIs
     AsyncLogFileReader(FileReaderInfo<'a>),
+    /// Reads events from several Quake 3 server log files, in order, as a single feed -- using the sync / std APIs
+    MultiFileReader(Vec<PathBuf>),
+    /// Reads events from the Quake 3 server log files in `dir` whose names match the glob `pattern` (`games*.log`, for instance),
+    /// sorted by name, as a single feed. Unless `strict`, files that can't be opened yield an error event, instead of failing it all
+    GlobReader { dir: PathBuf, pattern: String, strict: bool },
+    /// Reads events from a live Quake 3 server log, served as newline-delimited lines by a TCP server at the given `host:port`
+    TcpReader(String),
     // /// Reads events (as presented in a log file) using the sync / std buffered reader
     // SyncReader { reader: Box<dyn std::io::BufRead> },
     // /// Reads events (as presented in a log file) using the sync / tokio buffered reader
@@ -30,6 +98,18 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             debug: false,
+            validate_time_ranges: false,
+            max_log_minutes: None,
+            strict_init_game: false,
+            max_line_length: None,
+            lossy_utf8: true,
+            event_id_base: 1,
+            skip_lines: 0,
+            log_line_pattern: None,
+            hash_source_lines: false,
+            stream_end: false,
+            follow: None,
+            buffer_size: 1024*1024,
         }
     }
 }
@@ -1 +1,197 @@
-//! For concept teaser...
\ No newline at end of file
+//! Resting place for [Quake3LogFileAsyncReader] -- enabled by the `tokio` feature
+
+
+use crate::{
+    events_translation::{log_line_deserializer, translate_quake3_events},
+    sync_file_reader::GZIP_MAGIC,
+    sync_reader::{LineAccumulator, ReadLine},
+};
+use common::types::Result;
+use model::quake3_events::Quake3Events;
+use dal_api::{Config, FileReaderInfo, Quake3ServerEvents};
+use std::{
+    io::{ErrorKind, Read, Seek},
+    pin::Pin,
+    sync::Arc,
+};
+use futures::{Stream, stream, StreamExt};
+use log::info;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader};
+
+
+/// [Quake3ServerEvents] implementation for reading Quake 3 Server events from a log file, using the async / tokio APIs:
+/// the lines are read without blocking, but are fed to the same deserializer used by [crate::sync_reader::Quake3LogSyncReader]
+/// -- honoring the same [Config] options, except for [Config::follow] & gzip-compressed files, which are refused at `Stream` creation.\
+/// The returned `Stream` must be polled from within a tokio runtime.
+pub struct Quake3LogFileAsyncReader<'a> {
+    config: Arc<Config>,
+    params: FileReaderInfo<'a>,
+}
+
+impl<'a> Quake3LogFileAsyncReader<'a> {
+
+    pub fn new(config: Arc<Config>, params: FileReaderInfo<'a>) -> Box<Self> {
+        Box::new(Self {
+            config,
+            params,
+        })
+    }
+
+}
+
+impl Quake3ServerEvents for Quake3LogFileAsyncReader<'static> {
+
+    fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
+        let log_file_path = self.params.log_file_path.to_string();
+        if self.config.follow.is_some() {
+            return Err(Box::from(format!("Couldn't read Quake3 Server log file '{log_file_path}': following the file is not supported by the async reader")))
+        }
+        // opening is done synchronously, so failures are reported at `Stream` creation -- just like the sync reader does
+        let mut file = std::fs::File::open(&log_file_path)
+            .map_err(|err| format!("Couldn't open Quake3 Server log file '{log_file_path}' for reading: {err}"))?;
+        let mut magic = [0; GZIP_MAGIC.len()];
+        if log_file_path.ends_with(".gz") || (file.read_exact(&mut magic).is_ok() && magic == GZIP_MAGIC) {
+            return Err(Box::from(format!("Couldn't read Quake3 Server log file '{log_file_path}': gzip-compressed files are not supported by the async reader")))
+        }
+        file.rewind()
+            .map_err(|err| format!("Couldn't rewind Quake3 Server log file '{log_file_path}': {err}"))?;
+        let reader = BufReader::with_capacity(self.config.buffer_size, tokio::fs::File::from_std(file));
+
+        let debug = self.config.debug;
+        let max_line_length = self.config.max_line_length;
+        let lossy_utf8 = self.config.lossy_utf8;
+        let skip_lines = self.config.skip_lines;
+        let deserialize_log_line = log_line_deserializer(&self.config)?;
+        // line numbers & event ids are the same -- based as configured
+        let first_line_number = self.config.event_id_base as usize;
+        let source_name = log_file_path.clone();
+        let stream = stream::unfold(reader, move |mut reader| async move {
+                read_line(&mut reader, max_line_length, lossy_utf8).await
+                    .map(|line_result| (line_result, reader))
+            })
+            .enumerate()
+            .skip(skip_lines)
+            .map(move |(line_number, line_result)| line_result
+                .map_err(|read_err| format!("IO read error when processing log file '{source_name}' at line {}: {read_err:?}", first_line_number+line_number))
+                .and_then(|read_line| match read_line {
+                    ReadLine::Line(line) => Ok(line),
+                    ReadLine::Oversized { length } => Err(format!("`LogParsingError` when processing log file '{source_name}' at line {}: line length of {length} bytes exceeds the maximum of {} -- truncated", first_line_number+line_number, max_line_length.unwrap_or_default())),
+                })
+                .and_then(|line| deserialize_log_line(&line)
+                    .map_err(|log_parser_err| format!("`LogParsingError` when processing log file '{source_name}' at line {}: {log_parser_err:?}", first_line_number+line_number)))
+                .map_err(Box::from));
+        let stream = translate_quake3_events(stream, self.config.event_id_base + skip_lines as u32, &log_file_path, self.config.stream_end);
+        let stream: Pin<Box<dyn Stream<Item=Quake3Events<'static>>>> = if debug {
+            Box::pin(stream
+                .inspect(|yielded_event| info!("{yielded_event:?}")))
+        } else {
+            Box::pin(stream)
+        };
+        Ok(stream)
+    }
+
+}
+
+/// The async counterpart of [crate::sync_reader]'s `read_line()`: reads the next line from `reader`, buffering at most
+/// `max_line_length` bytes of it & decoding invalid UTF-8 as replacement characters, if `lossy_utf8`.\
+/// Returns `None` at the end of the input.
+async fn read_line(reader: &mut (impl AsyncBufRead + Unpin), max_line_length: Option<usize>, lossy_utf8: bool) -> Option<std::io::Result<ReadLine>> {
+    let mut line_accumulator = LineAccumulator::default();
+    loop {
+        let buffer = match reader.fill_buf().await {
+            Ok(buffer) => buffer,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Some(Err(err)),
+        };
+        if buffer.is_empty() {
+            break
+        }
+        let (consumed, is_complete) = line_accumulator.feed(buffer, max_line_length);
+        reader.consume(consumed);
+        if is_complete {
+            break
+        }
+    }
+    line_accumulator.finish(lossy_utf8)
+}
+
+
+/// Unit tests the [async_file_reader](super) implementation of [dal_api::Quake3ServerEvents]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync_file_reader::Quake3LogFileSyncReader;
+    use std::{borrow::Cow, time::Duration};
+
+
+    /// The location of a good log file, with all lines OK
+    const GOOD_LOG_FILE_LOCATION: &str = "tests/resources/qgames_excerpt.log";
+    /// A log file with some malformed lines
+    const MALFORMED_LOG_FILE_LOCATION: &str = "tests/resources/malformed_line.log";
+    /// A log file with a Latin-1 -- invalid UTF-8 -- player name
+    const LATIN1_LOG_FILE_LOCATION: &str = "tests/resources/latin1_name.log";
+    /// The same as [GOOD_LOG_FILE_LOCATION], gzip-compressed
+    const GZIPPED_LOG_FILE_LOCATION: &str = "tests/resources/qgames_excerpt.log.gz";
+    /// A path to a file that simply isn't there
+    const NON_EXISTING_FILE_LOCATION: &str = "/tmp/non-existing.log";
+
+
+    /// Tests that an existing & valid file may be correctly read from beginning to end, without blocking
+    #[tokio::test]
+    async fn read_file() {
+        let log_dao = Quake3LogFileAsyncReader::new(Arc::new(Config::default()), FileReaderInfo { log_file_path: Cow::Borrowed(GOOD_LOG_FILE_LOCATION) });
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events_count = stream
+            .inspect(|event| assert!(event.is_ok(), "Parsing log line #{} yielded a unexpected error {event:?}", event.event_id()))
+            .count().await;
+        assert_eq!(events_count, 93, "Unexpected number of parsed log lines");
+    }
+
+    /// Tests that opening a non-existing file fails at `Stream` creation, with the same message as the sync reader
+    #[tokio::test]
+    async fn non_existing_file() {
+        let expected_err = "Couldn't open Quake3 Server log file '/tmp/non-existing.log' for reading: No such file or directory (os error 2)";
+        let log_dao = Quake3LogFileAsyncReader::new(Arc::new(Config::default()), FileReaderInfo { log_file_path: Cow::Borrowed(NON_EXISTING_FILE_LOCATION) });
+        match log_dao.events_stream() {
+            Ok(_stream) => panic!("Opening a non-existing file was expected to fail at `Stream` creation, but the operation succeeded"),
+            Err(stream_creation_err) => assert_eq!(stream_creation_err.to_string(), expected_err.to_string(), "Unexpected `Stream` creation error"),
+        }
+    }
+
+    /// Tests that the [Config] options are honored just like the sync reader does -- by comparing the events of both
+    /// -- while the unsupported ones are refused at `Stream` creation
+    #[tokio::test]
+    async fn same_options_as_the_sync_reader() {
+        for (options_description, config, log_file_path) in [
+            ("stream_end", Config { stream_end: true, ..Config::default() }, GOOD_LOG_FILE_LOCATION),
+            ("skip_lines", Config { skip_lines: 2, event_id_base: 0, ..Config::default() }, MALFORMED_LOG_FILE_LOCATION),
+            ("max_line_length", Config { max_line_length: Some(32), ..Config::default() }, GOOD_LOG_FILE_LOCATION),
+            ("lossy_utf8", Config { lossy_utf8: true, ..Config::default() }, LATIN1_LOG_FILE_LOCATION),
+            ("strict utf8", Config { lossy_utf8: false, ..Config::default() }, LATIN1_LOG_FILE_LOCATION),
+        ] {
+            let config = Arc::new(config);
+            let sync_stream = Quake3LogFileSyncReader::new(Arc::clone(&config), FileReaderInfo { log_file_path: Cow::Borrowed(log_file_path) })
+                .events_stream().expect("Couldn't create the sync `Stream`");
+            let sync_events: Vec<String> = futures::executor::block_on_stream(sync_stream)
+                .map(|event| format!("{event:?}"))
+                .collect();
+            let async_events: Vec<String> = Quake3LogFileAsyncReader::new(config, FileReaderInfo { log_file_path: Cow::Borrowed(log_file_path) })
+                .events_stream().expect("Couldn't create the async `Stream`")
+                .map(|event| format!("{event:?}"))
+                .collect().await;
+            assert_eq!(async_events, sync_events, "The async reader diverged from the sync one for {options_description} on '{log_file_path}'");
+        }
+
+        let stream_creation_err = |config, log_file_path| Quake3LogFileAsyncReader::new(Arc::new(config), FileReaderInfo { log_file_path: Cow::Borrowed(log_file_path) })
+            .events_stream()
+            .err()
+            .map(|err| err.to_string());
+        assert_eq!(stream_creation_err(Config::default(), GZIPPED_LOG_FILE_LOCATION),
+                   Some("Couldn't read Quake3 Server log file 'tests/resources/qgames_excerpt.log.gz': gzip-compressed files are not supported by the async reader".to_owned()),
+                   "gzip-compressed files should have been refused");
+        assert_eq!(stream_creation_err(Config { follow: Some(Duration::from_millis(100)), ..Config::default() }, GOOD_LOG_FILE_LOCATION),
+                   Some("Couldn't read Quake3 Server log file 'tests/resources/qgames_excerpt.log': following the file is not supported by the async reader".to_owned()),
+                   "Following the file should have been refused");
+    }
+
+}
@@ -3,37 +3,127 @@
 
 use common::types::Result;
 use model::quake3_events::Quake3Events;
-use quake3_server_events::types::Quake3FullEvents;
-use std::future;
-use futures::{Stream, StreamExt};
+use dal_api::Config;
+use quake3_server_events::{
+    deserializer_logs::{deserialize_log_line_with_options, DeserializationOptions, LogParsingError, TimeRanges},
+    types::Quake3FullEvents,
+};
+#[cfg(feature = "regex")]
+use quake3_server_events::deserializer_logs::{deserialize_log_line_with_regex, log_line_regex};
+use std::{
+    borrow::Cow,
+    future,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+};
+use futures::{Stream, stream, StreamExt};
+use sha2::{Digest, Sha256};
 
 
+/// What the `quake3-server-events` library yields for each log line: the event, along with its time in seconds -- plus,
+/// for `ShutdownGame` events, the hash of the game's lines, if [Config::hash_source_lines] is set
+pub type DeserializedLogLine = std::result::Result<(Option<u32>, Quake3FullEvents<'static>, Option<String>), LogParsingError>;
+
+/// Returns the log line deserializer to be used by the readers, as given by `config`: the standard one -- validating
+/// the time ranges & `InitGame`s, if so configured -- or the one driven by [Config::log_line_pattern].\
+/// If [Config::hash_source_lines] is set, the deserializer also keeps the hash of the current game's lines -- so it should
+/// be given all the lines, in order.\
+/// Fails if the custom pattern is invalid or lacks the required named groups -- or if the `regex` feature is off.
+pub fn log_line_deserializer(config: &Config) -> Result<impl Fn(&str) -> DeserializedLogLine> {
+    let time_ranges = config.validate_time_ranges
+        .then_some(TimeRanges { max_minutes: config.max_log_minutes });
+    #[cfg(feature = "regex")]
+    let log_line_regex = config.log_line_pattern.as_deref()
+        .map(|pattern| log_line_regex(pattern)
+            .map_err(|err| format!("Invalid custom log line pattern '{pattern}': {err:?}")))
+        .transpose()?;
+    #[cfg(not(feature = "regex"))]
+    if config.log_line_pattern.is_some() {
+        return Err(Box::from("log_line_deserializer(): custom log line patterns require the `regex` feature of the `dal` crate"))
+    }
+    let strict_init_game = config.strict_init_game;
+    let options = DeserializationOptions { time_ranges, strict_init_game, ..DeserializationOptions::default() };
+    let hash_source_lines = config.hash_source_lines;
+    // the hasher of the current game's lines -- present only while in a game
+    let game_hasher = Mutex::new(None::<Sha256>);
+    Ok(move |log_line: &str| {
+        #[cfg(feature = "regex")]
+        let deserialized_log_line = match &log_line_regex {
+            Some(log_line_regex) => deserialize_log_line_with_regex(log_line, log_line_regex, &options),
+            None => deserialize_log_line_with_options(log_line, &options),
+        };
+        #[cfg(not(feature = "regex"))]
+        let deserialized_log_line = deserialize_log_line_with_options(log_line, &options);
+        let source_hash = if hash_source_lines {
+            hash_game_line(&mut game_hasher.lock().unwrap_or_else(PoisonError::into_inner), log_line, deserialized_log_line.as_ref().ok().map(|(_time_secs, event)| event))
+        } else {
+            None
+        };
+        deserialized_log_line
+            .map(|(time_secs, event)| (time_secs, event, source_hash))
+    })
+}
+
+/// Feeds `log_line` to the `game_hasher` -- which is (re)started by `InitGame` events & consumed by `ShutdownGame` ones,
+/// whose lines are included: for these, the lowercase hex digest of the game's lines is returned
+fn hash_game_line(game_hasher: &mut Option<Sha256>, log_line: &str, event: Option<&Quake3FullEvents>) -> Option<String> {
+    if let Some(Quake3FullEvents::InitGame { .. }) = event {
+        *game_hasher = Some(Sha256::new());
+    }
+    let hasher = game_hasher.as_mut()?;
+    hasher.update(log_line.as_bytes());
+    hasher.update(b"\n");
+    if let Some(Quake3FullEvents::ShutdownGame) = event {
+        let digest = game_hasher.take()?.finalize();
+        Some(digest.iter().map(|byte| format!("{byte:02x}")).collect())
+    } else {
+        None
+    }
+}
+
 /// Receives a `Stream` of the Quake3 events produced by the `quake3-server-events` library and
-/// simplifies & translates them into another `Stream` of our [model::quake3_events::Quake3Events]
-pub fn translate_quake3_events<'a>(lib_events: impl Stream<Item=Result<Quake3FullEvents<'a>>>) -> impl Stream<Item=Quake3Events<'a>> {
-    let mut event_id = 0;
+/// simplifies & translates them into another `Stream` of our [model::quake3_events::Quake3Events] -- the library events come along with their log time, in seconds,
+/// and the `ShutdownGame` ones, with the hash of the game's lines (see [log_line_deserializer()]).\
+/// The produced events are numbered from `first_event_id` on -- allowing several sources to be chained with unique ids --
+/// and the games are tagged with the `source_name` they come from.\
+/// If `stream_end`, a [Quake3Events::StreamEnd] is appended once `lib_events` ends, with the number of library events -- lines -- it had.
+pub fn translate_quake3_events<'a>(lib_events: impl Stream<Item=Result<(Option<u32>, Quake3FullEvents<'a>, Option<String>)>>, first_event_id: u32, source_name: &str, stream_end: bool) -> impl Stream<Item=Quake3Events<'a>> {
+    let next_event_id = Arc::new(AtomicU32::new(first_event_id));
+    let end_event_id = Arc::clone(&next_event_id);
+    let source_name = source_name.to_owned();
+    let stream_end = stream::iter(stream_end.then_some(()))
+        // evaluated only after `lib_events` is over
+        .map(move |_| {
+            let event_id = end_event_id.load(Ordering::Relaxed);
+            Some(Quake3Events::StreamEnd { event_id, total_lines: event_id - first_event_id })
+        });
     lib_events
         .map(move |event_result| {
-            event_id += 1;
-            let Ok(event) = event_result
+            let event_id = next_event_id.fetch_add(1, Ordering::Relaxed);
+            let Ok((time_secs, event, source_hash)) = event_result
                 else {
                     return Some(Quake3Events::Error { event_id, err: event_result.unwrap_err() })
                 };
             match event {
-                Quake3FullEvents::InitGame { .. } => Some(Quake3Events::InitGame { event_id }),
-                Quake3FullEvents::ClientConnect { id } => Some(Quake3Events::ClientConnect { event_id, client_id: id }),
-                Quake3FullEvents::ClientUserinfoChanged { id, name } => Some(Quake3Events::ClientUserinfoChanged { event_id, client_id: id, name }),
+                Quake3FullEvents::InitGame { map_name, .. } => Some(Quake3Events::InitGame { event_id, time_secs, map_name, source: Some(Cow::Owned(source_name.clone())) }),
+                Quake3FullEvents::ClientConnect { id } => Some(Quake3Events::ClientConnect { event_id, time_secs, client_id: id }),
+                Quake3FullEvents::ClientUserinfoChanged { id, name } => Some(Quake3Events::ClientUserinfoChanged { event_id, time_secs, client_id: id, name }),
                 Quake3FullEvents::ClientBegin { .. } => None,
-                Quake3FullEvents::ClientDisconnect { id } => Some(Quake3Events::ClientDisconnect { event_id, client_id: id }),
-                Quake3FullEvents::Item => None,
-                Quake3FullEvents::Say => None,
-                Quake3FullEvents::Kill { killer_id, victim_id, reason_id, killer_name, victim_name, reason_name } => Some(Quake3Events::Kill { event_id, killer_id, victim_id, reason_id, killer_name, victim_name, reason_name }),
-                Quake3FullEvents::Exit => Some(Quake3Events::Exit { event_id }),
+                Quake3FullEvents::ClientDisconnect { id } => Some(Quake3Events::ClientDisconnect { event_id, time_secs, client_id: id }),
+                Quake3FullEvents::Item { .. } => Some(Quake3Events::Item { event_id, time_secs }),
+                Quake3FullEvents::Say { name, message } => Some(Quake3Events::Say { event_id, time_secs, name, message }),
+                Quake3FullEvents::Tell { from, to, message } => Some(Quake3Events::Tell { event_id, time_secs, from, to, message }),
+                Quake3FullEvents::Kill { killer_id, victim_id, reason_id, killer_name, victim_name, reason_name } => Some(Quake3Events::Kill { event_id, time_secs, killer_id, victim_id, reason_id, killer_name, victim_name, reason_name }),
+                Quake3FullEvents::Exit { .. } => Some(Quake3Events::Exit { event_id, time_secs }),
                 Quake3FullEvents::CaptureTheFlagResults { .. } => None,
-                Quake3FullEvents::Score { frags, id, name } => Some(Quake3Events::Score { event_id, frags, client_id: id, name }),
-                Quake3FullEvents::ShutdownGame => Some(Quake3Events::ShutdownGame { event_id }),
+                Quake3FullEvents::Score { frags, id, name } => Some(Quake3Events::Score { event_id, time_secs, frags, client_id: id, name }),
+                Quake3FullEvents::ShutdownGame => Some(Quake3Events::ShutdownGame { event_id, time_secs, source_hash: source_hash.map(Cow::Owned) }),
                 Quake3FullEvents::Comment => None,
+                Quake3FullEvents::Ignored { .. } => None,
             }
         })
-        .filter_map(|our_event_option| future::ready(our_event_option))
+        .chain(stream_end)
+        .filter_map(future::ready)
 }
\ No newline at end of file
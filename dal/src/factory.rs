@@ -1,5 +1,6 @@
 //! Factory for obtaining one of the implementations of our DAO objects
 
+use common::types::Result;
 use dal_api::{
     Config,
     Quake3ServerEvents,
@@ -9,11 +10,50 @@ use std::sync::Arc;
 
 
 /// Instantiates a Data Access Object (dao) able retrieve data from the given `implementation` source
-pub fn instantiate_log_dao(implemetation: Quake3ServerEventsImplementations<'static>, config: Arc<Config>) -> Box<dyn Quake3ServerEvents + 'static> {
-    match implemetation {
+/// -- failing if the `implementation` depends on a feature of this crate that wasn't enabled
+pub fn instantiate_log_dao(implemetation: Quake3ServerEventsImplementations<'static>, config: Arc<Config>) -> Result<Box<dyn Quake3ServerEvents + 'static>> {
+    Ok(match implemetation {
         Quake3ServerEventsImplementations::StdinReader => crate::stdin_reader::Quake3LogFileStdinReader::new(config),
         Quake3ServerEventsImplementations::SyncLogFileReader(params) => crate::sync_file_reader::Quake3LogFileSyncReader::new(config, params),
-        Quake3ServerEventsImplementations::AsyncLogFileReader(_params) => todo!("Not implemented for this exercise"),
+        #[cfg(feature = "tokio")]
+        Quake3ServerEventsImplementations::AsyncLogFileReader(params) => crate::async_file_reader::Quake3LogFileAsyncReader::new(config, params),
+        #[cfg(not(feature = "tokio"))]
+        Quake3ServerEventsImplementations::AsyncLogFileReader(_params) => return Err(Box::from("instantiate_log_dao(): the async reader requires the `tokio` feature of the `dal` crate")),
+        Quake3ServerEventsImplementations::MultiFileReader(log_file_paths) => crate::multi_file_reader::Quake3LogMultiFileSyncReader::new(config, log_file_paths, true),
+        Quake3ServerEventsImplementations::GlobReader { dir, pattern, strict } => crate::glob_reader::Quake3LogGlobReader::new(config, dir, pattern, strict),
+        #[cfg(feature = "socket")]
+        Quake3ServerEventsImplementations::TcpReader(address) => crate::tcp_reader::Quake3LogTcpReader::new(config, address),
+        #[cfg(not(feature = "socket"))]
+        Quake3ServerEventsImplementations::TcpReader(_address) => return Err(Box::from("instantiate_log_dao(): the TCP reader requires the `socket` feature of the `dal` crate")),
         Quake3ServerEventsImplementations::HttpRealtimeBinaryEventsReader => todo!("Not implemented for this exercise"),
+    })
+}
+
+
+/// Unit tests the [factory](super) module -- for the features that weren't enabled
+#[cfg(all(test, not(all(feature = "tokio", feature = "socket"))))]
+mod tests {
+    use super::*;
+
+
+    /// Tests that asking for the async reader without the `tokio` feature is an error -- rather than a panic
+    #[cfg(not(feature = "tokio"))]
+    #[test]
+    fn async_reader_without_tokio() {
+        let implementation = Quake3ServerEventsImplementations::AsyncLogFileReader(dal_api::FileReaderInfo { log_file_path: std::borrow::Cow::Borrowed("qgames.log") });
+        let err = instantiate_log_dao(implementation, Arc::new(Config::default()))
+            .err().expect("The async reader should have been refused");
+        assert_eq!(err.to_string(), "instantiate_log_dao(): the async reader requires the `tokio` feature of the `dal` crate");
     }
-}
\ No newline at end of file
+
+    /// Tests that asking for the TCP reader without the `socket` feature is an error -- rather than a panic
+    #[cfg(not(feature = "socket"))]
+    #[test]
+    fn tcp_reader_without_socket() {
+        let implementation = Quake3ServerEventsImplementations::TcpReader("127.0.0.1:27960".to_string());
+        let err = instantiate_log_dao(implementation, Arc::new(Config::default()))
+            .err().expect("The TCP reader should have been refused");
+        assert_eq!(err.to_string(), "instantiate_log_dao(): the TCP reader requires the `socket` feature of the `dal` crate");
+    }
+
+}
@@ -0,0 +1,181 @@
+//! Resting place for [FramedSocketReader] -- enabled by the `socket` feature
+
+
+use crate::events_translation::{log_line_deserializer, translate_quake3_events};
+use common::types::Result;
+use model::quake3_events::Quake3Events;
+use dal_api::{Config, Quake3ServerEvents};
+use std::{
+    io::{BufReader, ErrorKind, Read},
+    net::TcpStream,
+    pin::Pin,
+    sync::Arc,
+};
+use futures::{Stream, stream, StreamExt};
+use log::{info, warn};
+
+
+/// Size for buffering IO (the larger, more RAM is used, but fewer system calls / context switches / hardware requests are required)
+const BUFFER_SIZE: usize = 64*1024;
+
+
+/// [Quake3ServerEvents] implementation for reading Quake 3 Server log lines shipped through a socket, as length-prefixed frames:
+/// each frame is a big-endian `u32` with the number of bytes of the line that follows -- UTF-8 encoded, without the line break.\
+/// Frames may arrive split among any number of reads. The `Stream` ends when the connection is closed -- a frame cut short by
+/// the closing is discarded.
+pub struct FramedSocketReader {
+    config: Arc<Config>,
+    tcp_stream: TcpStream,
+}
+
+impl FramedSocketReader {
+
+    pub fn new(config: Arc<Config>, tcp_stream: TcpStream) -> Box<Self> {
+        Box::new(Self {
+            config,
+            tcp_stream,
+        })
+    }
+
+}
+
+impl Quake3ServerEvents for FramedSocketReader {
+
+    fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
+        let source_name = self.tcp_stream.peer_addr()
+            .map_or_else(|_err| String::from("<socket>"), |peer_addr| format!("tcp://{peer_addr}"));
+        let mut reader = BufReader::with_capacity(BUFFER_SIZE, self.tcp_stream);
+        let max_line_length = self.config.max_line_length;
+        let deserialize_log_line = log_line_deserializer(&self.config)?;
+        let first_line_number = self.config.event_id_base;
+        let frames_source_name = source_name.clone();
+        let lines_source_name = source_name.clone();
+        let lines = std::iter::from_fn(move || read_frame(&mut reader, max_line_length, &frames_source_name))
+            .zip(first_line_number..)
+            .map(move |(frame_result, line_number)| frame_result
+                .map_err(|err| format!("`LogParsingError` when processing log frames from '{lines_source_name}' at line {line_number}: {err}"))
+                .and_then(|line| deserialize_log_line(&line)
+                    .map_err(|log_parser_err| format!("`LogParsingError` when processing log frames from '{lines_source_name}' at line {line_number}: {log_parser_err:?}")))
+                .map_err(Box::from));
+
+        let debug = self.config.debug;
+        let stream = translate_quake3_events(stream::iter(lines), self.config.event_id_base, &source_name, false);
+        let stream: Pin<Box<dyn Stream<Item=Quake3Events<'static>>>> = if debug {
+            Box::pin(stream
+                .inspect(|yielded_event| info!("{yielded_event:?}")))
+        } else {
+            Box::pin(stream)
+        };
+        Ok(stream)
+    }
+
+}
+
+/// Reads the next frame out of `reader`, returning its line -- or `None` if the connection was closed.\
+/// Frames longer than `max_line_length` are skipped, without being buffered, yielding an error instead
+fn read_frame(reader: &mut impl Read, max_line_length: Option<usize>, source_name: &str) -> Option<std::result::Result<String, String>> {
+    let mut length_prefix = [0u8; 4];
+    match reader.read_exact(&mut length_prefix) {
+        Ok(()) => (),
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => return None,
+        Err(err) => {
+            warn!("Log frames from '{source_name}': IO error reading a frame length -- considering the connection closed: {err}");
+            return None
+        },
+    }
+    let length = u32::from_be_bytes(length_prefix) as usize;
+    if let Some(max_line_length) = max_line_length.filter(|max_line_length| length > *max_line_length) {
+        return match std::io::copy(&mut reader.take(length as u64), &mut std::io::sink()) {
+            Ok(skipped) if skipped == length as u64 => Some(Err(format!("line length of {length} bytes exceeds the maximum of {max_line_length} -- skipped"))),
+            _ => None,
+        }
+    }
+    let mut line = vec![0u8; length];
+    match reader.read_exact(&mut line) {
+        Ok(()) => Some(String::from_utf8(line)
+            .map_err(|err| format!("the frame isn't valid UTF-8: {err}"))),
+        Err(err) => {
+            warn!("Log frames from '{source_name}': connection closed in the middle of a frame of {length} bytes -- discarding it: {err}");
+            None
+        },
+    }
+}
+
+
+/// Unit tests the [framed_socket_reader](super) implementation of [dal_api::Quake3ServerEvents]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::Write,
+        net::TcpListener,
+        thread,
+        time::Duration,
+    };
+
+
+    /// Tests that framed lines are read regardless of how they are split among the TCP writes -- and that the
+    /// connection closing ends the `Stream`, discarding the frame cut short
+    #[test]
+    fn split_frames() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind a local TCP port");
+        let address = listener.local_addr().expect("Couldn't get the local address");
+        let server = thread::spawn(move || {
+            let (mut connection, _peer) = listener.accept().expect("Couldn't accept the connection");
+            let frames = ["  0:00 InitGame: \\fraglimit\\20", "  0:01 ClientConnect: 1", "  0:02 ClientConnect: 2", "  0:03 ShutdownGame:"].into_iter()
+                .flat_map(|line| (line.len() as u32).to_be_bytes().into_iter().chain(line.bytes()))
+                .collect::<Vec<u8>>();
+            // sent in awkward pieces, splitting both the length prefixes & the lines
+            for piece in frames.chunks(7) {
+                connection.write_all(piece).expect("Couldn't send a piece of the frames");
+                connection.flush().expect("Couldn't flush");
+                thread::sleep(Duration::from_millis(1));
+            }
+            // a frame cut short by the connection closing
+            connection.write_all(&100u32.to_be_bytes()).expect("Couldn't send the truncated frame length");
+            connection.write_all(b"  0:04 Client").expect("Couldn't send the truncated frame");
+        });
+
+        let tcp_stream = TcpStream::connect(address).expect("Couldn't connect to the local TCP server");
+        let log_dao = FramedSocketReader::new(Arc::new(Config::default()), tcp_stream);
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        server.join().expect("The server thread panicked");
+
+        assert!(events.iter().all(|event| event.is_ok()), "Unexpected errors: {events:?}");
+        let client_ids = events.iter()
+            .filter_map(|event| match event {
+                Quake3Events::ClientConnect { client_id, .. } => Some(*client_id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(client_ids, vec![1, 2], "Frames were lost or garbled");
+        assert_eq!(events.len(), 4, "Unexpected number of events: {events:?}");
+    }
+
+    /// Tests that frames longer than [Config::max_line_length] yield an error, without disrupting the following ones
+    #[test]
+    fn oversized_frame() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind a local TCP port");
+        let address = listener.local_addr().expect("Couldn't get the local address");
+        let server = thread::spawn(move || {
+            let (mut connection, _peer) = listener.accept().expect("Couldn't accept the connection");
+            let oversized_line = format!("  0:01 say: Zeh: {}", "z".repeat(1000));
+            for line in ["  0:00 InitGame: \\fraglimit\\20", &oversized_line, "  0:03 ShutdownGame:"] {
+                connection.write_all(&(line.len() as u32).to_be_bytes()).expect("Couldn't send the frame length");
+                connection.write_all(line.as_bytes()).expect("Couldn't send the frame");
+            }
+        });
+
+        let tcp_stream = TcpStream::connect(address).expect("Couldn't connect to the local TCP server");
+        let log_dao = FramedSocketReader::new(Arc::new(Config { max_line_length: Some(256), ..Config::default() }), tcp_stream);
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        server.join().expect("The server thread panicked");
+
+        assert_eq!(events.len(), 3, "Unexpected number of events: {events:?}");
+        assert!(events[1].is_err(), "The oversized frame should have yielded an error: {events:?}");
+        assert!(events[2].is_ok(), "The frame after the oversized one should have been read: {events:?}");
+    }
+
+}
@@ -0,0 +1,94 @@
+//! Resting place for [Quake3LogGlobReader]
+
+
+use crate::multi_file_reader::Quake3LogMultiFileSyncReader;
+use common::types::Result;
+use model::quake3_events::Quake3Events;
+use dal_api::{Config, Quake3ServerEvents};
+use std::{
+    path::PathBuf,
+    pin::Pin,
+    sync::Arc,
+};
+use futures::Stream;
+
+
+/// [Quake3ServerEvents] implementation for reading Quake 3 Server events from the log files of a directory whose names
+/// match a glob `pattern` -- such as `games*.log` -- sorted by name, as a single feed: see [Quake3LogMultiFileSyncReader],
+/// which also tells what `strict` means.\
+/// The directory is listed when the `Stream` is created.
+pub struct Quake3LogGlobReader {
+    config: Arc<Config>,
+    dir: PathBuf,
+    pattern: String,
+    strict: bool,
+}
+
+impl Quake3LogGlobReader {
+
+    pub fn new(config: Arc<Config>, dir: impl Into<PathBuf>, pattern: impl Into<String>, strict: bool) -> Box<Self> {
+        Box::new(Self {
+            config,
+            dir: dir.into(),
+            pattern: pattern.into(),
+            strict,
+        })
+    }
+
+}
+
+impl Quake3ServerEvents for Quake3LogGlobReader {
+
+    fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
+        let directory_name = self.dir.display().to_string();
+        let pattern = glob::Pattern::new(&self.pattern)
+            .map_err(|err| format!("Invalid glob pattern '{}' for the Quake3 Server log files: {err}", self.pattern))?;
+        let mut log_file_paths = std::fs::read_dir(&self.dir)
+            .map_err(|err| format!("Couldn't list the Quake3 Server logs directory '{directory_name}': {err}"))?
+            .flatten()
+            .filter(|entry| entry.file_name().to_str().is_some_and(|file_name| pattern.matches(file_name)))
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>();
+        log_file_paths.sort();
+        Quake3LogMultiFileSyncReader::new(self.config, log_file_paths, self.strict)
+            .events_stream()
+    }
+
+}
+
+
+/// Unit tests the [glob_reader](super) implementation of [dal_api::Quake3ServerEvents]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+
+    /// Tests that all matching files in the directory are read, in the order of their names
+    #[test]
+    fn matching_files() {
+        let log_dao = Quake3LogGlobReader::new(Arc::new(Config::default()), "tests/resources", "qgames_*.log", false);
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        assert!(events.iter().all(|event| event.is_ok()), "Unexpected errors: {events:?}");
+        let sources = events.iter()
+            .filter_map(|event| match event {
+                Quake3Events::InitGame { source: Some(source), .. } => Some(source.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let read_files = sources.iter().collect::<BTreeSet<_>>();
+        assert!(read_files.len() >= 2, "At least 2 files should have been read: {read_files:?}");
+        assert!(sources.windows(2).all(|pair| pair[0] <= pair[1]), "The files should have been read in the order of their names: {sources:?}");
+        assert!(sources.iter().all(|source| !source.ends_with(".gz")), "Only the files matching the pattern should have been read: {read_files:?}");
+    }
+
+    /// Tests that an invalid pattern fails the `Stream` creation
+    #[test]
+    fn invalid_pattern() {
+        let log_dao = Quake3LogGlobReader::new(Arc::new(Config::default()), "tests/resources", "qgames_[.log", false);
+        assert!(log_dao.events_stream().is_err(), "An invalid glob pattern should fail the `Stream` creation");
+    }
+
+}
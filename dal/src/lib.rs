@@ -1,9 +1,18 @@
 #![doc = include_str!("../README.md")]
 
 pub mod factory;
+#[cfg(feature = "tokio")]
 pub mod async_file_reader;
 pub mod sync_file_reader;
+pub mod multi_file_reader;
+pub mod glob_reader;
 pub mod sync_reader;
 pub mod stdin_reader;
+#[cfg(feature = "watch")]
+pub mod watched_directory_reader;
+#[cfg(feature = "socket")]
+pub mod framed_socket_reader;
+#[cfg(feature = "socket")]
+pub mod tcp_reader;
 
 mod events_translation;
@@ -0,0 +1,216 @@
+//! Resting place for [Quake3LogMultiFileSyncReader]
+
+
+use crate::{
+    events_translation::log_line_deserializer,
+    sync_file_reader::open_log_file,
+    sync_reader::Quake3LogSyncReader,
+};
+use common::types::Result;
+use model::quake3_events::Quake3Events;
+use dal_api::{Config, Quake3ServerEvents};
+use std::{
+    cell::Cell,
+    io::{BufRead, Read},
+    path::PathBuf,
+    pin::Pin,
+    rc::Rc,
+    sync::Arc,
+};
+use futures::{Stream, stream, StreamExt};
+
+
+/// [Quake3ServerEvents] implementation for reading Quake 3 Server events from several log files, in the given order,
+/// as if they were a single one -- like rotated logs (`games.log.1`, `games.log`), which often split a server session.\
+/// Line numbers (and, thus, event ids) continue from one file to the next, while the games are tagged with the file they came from.
+/// Each file is read as [crate::sync_file_reader::Quake3LogFileSyncReader] would -- but only the last one is followed,
+/// if [Config::follow] is set.
+pub struct Quake3LogMultiFileSyncReader {
+    config: Arc<Config>,
+    log_file_paths: Vec<PathBuf>,
+    strict: bool,
+}
+
+impl Quake3LogMultiFileSyncReader {
+
+    /// If `strict`, all files are opened upfront, so any failures happen at `Stream` creation -- otherwise, each file is only
+    /// opened when its turn comes, with failures yielding a [Quake3Events::Error] in its place, so the remaining files are still read
+    pub fn new(config: Arc<Config>, log_file_paths: Vec<PathBuf>, strict: bool) -> Box<Self> {
+        Box::new(Self {
+            config,
+            log_file_paths,
+            strict,
+        })
+    }
+
+}
+
+impl Quake3ServerEvents for Quake3LogMultiFileSyncReader {
+
+    fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
+        // the config is validated upfront, so it fails the `Stream` creation, rather than each file
+        let _deserializer = log_line_deserializer(&self.config)?;
+        let last_file_index = self.log_file_paths.len().saturating_sub(1);
+        let files = self.log_file_paths.iter().enumerate()
+            .map(|(file_index, log_file_path)| {
+                let log_file_path = log_file_path.display().to_string();
+                let follow = self.config.follow.filter(|_| file_index == last_file_index);
+                let reader = if self.strict { Some(open_log_file(&log_file_path, self.config.buffer_size, follow)?) } else { None };
+                Ok((log_file_path, follow, reader))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // a single `StreamEnd` is given out, after the last file, rather than one per file
+        let stream_end = self.config.stream_end;
+        let config = Arc::new(Config { stream_end: false, ..Config::clone(&self.config) });
+        let lines_read = Rc::new(Cell::new(0));
+        let end_lines_read = Rc::clone(&lines_read);
+        let (skip_lines, event_id_base) = (config.skip_lines as u32, config.event_id_base);
+        let stream_end = stream::iter(stream_end.then_some(()))
+            // evaluated only after all files were read
+            .map(move |_| {
+                let lines_read = end_lines_read.get();
+                Quake3Events::StreamEnd { event_id: lines_read + event_id_base, total_lines: lines_read - skip_lines.min(lines_read) }
+            });
+        let stream = stream::iter(files)
+            .flat_map(move |(log_file_path, follow, reader)| {
+                // the previous files are done with by now: their lines are all counted
+                let line_number_offset = lines_read.get();
+                reader.map_or_else(|| open_log_file(&log_file_path, config.buffer_size, follow), Ok)
+                    .and_then(|reader| {
+                        let reader = LineCountingReader::new(reader, Rc::clone(&lines_read));
+                        Quake3LogSyncReader::new(Arc::clone(&config), &log_file_path, reader, line_number_offset)
+                            .events_stream()
+                    })
+                    .unwrap_or_else(|err| Box::pin(stream::iter([Quake3Events::Error { event_id: line_number_offset + config.event_id_base, err }])))
+            })
+            .chain(stream_end);
+        Ok(Box::pin(stream))
+    }
+
+}
+
+/// Counts, into `lines_read`, the lines given out by `reader` -- including a last one not ending in a line break --
+/// so the numbering of the next file may continue from there
+struct LineCountingReader<Reader: BufRead> {
+    reader: Reader,
+    lines_read: Rc<Cell<u32>>,
+    /// whether the bytes consumed so far don't end in a line break
+    mid_line: bool,
+}
+
+impl<Reader: BufRead> LineCountingReader<Reader> {
+
+    fn new(reader: Reader, lines_read: Rc<Cell<u32>>) -> Self {
+        Self { reader, lines_read, mid_line: false }
+    }
+
+}
+
+impl<Reader: BufRead> Read for LineCountingReader<Reader> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let available = self.fill_buf()?;
+        let length = available.len().min(buffer.len());
+        buffer[..length].copy_from_slice(&available[..length]);
+        self.consume(length);
+        Ok(length)
+    }
+}
+
+impl<Reader: BufRead> BufRead for LineCountingReader<Reader> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        let buffer = self.reader.fill_buf()?;
+        if buffer.is_empty() && self.mid_line {
+            self.mid_line = false;
+            self.lines_read.set(self.lines_read.get() + 1);
+        }
+        Ok(buffer)
+    }
+    fn consume(&mut self, amount: usize) {
+        // the consumed bytes are still in the buffer -- so no IO happens here
+        if let Ok(buffer) = self.reader.fill_buf() {
+            let consumed = &buffer[..amount.min(buffer.len())];
+            let line_breaks = consumed.iter().filter(|&&byte| byte == b'\n').count() as u32;
+            self.lines_read.set(self.lines_read.get() + line_breaks);
+            if let Some(&last_byte) = consumed.last() {
+                self.mid_line = last_byte != b'\n';
+            }
+        }
+        self.reader.consume(amount)
+    }
+}
+
+
+/// Unit tests the [multi_file_reader](super) implementation of [dal_api::Quake3ServerEvents]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// The location of a good log file, with all lines OK
+    const GOOD_LOG_FILE_LOCATION: &str = "tests/resources/qgames_excerpt.log";
+    /// A log file full of bad log entries
+    const MALFORMED_LOG_FILE_LOCATION: &str = "tests/resources/malformed_line.log";
+
+
+    /// Tests that the events of all files are given out, in order -- with the event ids continuing from one file to the next
+    #[test]
+    fn concatenated_files() {
+        let log_dao = Quake3LogMultiFileSyncReader::new(Arc::new(Config::default()), vec![PathBuf::from(GOOD_LOG_FILE_LOCATION), PathBuf::from(MALFORMED_LOG_FILE_LOCATION)], true);
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(events.len(), 93 + 6, "The events of both files should have been given out");
+        let event_ids = events.iter().map(|event| event.event_id()).collect::<Vec<_>>();
+        assert!(event_ids.windows(2).all(|pair| pair[0] < pair[1]), "Event ids should be increasing across the files: {event_ids:?}");
+        assert_eq!(event_ids[93], 100 + 1, "The second file's numbering should continue from the first one's 100 lines");
+        assert!(matches!(&events[0], Quake3Events::InitGame { source: Some(source), .. } if source == GOOD_LOG_FILE_LOCATION), "The games should be tagged with their file");
+    }
+
+    /// Tests that, under [Config::stream_end], a single `StreamEnd` marker is given out -- after the last file -- with the lines of all files
+    #[test]
+    fn stream_end_after_all_files() {
+        let config = Arc::new(Config { stream_end: true, ..Config::default() });
+        let log_dao = Quake3LogMultiFileSyncReader::new(config, vec![PathBuf::from(GOOD_LOG_FILE_LOCATION), PathBuf::from(MALFORMED_LOG_FILE_LOCATION)], true);
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(events.iter().filter(|event| matches!(event, Quake3Events::StreamEnd { .. })).count(), 1, "There should be a single `StreamEnd` marker");
+        assert!(matches!(events.last(), Some(Quake3Events::StreamEnd { event_id: 108, total_lines: 107 })), "Unexpected last event: {:?}", events.last());
+    }
+
+    /// Tests that [Config::skip_lines] counts the lines across the files -- resuming in the middle of the second one
+    #[test]
+    fn skip_lines_across_files() {
+        let config = Arc::new(Config { skip_lines: 100 + 2, ..Config::default() });
+        let log_dao = Quake3LogMultiFileSyncReader::new(config, vec![PathBuf::from(GOOD_LOG_FILE_LOCATION), PathBuf::from(MALFORMED_LOG_FILE_LOCATION)], true);
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let error_ids = futures::executor::block_on_stream(stream)
+            .filter(|event| event.is_err())
+            .map(|event| event.event_id())
+            .collect::<Vec<_>>();
+        assert_eq!(error_ids, vec![100 + 5, 100 + 6], "The first file & the first 2 lines of the second should have been skipped");
+    }
+
+    /// Tests that a non-existing file among the given ones fails the `Stream` creation, if `strict` -- otherwise,
+    /// an error takes its place in the `Stream`, which goes on with the remaining files
+    #[test]
+    fn non_existing_file() {
+        let expected_err = "Couldn't open Quake3 Server log file '/tmp/non-existing.log' for reading: No such file or directory (os error 2)";
+        let log_file_paths = || vec![PathBuf::from("/tmp/non-existing.log"), PathBuf::from(GOOD_LOG_FILE_LOCATION)];
+
+        // scenario: strict
+        let log_dao = Quake3LogMultiFileSyncReader::new(Arc::new(Config::default()), log_file_paths(), true);
+        match log_dao.events_stream() {
+            Ok(_stream) => panic!("Opening a non-existing file was expected to fail at `Stream` creation, but the operation succeeded"),
+            Err(stream_creation_err) => assert_eq!(stream_creation_err.to_string(), expected_err, "Unexpected `Stream` creation error"),
+        }
+
+        // scenario: tolerant
+        let log_dao = Quake3LogMultiFileSyncReader::new(Arc::new(Config::default()), log_file_paths(), false);
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(events.len(), 1 + 93, "The error should have taken the place of the missing file");
+        assert!(matches!(&events[0], Quake3Events::Error { event_id: 1, err } if err.to_string() == expected_err), "Unexpected first event: {:?}", events[0]);
+        assert!(events[1..].iter().all(|event| event.is_ok()), "The remaining file should have been read normally");
+    }
+
+}
@@ -12,9 +12,6 @@ use std::{
 };
 use futures::Stream;
 
-/// Size for buffering IO (the larger, more RAM is used, but fewer system calls / context switches / hardware requests are required)
-const BUFFER_SIZE: usize = 1024*1024;
-
 /// [Quake3ServerEvents] implementation for reading Quake 3 Server events from a log file
 pub struct Quake3LogFileStdinReader {
     config: Arc<Config>,
@@ -33,8 +30,8 @@ impl Quake3LogFileStdinReader {
 impl Quake3ServerEvents for Quake3LogFileStdinReader {
 
     fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
-        let reader = BufReader::with_capacity(BUFFER_SIZE, std::io::stdin());
-        Quake3LogSyncReader::new(self.config, "<stdin>", reader)
+        let reader = BufReader::with_capacity(self.config.buffer_size, std::io::stdin());
+        Quake3LogSyncReader::new(self.config, "<stdin>", reader, 0)
             .events_stream()
     }
 
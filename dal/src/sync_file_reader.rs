@@ -7,18 +7,23 @@ use model::quake3_events::Quake3Events;
 use dal_api::{Config, FileReaderInfo, Quake3ServerEvents};
 use std::{
     fs::File,
-    io::BufReader,
+    io::{BufRead, BufReader, Read},
     pin::Pin,
     sync::Arc,
+    time::Duration,
 };
 use futures::Stream;
+use flate2::bufread::MultiGzDecoder;
 
 
-/// Size for buffering IO (the larger, more RAM is used, but fewer system calls / context switches / hardware requests are required)
-const BUFFER_SIZE: usize = 1024*1024;
+/// The first bytes of any gzip stream
+pub(crate) const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
 
 
-/// [Quake3ServerEvents] implementation for reading Quake 3 Server events from a log file
+/// [Quake3ServerEvents] implementation for reading Quake 3 Server events from a log file.\
+/// gzip-compressed files -- as rotated logs routinely are -- are decompressed on the fly: they are told apart by their
+/// `.gz` extension or by their gzip header.\
+/// If [Config::follow] is set, the file is followed as it grows -- see [FollowedReader].
 pub struct Quake3LogFileSyncReader<'a> {
     config: Arc<Config>,
     params: FileReaderInfo<'a>,
@@ -38,15 +43,65 @@ impl<'a> Quake3LogFileSyncReader<'a> {
 impl Quake3ServerEvents for Quake3LogFileSyncReader<'static> {
 
     fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
-        let file = File::open(&self.params.log_file_path.as_ref())
-            .map_err(|err| format!("Couldn't open Quake3 Server log file '{}' for reading: {err}", self.params.log_file_path))?;
-        let reader = BufReader::with_capacity(BUFFER_SIZE, file);
-        Quake3LogSyncReader::new(self.config, &self.params.log_file_path, reader)
+        let reader = open_log_file(&self.params.log_file_path, self.config.buffer_size, self.config.follow)?;
+        Quake3LogSyncReader::new(self.config, &self.params.log_file_path, reader, 0)
             .events_stream()
     }
 
 }
 
+/// Opens the Quake 3 Server log file at `log_file_path` for reading -- decompressing it on the fly, if it is gzipped,
+/// and following it as it grows, if `follow` is set: see [Config::follow]. `buffer_size` is as in [Config::buffer_size]
+pub(crate) fn open_log_file(log_file_path: &str, buffer_size: usize, follow: Option<Duration>) -> Result<Box<dyn BufRead>> {
+    let file = File::open(log_file_path)
+        .map_err(|err| format!("Couldn't open Quake3 Server log file '{log_file_path}' for reading: {err}"))?;
+    let mut reader = BufReader::with_capacity(buffer_size, file);
+    let is_gzip = log_file_path.ends_with(".gz") || reader.fill_buf().is_ok_and(|buffer| buffer.starts_with(&GZIP_MAGIC));
+    Ok(match (is_gzip, follow) {
+        (false, None) => Box::new(reader),
+        (false, Some(poll_interval)) => Box::new(FollowedReader::new(reader, poll_interval)),
+        (true, None) => Box::new(BufReader::with_capacity(buffer_size, MultiGzDecoder::new(reader))),
+        (true, Some(poll_interval)) => Box::new(BufReader::with_capacity(buffer_size, MultiGzDecoder::new(FollowedReader::new(reader, poll_interval)))),
+    })
+}
+
+/// Wraps the reader of a file being written to -- like `tail -f` does: instead of reporting the end of the file,
+/// reads block, polling every `poll_interval` until more data is appended
+struct FollowedReader<Reader: BufRead> {
+    reader: Reader,
+    poll_interval: Duration,
+}
+
+impl<Reader: BufRead> FollowedReader<Reader> {
+
+    fn new(reader: Reader, poll_interval: Duration) -> Self {
+        Self { reader, poll_interval }
+    }
+
+}
+
+impl<Reader: BufRead> Read for FollowedReader<Reader> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let available = self.fill_buf()?;
+        let length = available.len().min(buffer.len());
+        buffer[..length].copy_from_slice(&available[..length]);
+        self.consume(length);
+        Ok(length)
+    }
+}
+
+impl<Reader: BufRead> BufRead for FollowedReader<Reader> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        while self.reader.fill_buf()?.is_empty() {
+            std::thread::sleep(self.poll_interval);
+        }
+        self.reader.fill_buf()
+    }
+    fn consume(&mut self, amount: usize) {
+        self.reader.consume(amount)
+    }
+}
+
 
 /// Unit tests the [sync_file_reader](super) implementation of [dal_api::Quake3ServerEvents]
 #[cfg(test)]
@@ -55,6 +110,7 @@ mod tests {
     use std::{
         borrow::Cow,
         collections::HashMap,
+        io::Write,
     };
 
 
@@ -62,6 +118,10 @@ mod tests {
     const GOOD_LOG_FILE_LOCATION: &str = "tests/resources/qgames_excerpt.log";
     /// A log file full of bad log entries
     const MALFORMED_LOG_FILE_LOCATION: &str = "tests/resources/malformed_line.log";
+    /// A log file having a player name in Latin-1 -- not valid UTF-8
+    const LATIN1_LOG_FILE_LOCATION: &str = "tests/resources/latin1_name.log";
+    /// The same as [GOOD_LOG_FILE_LOCATION], gzip-compressed
+    const GZIPPED_LOG_FILE_LOCATION: &str = "tests/resources/qgames_excerpt.log.gz";
     /// A path to a file that simply isn't there
     const NON_EXISTING_FILE_LOCATION: &str = "/tmp/non-existing.log";
 
@@ -75,7 +135,85 @@ mod tests {
         let events_count = stream
             .inspect(|event| assert!(event.is_ok(), "Parsing log line #{} yielded a unexpected error {event:?}", event.event_id()))
             .count();
-        assert_eq!(events_count, 32, "Unexpected number of parsed log lines");
+        assert_eq!(events_count, 93, "Unexpected number of parsed log lines");
+    }
+
+    /// Tests that a gzip-compressed file yields the same events as its uncompressed counterpart -- line numbers included
+    #[test]
+    fn gzipped_file() {
+        let events = |log_file_location: &'static str| {
+            let log_dao = Quake3LogFileSyncReader::new(config(), FileReaderInfo { log_file_path: Cow::Borrowed(log_file_location) });
+            let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+            futures::executor::block_on_stream(Pin::from(stream))
+                .map(|event| format!("{event:?}").replace(log_file_location, "<source>"))
+                .collect::<Vec<_>>()
+        };
+        let gzipped_events = events(GZIPPED_LOG_FILE_LOCATION);
+        assert_eq!(gzipped_events.len(), 93, "Unexpected number of parsed log lines");
+        assert_eq!(gzipped_events, events(GOOD_LOG_FILE_LOCATION), "The gzipped file should yield the same events as the plain one");
+    }
+
+    /// Tests that, under [Config::stream_end], the `StreamEnd` marker is given out exactly once -- as the last event --
+    /// with the number of lines in the file
+    #[test]
+    fn stream_end() {
+        let log_dao = Quake3LogFileSyncReader::new(Arc::new(Config { stream_end: true, ..Config::default() }), FileReaderInfo { log_file_path: Cow::Borrowed(GOOD_LOG_FILE_LOCATION) });
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(events.len(), 93 + 1, "Unexpected number of events");
+        assert_eq!(events.iter().filter(|event| matches!(event, Quake3Events::StreamEnd { .. })).count(), 1, "There should be a single `StreamEnd` marker");
+        assert!(matches!(events.last(), Some(Quake3Events::StreamEnd { event_id: 101, total_lines: 100 })), "Unexpected last event: {:?}", events.last());
+    }
+
+    /// Tests that a tiny [Config::buffer_size] -- smaller than most lines -- still has the file fully & correctly read
+    #[test]
+    fn tiny_buffer() {
+        let events = |config: Arc<Config>| {
+            let log_dao = Quake3LogFileSyncReader::new(config, FileReaderInfo { log_file_path: Cow::Borrowed(GOOD_LOG_FILE_LOCATION) });
+            let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+            futures::executor::block_on_stream(stream)
+                .map(|event| format!("{event:?}"))
+                .collect::<Vec<_>>()
+        };
+        let tiny_buffer_events = events(Arc::new(Config { buffer_size: 64, ..Config::default() }));
+        assert_eq!(tiny_buffer_events.len(), 93, "Unexpected number of parsed log lines");
+        assert_eq!(tiny_buffer_events, events(config()), "The buffer size should make no difference in the events");
+    }
+
+    /// Tests that, under [Config::follow], the lines appended to the file after its end was reached are also given out
+    #[test]
+    fn followed_file() {
+        let log_path = std::env::temp_dir().join(format!("quake3-followed-file-{}.log", std::process::id()));
+        std::fs::write(&log_path, "  0:00 InitGame: \\fraglimit\\20\n  0:01 ClientConnect: 1\n").expect("Couldn't write the log file");
+
+        let writer_log_path = log_path.clone();
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(200));
+            let mut log_file = File::options().append(true).open(&writer_log_path).expect("Couldn't open the log file for appending");
+            log_file.write_all(b"  0:02 ClientConnect: 2\n  0:0").expect("Couldn't append to the log file");
+            std::thread::sleep(Duration::from_millis(100));
+            log_file.write_all(b"3 ClientConnect: 3\n").expect("Couldn't append the rest of the line to the log file");
+        });
+
+        let config = Arc::new(Config {
+            follow: Some(Duration::from_millis(10)),
+            ..Config::default()
+        });
+        let log_dao = Quake3LogFileSyncReader::new(config, FileReaderInfo { log_file_path: Cow::Owned(log_path.display().to_string()) });
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        // the `Stream` never ends: only the expected events are taken
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).take(4).collect();
+        writer.join().expect("The writer thread panicked");
+        let _ = std::fs::remove_file(&log_path);
+
+        assert!(events.iter().all(|event| event.is_ok()), "Unexpected errors: {events:?}");
+        let client_ids = events.iter()
+            .filter_map(|event| match event {
+                Quake3Events::ClientConnect { client_id, .. } => Some(*client_id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(client_ids, vec![1, 2, 3], "The appended lines weren't followed");
     }
 
     /// Tests that opening a non-existing file yields the expected error result & message
@@ -111,14 +249,56 @@ mod tests {
                 }
             })
             .count();
-        assert_eq!(events_count, 5, "Unexpected number of events");
+        assert_eq!(events_count, 6, "Unexpected number of events");
         assert!(expected_lines_and_errors.len() == 0, "Not all expected errors were cought: {} are left: {:?}", expected_lines_and_errors.len(), expected_lines_and_errors);
     }
 
     
+    /// Tests that [Config::skip_lines] drops the leading lines -- the remaining events keeping their original ids --
+    /// and that skipping past the end of the file yields no events
+    #[test]
+    fn skip_lines() {
+        let errors = |skip_lines| {
+            let config = Arc::new(Config { skip_lines, ..Config::default() });
+            let log_dao = Quake3LogFileSyncReader::new(config, FileReaderInfo { log_file_path: Cow::Borrowed(MALFORMED_LOG_FILE_LOCATION) });
+            let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+            futures::executor::block_on_stream(stream)
+                .map(|event| (event.event_id(), event.is_err()))
+                .collect::<Vec<_>>()
+        };
+        let error_ids = |events: Vec<(u32, bool)>| events.into_iter().filter(|(_event_id, is_err)| *is_err).map(|(event_id, _is_err)| event_id).collect::<Vec<_>>();
+        assert_eq!(error_ids(errors(0)), vec![2, 5, 6], "Skipping no lines should behave as usual");
+        assert_eq!(error_ids(errors(2)), vec![5, 6], "The first error should have been skipped, keeping the ids of the others");
+        assert_eq!(errors(1000), vec![], "Skipping past the end of the file should yield no events");
+    }
+
+    /// Tests that, with [Config::lossy_utf8], a line with invalid UTF-8 is still parsed -- with a replacement character
+    /// in place of the offending byte -- while, without it, the line yields an IO read error
+    #[test]
+    fn lossy_utf8() {
+        let events = |lossy_utf8| {
+            let config = Arc::new(Config { lossy_utf8, ..Config::default() });
+            let log_dao = Quake3LogFileSyncReader::new(config, FileReaderInfo { log_file_path: Cow::Borrowed(LATIN1_LOG_FILE_LOCATION) });
+            let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+            futures::executor::block_on_stream(stream).collect::<Vec<_>>()
+        };
+
+        let lossy_events = events(true);
+        assert!(lossy_events.iter().all(|event| event.is_ok()), "Unexpected errors: {lossy_events:?}");
+        assert!(matches!(&lossy_events[2], Quake3Events::ClientUserinfoChanged { client_id: 2, name, .. } if name == "Jos\u{FFFD}"),
+                "The Latin-1 name should have been decoded with a replacement character: {:?}", lossy_events[2]);
+
+        let strict_events = events(false);
+        assert_eq!(strict_events.len(), 4, "The `Stream` should have continued after the invalid line");
+        assert_eq!(strict_events[2].unwrap_err().to_string(),
+                   "IO read error when processing log file 'tests/resources/latin1_name.log' at line 3: Custom { kind: InvalidData, error: \"stream did not contain valid UTF-8\" }",
+                   "The invalid UTF-8 should have been reported");
+    }
+
     fn config() -> Arc<Config> {
         Arc::new(Config {
             debug: false,
+            ..Config::default()
         })
     }
     
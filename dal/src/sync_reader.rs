@@ -1,12 +1,12 @@
 //! Resting place for [Quake3LogSyncReader]
 
 
-use crate::events_translation::translate_quake3_events;
+use crate::events_translation::{log_line_deserializer, translate_quake3_events};
 use common::types::Result;
 use model::quake3_events::Quake3Events;
 use dal_api::{Config, Quake3ServerEvents};
-use quake3_server_events::deserializer_logs::deserialize_log_line;
 use std::{
+    io::{BufRead, ErrorKind},
     pin::Pin,
     sync::Arc,
     task::Poll,
@@ -20,15 +20,19 @@ pub struct Quake3LogSyncReader<Reader: std::io::BufRead> {
     config: Arc<Config>,
     source_name: String,
     reader: Reader,
+    line_number_offset: u32,
 }
 
 impl<Reader: std::io::BufRead> Quake3LogSyncReader<Reader> {
 
-    pub fn new(config: Arc<Config>, source_name: &str, reader: Reader) -> Box<Self> {
+    /// `line_number_offset` is the number of lines that precede `reader` -- non-zero when several sources are chained,
+    /// so line numbers (and, thus, event ids) continue from where the previous source left off
+    pub fn new(config: Arc<Config>, source_name: &str, reader: Reader, line_number_offset: u32) -> Box<Self> {
         Box::new(Self {
             config,
             source_name: source_name.into(),
             reader,
+            line_number_offset,
         })
     }
 
@@ -37,7 +41,13 @@ impl<Reader: std::io::BufRead> Quake3LogSyncReader<Reader> {
 impl<Reader: std::io::BufRead + 'static> Quake3ServerEvents for Quake3LogSyncReader<Reader> {
 
     fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
-        let mut lines_iter = self.reader.lines().enumerate();
+        let mut reader = self.reader;
+        let max_line_length = self.config.max_line_length;
+        let lossy_utf8 = self.config.lossy_utf8;
+        // the lines to skip are counted from the start of the feed -- so chained sources skip only what's left of them
+        let skip_lines = self.config.skip_lines.saturating_sub(self.line_number_offset as usize);
+        let mut lines_iter = std::iter::from_fn(move || read_line(&mut reader, max_line_length, lossy_utf8)).enumerate()
+            .skip(skip_lines);
 
         let yield_item = |event| Poll::Ready(Some(Ok(event)));
         let yield_error = |err| Poll::Ready(Some(Err(Box::from(err))));
@@ -45,20 +55,27 @@ impl<Reader: std::io::BufRead + 'static> Quake3ServerEvents for Quake3LogSyncRea
 
         let debug = self.config.debug;
         let source_name = self.source_name.to_owned();
+        // line numbers & event ids are the same -- offset & based as configured
+        let first_line_number = (self.line_number_offset + self.config.event_id_base) as usize;
+        let deserialize_log_line = log_line_deserializer(&self.config)?;
         let stream = stream::poll_fn(move |_|
             lines_iter.next()
                 .map_or_else(end_of_stream,
                              |(line_number, line_result)| line_result
-                                 .map_err(|read_err| format!("IO read error when processing log file '{}' at line {}: {read_err:?}", source_name, line_number+1))
+                                 .map_err(|read_err| format!("IO read error when processing log file '{}' at line {}: {read_err:?}", source_name, first_line_number+line_number))
+                                 .and_then(|read_line| match read_line {
+                                     ReadLine::Line(line) => Ok(line),
+                                     ReadLine::Oversized { length } => Err(format!("`LogParsingError` when processing log file '{}' at line {}: line length of {length} bytes exceeds the maximum of {} -- truncated", source_name, first_line_number+line_number, max_line_length.unwrap_or_default())),
+                                 })
                                  .map_or_else(yield_error,
                                               |line| deserialize_log_line(&line)
-                                                     .map_err(|log_parser_err| format!("`LogParsingError` when processing log file '{}' at line {}: {log_parser_err:?}", source_name, line_number+1))
+                                                     .map_err(|log_parser_err| format!("`LogParsingError` when processing log file '{}' at line {}: {log_parser_err:?}", source_name, first_line_number+line_number))
                                                      .map_or_else(yield_error, yield_item)
 
                                  )
                 )
         );
-        let stream = translate_quake3_events(stream);
+        let stream = translate_quake3_events(stream, self.line_number_offset + self.config.event_id_base + skip_lines as u32, &self.source_name, self.config.stream_end);
         let stream: Pin<Box<dyn Stream<Item=Quake3Events<'static>>>> = if debug {
             Box::pin(stream
                 .inspect(|yielded_event| info!("{yielded_event:?}")))
@@ -70,5 +87,227 @@ impl<Reader: std::io::BufRead + 'static> Quake3ServerEvents for Quake3LogSyncRea
 
 }
 
-// for unit tests, see sync_file_reader.rs
-// (the tests were delegated there as it is easier to test from files)
\ No newline at end of file
+/// The outcome of [read_line()]
+pub(crate) enum ReadLine {
+    Line(String),
+    /// The line exceeded `max_line_length` and was truncated -- `length` is the original one, in bytes
+    Oversized { length: usize },
+}
+
+/// Reads the next line from `reader` -- like [BufRead::lines()] does -- but buffering at most `max_line_length` bytes of it
+/// & decoding invalid UTF-8 as replacement characters, if `lossy_utf8`: see [Config::lossy_utf8].\
+/// Returns `None` at the end of the input.
+fn read_line(reader: &mut impl BufRead, max_line_length: Option<usize>, lossy_utf8: bool) -> Option<std::io::Result<ReadLine>> {
+    let mut line_accumulator = LineAccumulator::default();
+    loop {
+        let buffer = match reader.fill_buf() {
+            Ok(buffer) => buffer,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Some(Err(err)),
+        };
+        if buffer.is_empty() {
+            break
+        }
+        let (consumed, is_complete) = line_accumulator.feed(buffer, max_line_length);
+        reader.consume(consumed);
+        if is_complete {
+            break
+        }
+    }
+    line_accumulator.finish(lossy_utf8)
+}
+
+/// Gathers the bytes of a line out of the successive buffers of a reader -- keeping at most `max_line_length` of them.
+/// Shared by [read_line()] & its async counterpart, in [crate::async_file_reader]
+#[derive(Default)]
+pub(crate) struct LineAccumulator {
+    line: Vec<u8>,
+    /// The length of the line, in bytes -- including the ones beyond `max_line_length`
+    length: usize,
+    last_byte: Option<u8>,
+    anything_read: bool,
+}
+
+impl LineAccumulator {
+
+    /// Takes in the bytes of `buffer` up to the end of the line, returning how many of them should be consumed from the reader
+    /// & whether the line is complete
+    pub(crate) fn feed(&mut self, buffer: &[u8], max_line_length: Option<usize>) -> (usize, bool) {
+        self.anything_read = true;
+        let newline_position = buffer.iter().position(|&byte| byte == b'\n');
+        let chunk = &buffer[..newline_position.unwrap_or(buffer.len())];
+        let room = max_line_length.map_or(chunk.len(), |max_line_length| max_line_length.saturating_sub(self.line.len()).min(chunk.len()));
+        self.line.extend_from_slice(&chunk[..room]);
+        self.length += chunk.len();
+        self.last_byte = chunk.last().copied().or(self.last_byte);
+        (chunk.len() + newline_position.map_or(0, |_| 1), newline_position.is_some())
+    }
+
+    /// The line read -- trimmed of any `\r` & decoded as in [read_line()] -- or `None` if nothing was fed, at the end of the input
+    pub(crate) fn finish(self, lossy_utf8: bool) -> Option<std::io::Result<ReadLine>> {
+        let Self { mut line, mut length, last_byte, anything_read } = self;
+        if !anything_read {
+            return None
+        }
+        if last_byte == Some(b'\r') {
+            length -= 1;
+            line.truncate(length);
+        }
+        if length > line.len() {
+            return Some(Ok(ReadLine::Oversized { length }))
+        }
+        Some(String::from_utf8(line)
+            .or_else(|utf8_err| if lossy_utf8 {
+                Ok(String::from_utf8_lossy(utf8_err.as_bytes()).into_owned())
+            } else {
+                Err(std::io::Error::new(ErrorKind::InvalidData, "stream did not contain valid UTF-8"))
+            })
+            .map(ReadLine::Line))
+    }
+
+}
+
+// for most of the unit tests, see sync_file_reader.rs
+// (the tests were delegated there as it is easier to test from files)
+
+
+/// Unit tests the [sync_reader](super) implementation of [dal_api::Quake3ServerEvents] for in-memory sources
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+
+    /// Tests that chained sources, built with the appropriate `line_number_offset`, yield continuous event ids & line numbers
+    #[test]
+    fn chained_sources_numbering() {
+        let first_source = "  0:00 InitGame: \\fraglimit\\20\\timelimit\\15\\capturelimit\\8\n  0:01 ClientConnect: 2\n";
+        let second_source = "  0:02 ClientDisconnect: 2\n  0:03 Bogus: data\n  0:04 ShutdownGame:\n";
+        let first_dao = Quake3LogSyncReader::new(config(), "first", Cursor::new(first_source.as_bytes()), 0);
+        let second_dao = Quake3LogSyncReader::new(config(), "second", Cursor::new(second_source.as_bytes()), 2);
+        let stream = first_dao.events_stream().expect("Couldn't create the first `Stream`")
+            .chain(second_dao.events_stream().expect("Couldn't create the second `Stream`"));
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        let event_ids: Vec<u32> = events.iter().map(|event| event.event_id()).collect();
+        assert_eq!(event_ids, vec![1, 2, 3, 4, 5], "Event ids are not continuous across the chained sources");
+        assert_eq!(events[3].unwrap_err().to_string(),
+                   r#"`LogParsingError` when processing log file 'second' at line 4: EventParsingError { event_name: "Bogus", event_parsing_error: UnknownEventName }"#,
+                   "The error line number didn't take the offset into account");
+    }
+
+
+    /// Tests that out of range minutes are only rejected when [Config::validate_time_ranges] is set
+    /// -- whereas out of range seconds are always rejected
+    #[test]
+    fn time_ranges_validation() {
+        let source = "1000:00 ShutdownGame:\n10:75 ShutdownGame:\n";
+        let events = |config| futures::executor::block_on_stream(Quake3LogSyncReader::new(config, "source", Cursor::new(source.as_bytes()), 0)
+            .events_stream().expect("Couldn't create the `Stream`"))
+            .collect::<Vec<Quake3Events>>();
+
+        let lenient_events = events(config());
+        assert!(lenient_events[0].is_ok(), "Out of range minutes should be tolerated by default");
+        assert_eq!(lenient_events[1].unwrap_err().to_string(),
+                   r#"`LogParsingError` when processing log file 'source' at line 2: UnparseableTime { field_name: "seconds", observed_number: "75" }"#,
+                   "Out of range seconds should have been rejected, even by default");
+
+        let strict_config = Arc::new(Config {
+            validate_time_ranges: true,
+            max_log_minutes: Some(999),
+            ..Config::default()
+        });
+        let strict_events = events(strict_config);
+        assert_eq!(strict_events[0].unwrap_err().to_string(),
+                   r#"`LogParsingError` when processing log file 'source' at line 1: UnparseableTime { field_name: "minutes", observed_number: "1000" }"#,
+                   "Out of range minutes should have been rejected");
+        assert!(strict_events[1].is_err(), "Out of range seconds should have been rejected");
+    }
+
+
+    /// Tests that the first event id -- and line number, in error messages -- follows [Config::event_id_base]
+    #[test]
+    fn event_id_base() {
+        let source = "  0:00 Bogus: data\n  0:01 ShutdownGame:\n";
+        let zero_based_config = Arc::new(Config {
+            event_id_base: 0,
+            ..Config::default()
+        });
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(Quake3LogSyncReader::new(zero_based_config, "source", Cursor::new(source.as_bytes()), 0)
+            .events_stream().expect("Couldn't create the `Stream`"))
+            .collect();
+        assert_eq!(events.iter().map(|event| event.event_id()).collect::<Vec<_>>(), vec![0, 1], "Event ids should be 0-based");
+        assert_eq!(events[0].unwrap_err().to_string(),
+                   r#"`LogParsingError` when processing log file 'source' at line 0: EventParsingError { event_name: "Bogus", event_parsing_error: UnknownEventName }"#,
+                   "The error line number should be 0-based");
+    }
+
+    /// Tests that lines longer than [Config::max_line_length] yield an error, without interrupting the `Stream`
+    #[test]
+    fn oversized_lines() {
+        let source = format!("  0:00 InitGame: \\fraglimit\\20\n  0:01 ClientConnect: 2{}\n  0:02 ClientConnect: 3\n", " ".repeat(1000));
+        let config = Arc::new(Config {
+            max_line_length: Some(64),
+            ..Config::default()
+        });
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(Quake3LogSyncReader::new(config, "source", Cursor::new(source.into_bytes()), 0)
+            .events_stream().expect("Couldn't create the `Stream`"))
+            .collect();
+        assert_eq!(events.len(), 3, "The `Stream` should have continued after the oversized line");
+        assert!(events[0].is_ok(), "The first line is within the limit: {:?}", events[0]);
+        assert_eq!(events[1].unwrap_err().to_string(),
+                   "`LogParsingError` when processing log file 'source' at line 2: line length of 1023 bytes exceeds the maximum of 64 -- truncated",
+                   "The oversized line went unreported");
+        assert!(matches!(events[2], Quake3Events::ClientConnect { client_id: 3, .. }), "The line after the oversized one wasn't read correctly: {:?}", events[2]);
+    }
+
+    /// Tests that logs in a nonstandard format are read through [Config::log_line_pattern] -- and that patterns
+    /// lacking the required named groups are refused at `Stream` creation
+    #[cfg(feature = "regex")]
+    #[test]
+    fn custom_log_line_pattern() {
+        let source = "[0h00] InitGame: \\fraglimit\\20\n[0h01] ClientConnect: 2\n[1h00] ShutdownGame:\n";
+        let config = Arc::new(Config {
+            log_line_pattern: Some(r#"^\[(?P<hour>\d+)h(?P<minute>\d{2})\] (?P<event_name>[^:]*):? *(?P<data>.*)$"#.to_owned()),
+            ..Config::default()
+        });
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(Quake3LogSyncReader::new(config, "source", Cursor::new(source.as_bytes()), 0)
+            .events_stream().expect("Couldn't create the `Stream`"))
+            .collect();
+        assert_eq!(events.len(), 3, "Unexpected number of events");
+        assert!(events.iter().all(|event| event.is_ok()), "The custom format wasn't parsed: {events:?}");
+        assert!(matches!(events[1], Quake3Events::ClientConnect { client_id: 2, time_secs: Some(60), .. }), "Unexpected event: {:?}", events[1]);
+        assert!(matches!(events[2], Quake3Events::ShutdownGame { time_secs: Some(3600), .. }), "Unexpected event: {:?}", events[2]);
+
+        let bad_config = Arc::new(Config {
+            log_line_pattern: Some(r#"^(?P<minute>\d+) (?P<data>.*)$"#.to_owned()),
+            ..Config::default()
+        });
+        let result = Quake3LogSyncReader::new(bad_config, "source", Cursor::new(source.as_bytes()), 0).events_stream();
+        assert_eq!(result.err().map(|err| err.to_string()),
+                   Some(r#"Invalid custom log line pattern '^(?P<minute>\d+) (?P<data>.*)$': MissingGroup { group_name: "event_name" }"#.to_owned()),
+                   "A pattern without `event_name` should have been refused");
+    }
+
+    /// Tests that, without the `regex` feature, [Config::log_line_pattern] is refused at `Stream` creation -- rather than ignored
+    #[cfg(not(feature = "regex"))]
+    #[test]
+    fn custom_log_line_pattern_without_regex() {
+        let config = Arc::new(Config {
+            log_line_pattern: Some(r#"^(?P<minute>\d+) (?P<event_name>[^:]*):? *(?P<data>.*)$"#.to_owned()),
+            ..Config::default()
+        });
+        let result = Quake3LogSyncReader::new(config, "source", Cursor::new(&b""[..]), 0).events_stream();
+        assert_eq!(result.err().map(|err| err.to_string()),
+                   Some("log_line_deserializer(): custom log line patterns require the `regex` feature of the `dal` crate".to_owned()),
+                   "The custom pattern should have been refused");
+    }
+
+
+    fn config() -> Arc<Config> {
+        Arc::new(Config {
+            debug: false,
+            ..Config::default()
+        })
+    }
+
+}
\ No newline at end of file
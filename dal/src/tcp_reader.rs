@@ -0,0 +1,105 @@
+//! Resting place for [Quake3LogTcpReader] -- enabled by the `socket` feature
+
+
+use crate::sync_reader::Quake3LogSyncReader;
+use common::types::Result;
+use model::quake3_events::Quake3Events;
+use dal_api::{Config, Quake3ServerEvents};
+use std::{
+    io::BufReader,
+    net::TcpStream,
+    pin::Pin,
+    sync::Arc,
+};
+use futures::Stream;
+
+
+/// Size for buffering IO (the larger, more RAM is used, but fewer system calls / context switches / hardware requests are required)
+const BUFFER_SIZE: usize = 64*1024;
+
+
+/// [Quake3ServerEvents] implementation for reading a live Quake 3 Server log, as newline-delimited lines, from a TCP server
+/// at `host:port` -- such as one fed by `tail -f games.log | nc -l 27961`.\
+/// The lines are read just like the ones of a log file -- see [Quake3LogSyncReader] -- and the `Stream` ends when the connection is closed.
+/// Failing to connect fails the `Stream` creation, while IO errors afterwards are yielded as [Quake3Events::Error]s.
+pub struct Quake3LogTcpReader {
+    config: Arc<Config>,
+    address: String,
+}
+
+impl Quake3LogTcpReader {
+
+    pub fn new(config: Arc<Config>, address: impl Into<String>) -> Box<Self> {
+        Box::new(Self {
+            config,
+            address: address.into(),
+        })
+    }
+
+}
+
+impl Quake3ServerEvents for Quake3LogTcpReader {
+
+    fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
+        let tcp_stream = TcpStream::connect(&self.address)
+            .map_err(|err| format!("Couldn't connect to the Quake3 Server log stream at '{}': {err}", self.address))?;
+        let reader = BufReader::with_capacity(BUFFER_SIZE, tcp_stream);
+        Quake3LogSyncReader::new(self.config, &format!("tcp://{}", self.address), reader, 0)
+            .events_stream()
+    }
+
+}
+
+
+/// Unit tests the [tcp_reader](super) implementation of [dal_api::Quake3ServerEvents]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::Write,
+        net::TcpListener,
+        thread,
+    };
+
+
+    /// The location of a good log file, with all lines OK
+    const GOOD_LOG_FILE_LOCATION: &str = "tests/resources/qgames_excerpt.log";
+
+
+    /// Tests that the lines served through the connection are all read, until it is closed
+    #[test]
+    fn served_log() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind the listener");
+        let address = listener.local_addr().expect("Couldn't get the listener address").to_string();
+        let server = thread::spawn(move || {
+            let (mut connection, _peer_addr) = listener.accept().expect("Couldn't accept the connection");
+            let log = std::fs::read(GOOD_LOG_FILE_LOCATION).expect("Couldn't read the log file");
+            connection.write_all(&log).expect("Couldn't serve the log");
+        });
+
+        let log_dao = Quake3LogTcpReader::new(Arc::new(Config::default()), address.clone());
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        server.join().expect("The server thread panicked");
+
+        assert!(events.iter().all(|event| event.is_ok()), "Unexpected errors: {events:?}");
+        assert_eq!(events.len(), 93, "Unexpected number of events");
+        assert!(matches!(&events[0], Quake3Events::InitGame { source: Some(source), .. } if *source == format!("tcp://{address}")), "The games should be tagged with the server address");
+    }
+
+    /// Tests that failing to connect fails the `Stream` creation
+    #[test]
+    fn connection_refused() {
+        // a port just freed up has no one listening on it
+        let address = TcpListener::bind("127.0.0.1:0").expect("Couldn't bind the listener")
+            .local_addr().expect("Couldn't get the listener address")
+            .to_string();
+        let log_dao = Quake3LogTcpReader::new(Arc::new(Config::default()), address.clone());
+        match log_dao.events_stream() {
+            Ok(_stream) => panic!("Connecting to a closed port was expected to fail at `Stream` creation, but the operation succeeded"),
+            Err(stream_creation_err) => assert!(stream_creation_err.to_string().starts_with(&format!("Couldn't connect to the Quake3 Server log stream at '{address}': ")),
+                                                "Unexpected `Stream` creation error: {stream_creation_err}"),
+        }
+    }
+
+}
@@ -0,0 +1,237 @@
+//! Resting place for [WatchedDirectoryReader] -- enabled by the `watch` feature
+
+
+use crate::events_translation::{log_line_deserializer, translate_quake3_events};
+use common::types::Result;
+use model::quake3_events::Quake3Events;
+use dal_api::{Config, Quake3ServerEvents};
+use std::{
+    collections::{HashSet, VecDeque},
+    fs::{File, Metadata},
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{mpsc, Arc},
+    time::Duration,
+};
+use futures::{Stream, stream, StreamExt};
+use log::info;
+use notify::{EventKind, RecursiveMode, Watcher};
+
+
+/// [Quake3ServerEvents] implementation for following the Quake 3 Server log files of a directory, as they are written & rotated:
+/// the files already there are read first -- oldest first -- then the newest one is followed, until a new file appears in the
+/// directory, which is then followed instead.\
+/// The file being followed may be renamed (rotated) at will: as it is tracked by its identity, it is not mistaken by a new file.\
+/// The `Stream` blocks while waiting for new data, ending once `idle_timeout` elapses without any -- or never, if it is `None`.
+pub struct WatchedDirectoryReader {
+    config: Arc<Config>,
+    directory_path: PathBuf,
+    idle_timeout: Option<Duration>,
+}
+
+impl WatchedDirectoryReader {
+
+    pub fn new(config: Arc<Config>, directory_path: impl Into<PathBuf>, idle_timeout: Option<Duration>) -> Box<Self> {
+        Box::new(Self {
+            config,
+            directory_path: directory_path.into(),
+            idle_timeout,
+        })
+    }
+
+}
+
+impl Quake3ServerEvents for WatchedDirectoryReader {
+
+    fn events_stream(self: Box<Self>) -> Result<Pin<Box<dyn Stream<Item=Quake3Events<'static>>>>> {
+        let directory_name = self.directory_path.display().to_string();
+        // watching starts before listing the existing files, so none may be missed
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event_result| { let _ = sender.send(event_result); })
+            .map_err(|err| format!("Couldn't create a watcher for the Quake3 Server logs directory '{directory_name}': {err}"))?;
+        watcher.watch(&self.directory_path, RecursiveMode::NonRecursive)
+            .map_err(|err| format!("Couldn't watch the Quake3 Server logs directory '{directory_name}': {err}"))?;
+        let mut existing_files = std::fs::read_dir(&self.directory_path)
+            .map_err(|err| format!("Couldn't list the Quake3 Server logs directory '{directory_name}': {err}"))?
+            .flatten()
+            .filter_map(|entry| entry.metadata().ok()
+                .filter(|metadata| metadata.is_file())
+                .map(|metadata| (metadata.modified().ok(), entry.path())))
+            .collect::<Vec<_>>();
+        existing_files.sort();
+
+        let mut followed_files = FollowedFiles {
+            _watcher: watcher,
+            receiver,
+            idle_timeout: self.idle_timeout,
+            buffer_size: self.config.buffer_size,
+            pending_paths: existing_files.into_iter().map(|(_modified, path)| path).collect(),
+            seen_files: HashSet::new(),
+            current: None,
+        };
+        let deserialize_log_line = log_line_deserializer(&self.config)?;
+        let lines = std::iter::from_fn(move || followed_files.next_line())
+            .map(move |line_result| line_result
+                .and_then(|(line, file_name, line_number)| deserialize_log_line(&line)
+                    .map_err(|log_parser_err| format!("`LogParsingError` when processing log file '{file_name}' at line {line_number}: {log_parser_err:?}")))
+                .map_err(Box::from));
+
+        let debug = self.config.debug;
+        let stream = translate_quake3_events(stream::iter(lines), self.config.event_id_base, &directory_name, false);
+        let stream: Pin<Box<dyn Stream<Item=Quake3Events<'static>>>> = if debug {
+            Box::pin(stream
+                .inspect(|yielded_event| info!("{yielded_event:?}")))
+        } else {
+            Box::pin(stream)
+        };
+        Ok(stream)
+    }
+
+}
+
+/// Identifies a file regardless of its name -- so the followed file may be renamed (rotated) without being mistaken by a new one
+#[cfg(unix)]
+type FileId = (u64, u64);
+#[cfg(not(unix))]
+type FileId = PathBuf;
+
+#[cfg(unix)]
+fn file_id(_path: &Path, metadata: &Metadata) -> FileId {
+    use std::os::unix::fs::MetadataExt;
+    (metadata.dev(), metadata.ino())
+}
+#[cfg(not(unix))]
+fn file_id(path: &Path, _metadata: &Metadata) -> FileId {
+    path.to_path_buf()
+}
+
+/// The state of the files read by [WatchedDirectoryReader]
+struct FollowedFiles {
+    /// dropping the watcher would stop the filesystem events
+    _watcher: notify::RecommendedWatcher,
+    receiver: mpsc::Receiver<notify::Result<notify::Event>>,
+    idle_timeout: Option<Duration>,
+    /// see [Config::buffer_size]
+    buffer_size: usize,
+    /// files yet to be read, in order
+    pending_paths: VecDeque<PathBuf>,
+    /// files either read or pending
+    seen_files: HashSet<FileId>,
+    /// the file being read: its reader, name, the number of its last read line & the contents read past it, yet without a line break
+    current: Option<(BufReader<File>, String, u32, String)>,
+}
+
+impl FollowedFiles {
+
+    /// Returns the next line, along with the name of its file & its line number there -- waiting for it, if needed.\
+    /// `None` is returned if no new data arrives within the idle timeout
+    fn next_line(&mut self) -> Option<std::result::Result<(String, String, u32), String>> {
+        loop {
+            if let Some((reader, file_name, line_number, partial_line)) = &mut self.current {
+                match reader.read_line(partial_line) {
+                    Err(err) => return Some(Err(format!("IO read error when processing log file '{file_name}' at line {}: {err:?}", *line_number + 1))),
+                    Ok(_bytes_read) if partial_line.ends_with('\n') => {
+                        *line_number += 1;
+                        let line = std::mem::take(partial_line);
+                        return Some(Ok((line.trim_end_matches(['\n', '\r']).to_owned(), file_name.clone(), *line_number)))
+                    },
+                    // at the end of the file -- which is done with only if a newer one is there
+                    Ok(_bytes_read) if !self.pending_paths.is_empty() => {
+                        let (_reader, file_name, line_number, partial_line) = self.current.take()?;
+                        if !partial_line.is_empty() {
+                            return Some(Ok((partial_line, file_name, line_number + 1)))
+                        }
+                    },
+                    Ok(_bytes_read) => (),
+                }
+            }
+            if self.current.is_none() {
+                if let Some(path) = self.pending_paths.pop_front() {
+                    let file_name = path.display().to_string();
+                    match File::open(&path).and_then(|file| file.metadata().map(|metadata| (file, metadata))) {
+                        Ok((file, metadata)) => {
+                            self.seen_files.insert(file_id(&path, &metadata));
+                            self.current = Some((BufReader::with_capacity(self.buffer_size, file), file_name, 0, String::new()));
+                        },
+                        Err(err) => return Some(Err(format!("Couldn't open Quake3 Server log file '{file_name}' for reading: {err}"))),
+                    }
+                    continue
+                }
+            }
+            // nothing left to read: wait for the directory to change
+            let event_result = match self.idle_timeout {
+                Some(idle_timeout) => self.receiver.recv_timeout(idle_timeout).ok()?,
+                None => self.receiver.recv().ok()?,
+            };
+            match event_result {
+                Ok(event) if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(notify::event::ModifyKind::Name(_))) => {
+                    for path in event.paths {
+                        let Ok(metadata) = std::fs::metadata(&path)
+                            else {
+                                continue
+                            };
+                        if metadata.is_file() && self.seen_files.insert(file_id(&path, &metadata)) {
+                            self.pending_paths.push_back(path);
+                        }
+                    }
+                },
+                Ok(_other_event) => (),
+                Err(err) => return Some(Err(format!("Error watching the Quake3 Server logs directory: {err}"))),
+            }
+        }
+    }
+
+}
+
+
+/// Unit tests the [watched_directory_reader](super) implementation of [dal_api::Quake3ServerEvents]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::Write,
+        thread,
+    };
+
+
+    /// Tests that the lines of the files written to the directory are all seen -- including the ones appended after
+    /// the followed file was rotated (renamed) & the ones in the new file that took its place
+    #[test]
+    fn rotated_files() {
+        let directory_path = std::env::temp_dir().join(format!("quake3-watched-directory-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory_path);
+        std::fs::create_dir_all(&directory_path).expect("Couldn't create the temporary directory");
+        let log_path = directory_path.join("games.log");
+        std::fs::write(&log_path, "  0:00 InitGame: \\fraglimit\\20\n  0:01 ClientConnect: 1\n").expect("Couldn't write the log file");
+
+        let writer_directory_path = directory_path.clone();
+        let writer = thread::spawn(move || {
+            let log_path = writer_directory_path.join("games.log");
+            thread::sleep(Duration::from_millis(200));
+            let mut log_file = File::options().append(true).open(&log_path).expect("Couldn't open the log file for appending");
+            log_file.write_all(b"  0:02 ClientConnect: 2\n").expect("Couldn't append to the log file");
+            std::fs::rename(&log_path, writer_directory_path.join("games.log.1")).expect("Couldn't rotate the log file");
+            log_file.write_all(b"  0:03 ShutdownGame:\n").expect("Couldn't append to the rotated log file");
+            thread::sleep(Duration::from_millis(200));
+            std::fs::write(&log_path, "  0:00 InitGame: \\fraglimit\\20\n  0:01 ClientConnect: 3\n").expect("Couldn't write the new log file");
+        });
+
+        let log_dao = WatchedDirectoryReader::new(Arc::new(Config::default()), &directory_path, Some(Duration::from_secs(2)));
+        let stream = log_dao.events_stream().expect("Couldn't create the `Stream`");
+        let events: Vec<Quake3Events> = futures::executor::block_on_stream(stream).collect();
+        writer.join().expect("The writer thread panicked");
+        let _ = std::fs::remove_dir_all(&directory_path);
+
+        assert!(events.iter().all(|event| event.is_ok()), "Unexpected errors: {events:?}");
+        let client_ids = events.iter()
+            .filter_map(|event| match event {
+                Quake3Events::ClientConnect { client_id, .. } => Some(*client_id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(client_ids, vec![1, 2, 3], "Not all files were followed");
+        assert_eq!(events.len(), 6, "Unexpected number of events: {events:?}");
+    }
+
+}
@@ -1,4 +1,5 @@
 #![doc = include_str!("../README.md")]
 
+pub mod means_of_death;
 pub mod quake3_events;
 pub mod report;
@@ -0,0 +1,63 @@
+//! Resting place for [MeansOfDeathKind]
+
+use std::fmt::{Display, Formatter};
+
+
+/// Broad categories for the means of death (the `reason_name` of `Kill` events) -- see [MeansOfDeathKind::of()]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MeansOfDeathKind {
+    /// Instant hit weapons: shotgun, machinegun, railgun, lightning gun & chaingun
+    Hitscan,
+    /// Weapons causing explosions, as well as their splash damage: grenades, rockets, plasma, BFG, mines & the like
+    Explosive,
+    /// The gauntlet
+    Melee,
+    /// Deaths caused by the map: falling, drowning, being crushed, lava, slime, hurting triggers & lasers
+    Environmental,
+    /// Anything else -- telefrags, suicides, unknown or non-standard means
+    Other,
+}
+
+impl MeansOfDeathKind {
+
+    /// Classifies the standard Quake 3 `MOD_*` means of death -- unknown names are [Self::Other]
+    pub fn of(reason_name: &str) -> Self {
+        match reason_name {
+            "MOD_SHOTGUN" | "MOD_MACHINEGUN" | "MOD_RAILGUN" | "MOD_LIGHTNING" | "MOD_CHAINGUN" => Self::Hitscan,
+            "MOD_GRENADE" | "MOD_GRENADE_SPLASH" | "MOD_ROCKET" | "MOD_ROCKET_SPLASH" | "MOD_PLASMA" | "MOD_PLASMA_SPLASH" |
+            "MOD_BFG" | "MOD_BFG_SPLASH" | "MOD_PROXIMITY_MINE" | "MOD_KAMIKAZE" | "MOD_JUICED" => Self::Explosive,
+            "MOD_GAUNTLET" => Self::Melee,
+            "MOD_WATER" | "MOD_SLIME" | "MOD_LAVA" | "MOD_CRUSH" | "MOD_FALLING" | "MOD_TRIGGER_HURT" | "MOD_TARGET_LASER" => Self::Environmental,
+            _ => Self::Other,
+        }
+    }
+
+}
+
+impl Display for MeansOfDeathKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+
+/// Unit tests for the [means_of_death](super) module
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// Tests the classification of some standard means of death -- and of unknown ones
+    #[test]
+    fn classification() {
+        assert_eq!(MeansOfDeathKind::of("MOD_RAILGUN"), MeansOfDeathKind::Hitscan, "Wrong kind for the railgun");
+        assert_eq!(MeansOfDeathKind::of("MOD_ROCKET"), MeansOfDeathKind::Explosive, "Wrong kind for rockets");
+        assert_eq!(MeansOfDeathKind::of("MOD_ROCKET_SPLASH"), MeansOfDeathKind::Explosive, "Wrong kind for the rockets' splash");
+        assert_eq!(MeansOfDeathKind::of("MOD_GAUNTLET"), MeansOfDeathKind::Melee, "Wrong kind for the gauntlet");
+        assert_eq!(MeansOfDeathKind::of("MOD_TRIGGER_HURT"), MeansOfDeathKind::Environmental, "Wrong kind for hurting triggers");
+        assert_eq!(MeansOfDeathKind::of("MOD_TELEFRAG"), MeansOfDeathKind::Other, "Wrong kind for telefrags");
+        assert_eq!(MeansOfDeathKind::of("MOD_FROM_A_MOD"), MeansOfDeathKind::Other, "Unknown means should be `Other`");
+    }
+
+}
@@ -5,7 +5,12 @@ use std::borrow::Cow;
 
 /// Maps the Quake3 server events & info we care about, in close relation to [quake3-server-events::model::Quake3FullEvents].\
 /// For detailed docs on each variant & field, please consult the referred object, which has the full picture.\
-/// Every variant has an `event_id` -- it starts from 1 and references to the original events from the library.
+/// Every variant has an `event_id` -- it starts from 1 (unless configured otherwise in the DAL) and references to the original events from the library.\
+/// Non-error variants also carry `time_secs`: the event's log time, in seconds -- `None` if it was unavailable.\
+/// `InitGame` also carries the `source` the game is being read from (a file path, for instance) -- as sources may be chained --
+/// and `ShutdownGame`, the `source_hash` of the game's log lines, if the DAL was configured to compute it.\
+/// `StreamEnd`, if the DAL was configured to give it out, is the last event of a `Stream` that finished cleanly -- carrying
+/// the number of lines read -- so consumers may tell it apart from one that was aborted.
 ///
 /// IMPLEMENTATION NOTE: Notice this enum is similar to the one in the `quake3-server-events` crate.
 /// Nonetheless, both should exist (regardless of the repetitiveness) for the following reasons:
@@ -14,14 +19,18 @@ use std::borrow::Cow;
 ///   2) By unbinding the models, we end up having a simpler logic (as we don't need to know everything about all events)
 #[derive(Debug)]
 pub enum Quake3Events<'a> {
-    InitGame              { event_id: u32 },
-    ClientConnect         { event_id: u32, client_id: u32 },
-    ClientUserinfoChanged { event_id: u32, client_id: u32, name: Cow<'a, str>},
-    ClientDisconnect      { event_id: u32, client_id: u32 },
-    Kill                  { event_id: u32, killer_id: u32, victim_id: u32, reason_id: u32, killer_name: Cow<'a, str>, victim_name: Cow<'a, str>, reason_name: Cow<'a, str> },
-    Exit                  { event_id: u32 },
-    Score                 { event_id: u32, frags: i32, client_id: u32, name: Cow<'a, str> },
-    ShutdownGame          { event_id: u32 },
+    InitGame              { event_id: u32, time_secs: Option<u32>, map_name: Option<Cow<'a, str>>, source: Option<Cow<'a, str>> },
+    ClientConnect         { event_id: u32, time_secs: Option<u32>, client_id: u32 },
+    ClientUserinfoChanged { event_id: u32, time_secs: Option<u32>, client_id: u32, name: Cow<'a, str>},
+    ClientDisconnect      { event_id: u32, time_secs: Option<u32>, client_id: u32 },
+    Kill                  { event_id: u32, time_secs: Option<u32>, killer_id: u32, victim_id: u32, reason_id: u32, killer_name: Cow<'a, str>, victim_name: Cow<'a, str>, reason_name: Cow<'a, str> },
+    Exit                  { event_id: u32, time_secs: Option<u32> },
+    Score                 { event_id: u32, time_secs: Option<u32>, frags: i32, client_id: u32, name: Cow<'a, str> },
+    Say                   { event_id: u32, time_secs: Option<u32>, name: Cow<'a, str>, message: Cow<'a, str> },
+    Tell                  { event_id: u32, time_secs: Option<u32>, from: Cow<'a, str>, to: Cow<'a, str>, message: Cow<'a, str> },
+    Item                  { event_id: u32, time_secs: Option<u32> },
+    ShutdownGame          { event_id: u32, time_secs: Option<u32>, source_hash: Option<Cow<'a, str>> },
+    StreamEnd             { event_id: u32, total_lines: u32 },
     Error                 { event_id: u32, err: Box<dyn std::error::Error> }
 }
 
@@ -55,9 +64,32 @@ impl Quake3Events<'_> {
             Quake3Events::Kill                  { event_id, .. } |
             Quake3Events::Exit                  { event_id, .. } |
             Quake3Events::Score                 { event_id, .. } |
+            Quake3Events::Say                   { event_id, .. } |
+            Quake3Events::Tell                  { event_id, .. } |
+            Quake3Events::Item                  { event_id, .. } |
             Quake3Events::ShutdownGame          { event_id, .. } |
+            Quake3Events::StreamEnd             { event_id, .. } |
             Quake3Events::Error                 { event_id, .. } => *event_id
         }
     }
 
+    /// Returns the log time, in seconds, of the source Quake3 event -- `None` for errors, for the `StreamEnd` marker or if the time was unavailable
+    pub fn time_secs(&self) -> Option<u32> {
+        match self {
+            Quake3Events::InitGame              { time_secs, .. } |
+            Quake3Events::ClientConnect         { time_secs, .. } |
+            Quake3Events::ClientUserinfoChanged { time_secs, .. } |
+            Quake3Events::ClientDisconnect      { time_secs, .. } |
+            Quake3Events::Kill                  { time_secs, .. } |
+            Quake3Events::Exit                  { time_secs, .. } |
+            Quake3Events::Score                 { time_secs, .. } |
+            Quake3Events::Say                   { time_secs, .. } |
+            Quake3Events::Tell                  { time_secs, .. } |
+            Quake3Events::Item                  { time_secs, .. } |
+            Quake3Events::ShutdownGame          { time_secs, .. } => *time_secs,
+            Quake3Events::StreamEnd             { .. } |
+            Quake3Events::Error                 { .. } => None,
+        }
+    }
+
 }
\ No newline at end of file
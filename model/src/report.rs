@@ -1,32 +1,239 @@
 //! Contains summary data used to build reports
 
 use std::{
-    collections::{BTreeSet, BTreeMap},
+    collections::BTreeMap,
     pin::Pin,
 };
 use futures::Stream;
+pub use indexmap::{IndexMap, IndexSet};
 
 /// Grouped information for all matches / games available
 pub type GamesSummary = Pin<Box<dyn Stream<Item=Result<GameMatchSummary, Box<dyn std::error::Error>>>>>;
 
+/// A player's connected intervals in a game -- see [GameMatchSummary::sessions]
+pub type PlayerSessions = Vec<(u32, Option<u32>)>;
+
 /// Grouped information for a single match / game
-#[derive(Debug,PartialEq)]
+#[derive(Debug,Default,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct GameMatchSummary {
     /// Sum of the frags of all players in [Self::kills]
     pub total_kills: u32,
     /// The name of the available players at the moment the match ended
-    pub players: BTreeSet<String>,
-    /// The frag score for each of the [Self::players].
-    pub kills: BTreeMap<String, i32>,
+    /// -- sorted by name, unless the producer opted to preserve their order of appearance.\
+    /// Notice equality doesn't take the order into account.
+    pub players: IndexSet<String>,
+    /// The frag score for each of the [Self::players] -- in the same order.
+    pub kills: IndexMap<String, i32>,
+    /// The number of distinct names that ever connected or scored in the match -- unlike `players.len()`,
+    /// this includes the ones that disconnected before the match ended
+    pub total_participants: u32,
 
     /// extended / optional fields
     //////////////////////////////
 
+    /// The map the match was played on -- lowercased if the producer opted to canonicalize the map names
+    pub map_name: Option<String>,
     /// The number of casualties caused by each reasons
+    #[cfg_attr(feature = "serde", serde(rename = "kills_by_means"))]
     pub means_of_death: Option<BTreeMap<String, i32>>,
     /// The score the server reports through `score` events
     pub game_reported_scores: Option<BTreeMap<String, i32>>,
     /// Vector of users who disconnected before the game ended,
     /// in the form (id, nick, frags)
-    pub disconnected_players: Option<Vec<(u32, String, i32)>>
-}
\ No newline at end of file
+    pub disconnected_players: Option<Vec<(u32, String, i32)>>,
+    /// The average number of log minutes between each player's consecutive frags
+    /// -- players with less than 2 frags are left out
+    pub avg_frag_gap_minutes: Option<BTreeMap<String, f64>>,
+    /// The log minute having the most kills -- the earliest one, in case of ties -- in the form (minute, kills)
+    pub busiest_minute: Option<(u32, u32)>,
+    /// Consecutive games played by the exact same set of players (rematches) share the same series id
+    pub series_id: Option<u32>,
+    /// The unaggregated `Kill` events of the game, in the form (event_id, killer, victim, reason)
+    pub raw_kills: Option<Vec<(u32, String, String, String)>>,
+    /// The net frags between each pair of players, derived from [Self::raw_kills]: `head_to_head[A][B]` is the number of
+    /// times A killed B minus the number of times B killed A -- so `head_to_head[B][A]` is its negation.
+    /// `<world>` & self kills are left out
+    pub head_to_head: Option<BTreeMap<String, BTreeMap<String, i32>>>,
+    /// Whether the winner -- the single top scorer in [Self::kills] -- was never a victim in [Self::raw_kills].
+    /// Absent if there are no raw kills or if the top score is tied
+    pub flawless: Option<bool>,
+    /// The number of `Item` events (pickups) in the game -- a lightweight measure of how actively it was played
+    pub item_events: Option<u32>,
+    /// The chat transcript of the game, in the form (event_id, speaker, message) -- private messages have the speaker
+    /// in the form `<FROM> to <TO>`, just like in the logs
+    pub chat: Option<Vec<(u32, String, String)>>,
+    /// Where the game was read from -- the log file path or `<stdin>`, for instance
+    pub source: Option<String>,
+    /// The longest stretch of log minutes without any kills, between two consecutive ones -- useful for spotting stalls.
+    /// Absent if the game has less than 2 kills
+    pub longest_drought_minutes: Option<u32>,
+    /// The lowercase hex SHA-256 of the game's raw log lines -- for provenance. Only present if requested, as it must be
+    /// computed by the DAL
+    pub source_hash: Option<String>,
+    /// Each player's connected intervals, in the form (connect_minute, disconnect_minute) -- in log minutes -- where a `None`
+    /// end means the player was still connected when the game ended. Only intervals having a known connection time are kept
+    pub sessions: Option<BTreeMap<String, PlayerSessions>>,
+    /// The statistical shape of the players' frags in [Self::kills] -- absent for games without any entries there
+    pub kill_distribution: Option<KillDistribution>,
+}
+
+impl GameMatchSummary {
+
+    /// Flattens this summary into one [FlatRecord] per player in [Self::players] -- in the same order, followed by the
+    /// ones only present in [Self::kills] -- tagged with `game_id`: a representation suitable for columnar stores.
+    /// The fields not available in the summary are `None`
+    pub fn to_flat_records(&self, game_id: u32) -> Vec<FlatRecord> {
+        self.players.iter()
+            .chain(self.kills.keys().filter(|player| !self.players.contains(*player)))
+            .map(|player| FlatRecord {
+                game_id,
+                player: player.to_owned(),
+                kills: self.kills.get(player).copied().unwrap_or(0),
+                reported_score: self.game_reported_scores.as_ref()
+                    .and_then(|game_reported_scores| game_reported_scores.get(player).copied()),
+                deaths: self.raw_kills.as_ref()
+                    .map(|raw_kills| raw_kills.iter().filter(|(_event_id, _killer, victim, _reason)| victim == player).count() as u32),
+                map_name: self.map_name.clone(),
+                item_events: self.item_events,
+            })
+            .collect()
+    }
+
+}
+
+/// The mean, median & (population) standard deviation of the per-player frags of a game -- see [GameMatchSummary::kill_distribution]
+#[derive(Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KillDistribution {
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+}
+
+/// A single player's figures in a single game -- the flattened form of a [GameMatchSummary]: see [GameMatchSummary::to_flat_records()]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FlatRecord {
+    /// The game's position in the feed, as given by the caller
+    pub game_id: u32,
+    pub player: String,
+    /// The player's frags, as in [GameMatchSummary::kills] -- 0 for players without an entry there
+    pub kills: i32,
+    /// The player's score, as in [GameMatchSummary::game_reported_scores]
+    pub reported_score: Option<i32>,
+    /// The number of times the player was killed -- by anyone, including `<world>` & themselves. Only available along
+    /// with [GameMatchSummary::raw_kills]
+    pub deaths: Option<u32>,
+    /// The game's [GameMatchSummary::map_name]
+    pub map_name: Option<String>,
+    /// The game's [GameMatchSummary::item_events]
+    pub item_events: Option<u32>,
+}
+
+/// Aggregate stats across all games -- only counters, so it may be kept for feeds of any size.\
+/// See [Self::add()]
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlobalStats {
+    /// The number of games aggregated so far
+    pub total_games: u32,
+    /// Sum of the [GameMatchSummary::total_kills] of all games
+    pub total_kills: u64,
+    /// Sum of the [GameMatchSummary::kills] of each player, across all games
+    pub player_frags: BTreeMap<String, i64>,
+    /// Sum of the [GameMatchSummary::means_of_death] across all games -- present if any game had them
+    pub means_of_death: Option<BTreeMap<String, i64>>,
+    /// The number of games played on each [GameMatchSummary::map_name]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub games_by_map: BTreeMap<String, u32>,
+}
+
+impl GlobalStats {
+
+    /// Aggregates the given game into these stats
+    pub fn add(&mut self, summary: &GameMatchSummary) {
+        self.total_games += 1;
+        self.total_kills += summary.total_kills as u64;
+        if let Some(map_name) = &summary.map_name {
+            *self.games_by_map.entry(map_name.to_owned()).or_insert(0) += 1;
+        }
+        for (player, frags) in &summary.kills {
+            *self.player_frags.entry(player.to_owned()).or_insert(0) += *frags as i64;
+        }
+        if let Some(game_means_of_death) = &summary.means_of_death {
+            let means_of_death = self.means_of_death.get_or_insert_with(BTreeMap::new);
+            for (mean_of_death, count) in game_means_of_death {
+                *means_of_death.entry(mean_of_death.to_owned()).or_insert(0) += *count as i64;
+            }
+        }
+    }
+
+}
+
+/// A single player's stats across all games of a feed -- see `bll::summarize_player()`
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PlayerCareerStats {
+    pub player_name: String,
+    /// The number of games the player took part in -- connected, scored or died
+    pub games_played: u32,
+    /// The number of frags the player was credited with -- `<world>` & self kills don't count
+    pub kills: u32,
+    /// The number of times the player was killed -- by anyone, including `<world>` & themselves
+    pub deaths: u32,
+    /// The means of death the player killed others the most with -- the first, by name, in case of ties.
+    /// `None` if the player never killed anyone
+    pub favorite_weapon: Option<String>,
+}
+
+
+/// Unit tests for the [report](super) module
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// Builds a summary for the given players & kills, with none of the optional fields
+    fn minimal_summary(kills: &[(&str, i32)]) -> GameMatchSummary {
+        GameMatchSummary {
+            total_kills: kills.iter().map(|(_player, frags)| *frags as u32).sum(),
+            players: kills.iter().map(|(player, _frags)| player.to_string()).collect(),
+            kills: kills.iter().map(|(player, frags)| (player.to_string(), *frags)).collect(),
+            total_participants: kills.len() as u32,
+            ..Default::default()
+        }
+    }
+
+    /// Tests the flattening of a summary having the optional fields -- deaths counting the ones by `<world>`
+    #[test]
+    fn complete_flat_records() {
+        let summary = GameMatchSummary {
+            map_name: Some(String::from("q3dm17")),
+            game_reported_scores: Some(BTreeMap::from([(String::from("Isgalamido"), 1), (String::from("Zeh"), 0)])),
+            raw_kills: Some(vec![
+                (1, String::from("Isgalamido"), String::from("Zeh"), String::from("MOD_RAILGUN")),
+                (2, String::from("<world>"), String::from("Zeh"), String::from("MOD_TRIGGER_HURT")),
+            ]),
+            item_events: Some(7),
+            ..minimal_summary(&[("Isgalamido", 1), ("Zeh", 0)])
+        };
+        assert_eq!(summary.to_flat_records(3), vec![
+            FlatRecord { game_id: 3, player: String::from("Isgalamido"), kills: 1, reported_score: Some(1), deaths: Some(0), map_name: Some(String::from("q3dm17")), item_events: Some(7) },
+            FlatRecord { game_id: 3, player: String::from("Zeh"),        kills: 0, reported_score: Some(0), deaths: Some(2), map_name: Some(String::from("q3dm17")), item_events: Some(7) },
+        ], "Wrong flat records");
+    }
+
+    /// Tests that the flat records of a summary without the optional fields have them as `None`
+    #[test]
+    fn minimal_flat_records() {
+        let summary = minimal_summary(&[("Zeh", 2)]);
+        assert_eq!(summary.to_flat_records(1), vec![
+            FlatRecord { game_id: 1, player: String::from("Zeh"), kills: 2, reported_score: None, deaths: None, map_name: None, item_events: None },
+        ], "Wrong flat records");
+        assert_eq!(minimal_summary(&[]).to_flat_records(1), vec![], "A game without players should have no records");
+        let scored_without_connecting = GameMatchSummary { players: IndexSet::new(), ..minimal_summary(&[("Zeh", 2)]) };
+        assert_eq!(scored_without_connecting.to_flat_records(1), summary.to_flat_records(1), "Players only present in `kills` should have their records");
+    }
+
+}
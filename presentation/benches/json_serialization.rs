@@ -0,0 +1,124 @@
+//! Measures the hand-crafted JSON serialization of [presentation::to_json()] over many games -- with all optional fields
+//! enabled -- so the allocations made while serializing each game may be kept in check.
+//!
+//! Two measurements are made:
+//!   1) The number of heap allocations -- counted by a wrapping global allocator and printed before the benchmarks run
+//!   2) The time taken to serialize all the games
+//!
+//! Run with `cargo bench -p presentation --bench json_serialization`.
+//!
+//! # Analysis 2026-10-16
+//!     1) Writing every field straight into the game's `String` -- instead of each helper returning its own -- cut the
+//!        allocations by ~93% (487818 -> 36018) for the 1000 games
+//!     2) Time went from ~57ms to ~35ms (dropping the summaries included)
+//!
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::BTreeMap,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use model::report::{GameMatchSummary, IndexMap, IndexSet, KillDistribution};
+use presentation::{Config, RankMetric};
+use futures::stream;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, black_box};
+
+
+/// How many games are serialized on each iteration
+const GAMES: u32 = 1000;
+
+
+/// Counts every allocation made through the system allocator
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+/// Builds the summary of a busy game -- 8 players, with most of the optional fields filled in
+fn game_summary(game_id: u32) -> GameMatchSummary {
+    let players = (1..=8).map(|player| format!("Player {player}")).collect::<IndexSet<_>>();
+    let kills = players.iter().zip(game_id as i32..).map(|(player, frags)| (player.clone(), frags % 20 - 2)).collect::<IndexMap<_, _>>();
+    let raw_kills = (0..40).map(|kill| (kill, players[kill as usize % 8].clone(), players[(kill as usize + 3) % 8].clone(), String::from("MOD_RAILGUN"))).collect::<Vec<_>>();
+    let head_to_head = players.iter()
+        .map(|player| (player.clone(), players.iter().filter(|opponent| *opponent != player).map(|opponent| (opponent.clone(), 1)).collect()))
+        .collect();
+    GameMatchSummary {
+        total_kills: 40,
+        total_participants: players.len() as u32 + 1,
+        map_name: Some(String::from("q3dm17")),
+        means_of_death: Some(BTreeMap::from([
+            (String::from("MOD_RAILGUN"), 30),
+            (String::from("MOD_ROCKET_SPLASH"), 6),
+            (String::from("MOD_TRIGGER_HURT"), 4),
+        ])),
+        game_reported_scores: Some(kills.iter().map(|(player, frags)| (player.clone(), *frags)).collect()),
+        disconnected_players: Some(vec![(9, String::from("Leaver"), 0)]),
+        avg_frag_gap_minutes: Some(players.iter().map(|player| (player.clone(), 1.25)).collect()),
+        busiest_minute: Some((3, 12)),
+        series_id: None,
+        raw_kills: Some(raw_kills),
+        head_to_head: Some(head_to_head),
+        flawless: Some(false),
+        item_events: Some(120),
+        chat: Some(vec![(41, String::from("Player 1"), String::from("\"gg\""))]),
+        source: Some(String::from("games.log")),
+        longest_drought_minutes: Some(2),
+        source_hash: None,
+        sessions: Some(players.iter().map(|player| (player.clone(), vec![(0, Some(3)), (4, None)])).collect()),
+        kill_distribution: Some(KillDistribution { mean: 5.0, median: 4.5, stddev: 2.25 }),
+        players,
+        kills,
+    }
+}
+
+/// Builds the summaries of [GAMES] games
+fn game_summaries() -> Vec<GameMatchSummary> {
+    (1..=GAMES).map(game_summary).collect()
+}
+
+/// Serializes the `summaries`, returning the size of the JSON
+fn serialize(config: &Config, summaries: Vec<GameMatchSummary>) -> usize {
+    let mut json = Vec::with_capacity(16 * 1024 * 1024);
+    presentation::to_json(config, Box::pin(stream::iter(summaries.into_iter().map(Ok))), &mut json)
+        .expect("to_json() shouldn't fail here");
+    json.len()
+}
+
+fn bench_json_serialization(criterion: &mut Criterion) {
+
+    let config = Config {
+        emit_chat: true,
+        emit_source: true,
+        emit_means_of_death_kinds: true,
+        emit_frag_sum: true,
+        include_kill_share: true,
+        rank_by: Some(RankMetric::Frags),
+        ..Config::default()
+    };
+
+    let summaries = game_summaries();
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let json_size = serialize(&config, summaries);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+    println!("{allocations} allocations to serialize {GAMES} games into {json_size} bytes of JSON");
+
+    let mut group = criterion.benchmark_group("Serializing many games");
+    group.bench_function("to_json()", |bencher| bencher.iter_batched(game_summaries, |summaries| black_box(serialize(&config, summaries)), BatchSize::LargeInput));
+    group.finish();
+}
+
+criterion_group!(benches, bench_json_serialization);
+criterion_main!(benches);
@@ -0,0 +1,92 @@
+//! Serializes the summaries as a BSON document -- enabled by the `bson` feature
+
+use crate::{Config, apply_game_options};
+use model::report::GamesSummary;
+use std::io::Write;
+use bson::Document;
+use log::warn;
+
+
+/// Serializes the summaries into `writer` as a single BSON document, keyed by `game_N` -- just like [crate::to_json()] does,
+/// including the field names (`means_of_death` goes out as `kills_by_means`) & the per-game options -- such as
+/// [Config::emit_chat] & [Config::anonymize_names]. Absent extended fields are written as `null`.\
+/// As BSON documents are length-prefixed, the whole document is built in memory before being written.
+pub fn to_bson(config: &Config, games_summary_stream: GamesSummary, mut writer: impl Write) -> Result<(), Box<dyn std::error::Error>> {
+
+    let mut games = Document::new();
+    let games_summary_stream = futures::executor::block_on_stream(games_summary_stream);
+    for (game_id, summary_result) in (1..).zip(games_summary_stream) {
+        match summary_result {
+            Ok(mut summary) => {
+                apply_game_options(config, &mut summary);
+                let game = bson::to_bson(&summary)
+                    .map_err(|err| format!("presentation: to_bson(): Error serializing game_id {game_id}: {err}"))?;
+                games.insert(format!("game_{game_id}"), game);
+            },
+
+            Err(summary_err) => {
+                let msg = format!("presentation: to_bson(): Error in `games_summary_stream` while processing game_id {game_id}: {summary_err}");
+                if config.log_errors {
+                    warn!("{msg}");
+                }
+                if config.stop_on_errors {
+                    return Err(Box::from(msg))
+                }
+            }
+        }
+    }
+
+    games.to_writer(&mut writer)
+        .map_err(|err| format!("presentation: to_bson(): Error writing the BSON document to the given `writer`: {err}"))?;
+    Ok(())
+}
+
+
+/// Unit tests the [bson](super) exporter
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use model::report::{GameMatchSummary, IndexMap, IndexSet};
+    use std::{
+        collections::BTreeMap,
+        io::Cursor,
+    };
+    use futures::stream;
+
+    /// Serializes a summary, decoding it back to check the kill counts, the field names mapping & that the chat and source
+    /// are left out -- as [Config::emit_chat] & [Config::emit_source] are off by default
+    #[test]
+    fn round_trip() {
+        let summaries = vec![
+            GameMatchSummary {
+                total_kills: 45,
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
+                    ("Dono da bola".to_owned(), 5),
+                    ("Isgalamido".to_owned(), 18),
+                    ("Zeh".to_owned(), 20),
+                ]),
+                total_participants: 3,
+                means_of_death: Some(BTreeMap::from([
+                    ("MOD_SHOTGUN".to_owned(), 45),
+                ])),
+                chat: Some(vec![(7, "Zeh".to_owned(), "gg Isgalamido".to_owned())]),
+                source: Some("games.log".to_owned()),
+                ..Default::default()
+            }
+        ];
+        let mut buffer = Cursor::new(Vec::new());
+        to_bson(&Config::default(), Box::pin(stream::iter(summaries.into_iter().map(Ok))), &mut buffer)
+            .expect("Failure in generating the BSON");
+        buffer.set_position(0);
+        let document = Document::from_reader(&mut buffer).expect("The generated BSON couldn't be decoded");
+        let game = document.get_document("game_1").expect("`game_1` is missing");
+        let kills = game.get_document("kills").expect("`kills` is missing");
+        assert_eq!(kills.get_i32("Zeh"), Ok(20), "Wrong kill count");
+        let kills_by_means = game.get_document("kills_by_means").expect("`means_of_death` wasn't renamed to `kills_by_means`");
+        assert_eq!(kills_by_means.get_i32("MOD_SHOTGUN"), Ok(45), "Wrong means of death count");
+        assert_eq!(game.get("chat"), Some(&bson::Bson::Null), "The chat shouldn't have been emitted");
+        assert_eq!(game.get("source"), Some(&bson::Bson::Null), "The source shouldn't have been emitted");
+    }
+
+}
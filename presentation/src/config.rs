@@ -1,5 +1,8 @@
 //! Resting place for Presentation's [Config] & friends
 
+use bll_api::EventAnalyserOperations;
+use std::collections::HashSet;
+
 /// Configuration for the Presentation crate
 pub struct Config {
 
@@ -9,6 +12,77 @@ pub struct Config {
     /// If true, logs any any errors found on the generation of the report
     pub log_errors: bool,
 
+    /// If set, players having less frags than this (possibly negative) threshold are omitted from the `players` & `kills`
+    /// of each game -- `total_kills` is kept whole, though
+    pub min_player_frags: Option<i32>,
+
+    /// If true, the player names are replaced by stable pseudonyms -- `player_<16 hex digits of the SHA-256>` -- for privacy-sensitive sharing:
+    /// a name gets the same pseudonym in all games of the output. `<world>` is kept as is, and so are the chat messages' contents
+    pub anonymize_names: bool,
+
+    /// If true, the games are wrapped in an API response envelope:
+    /// `{"games": {...}, "game_count": N, "error_count": E, "errors": {"game_N": "<error message>", ...}, "status": "ok"}`
+    /// -- the counts, `errors` & `status` come last, so they may be known without buffering the games.
+    /// `status` is `"partial"` if any of the games failed (& were skipped)
+    pub envelope: bool,
+
+    /// If true, the chat transcript of each game is emitted -- when available
+    pub emit_chat: bool,
+
+    /// If true, the source each game was read from (the log file path, for instance) is emitted -- when available
+    pub emit_source: bool,
+
+    /// If true, the means of death are also emitted grouped by their [model::means_of_death::MeansOfDeathKind],
+    /// as `kills_by_means_kind` -- when available
+    pub emit_means_of_death_kinds: bool,
+
+    /// If set, `kills_by_means` is limited to the N most common means of death -- ties broken by name --
+    /// the kills by the remaining ones being summed up into `"other"`
+    pub top_means_n: Option<usize>,
+
+    /// If true, `net_frag_sum` -- the sum of all `kills` values, before [Config::min_player_frags] omits any player -- is emitted next to `total_kills`. They differ because `<world>`
+    /// kills count in `total_kills` while taking a frag from the victim: if all players stay until the end of the game,
+    /// `total_kills - net_frag_sum` is twice the number of such kills
+    pub emit_frag_sum: bool,
+
+    /// If true, each player's share of the frags is emitted, as `kill_share`: the percentage (with 2 decimals) of their frags over
+    /// the sum of all positive frags in `kills` -- so players with zero or negative frags get a share of 0 and the shares add up to 100
+    pub include_kill_share: bool,
+
+    /// If set, a `ranking` of the players -- best first -- is emitted for each game, by the given metric
+    pub rank_by: Option<RankMetric>,
+
+    /// If true, content-identical games (as in logs concatenated several times) are collapsed into their first occurrence,
+    /// written as soon as it comes in -- only a SHA-256 of each unique game is kept. As duplicates may come at any point of
+    /// the stream, their counts go into an `occurrences` trailer: `"occurrences": {"game_1": 3, "game_2": 1}`, as the
+    /// last key of the games object -- or of the envelope, if [Config::envelope] is set
+    pub dedup_identical_games: bool,
+
+    /// The order the games are written in -- reassigning the `game_N` keys accordingly.
+    /// Any order other than [GameOrder::Chronological] requires all games to be buffered before the first one is written
+    pub game_order: GameOrder,
+
+    /// Which fields are emitted for each game, and in what order -- [SummaryField::DEFAULT_ORDER] by default.
+    /// Optional fields are still only emitted if available & enabled -- unless [Self::emit_null_optionals] is set
+    pub field_order: Vec<SummaryField>,
+
+    /// If true, the optional fields not available -- or not enabled -- are emitted as `null` instead of being omitted,
+    /// so every game has the same keys: the ones in [Self::field_order], which may be trimmed to leave the unwanted ones out
+    pub emit_null_optionals: bool,
+
+    /// If set, a warning is logged -- under [Self::log_errors] -- for each game whose JSON object is larger than this many bytes:
+    /// an outlier worth looking at, such as a game with thousands of means of death
+    pub warn_game_size_bytes: Option<usize>,
+
+    /// If true, the JSON of each game is serialized on the `rayon` thread pool, in batches -- then written in order,
+    /// so the output is the same as the serial one. Requires the `parallel` feature: ignored without it
+    pub parallel_serialize: bool,
+
+    /// The logic operations the summaries were produced with -- the same as `bll_api::Config::processor_pipeline`.
+    /// As Parquet requires the schema up front, `to_parquet()` derives its nullable columns from them
+    /// -- see `ParquetColumn` for which operation fills in each column
+    pub processor_pipeline: HashSet<EventAnalyserOperations>,
+
 }
 
 impl Default for Config {
@@ -16,6 +90,174 @@ impl Default for Config {
         Self {
             stop_on_errors: false,
             log_errors: true,
+            min_player_frags: None,
+            anonymize_names: false,
+            envelope: false,
+            emit_chat: false,
+            emit_source: false,
+            emit_means_of_death_kinds: false,
+            top_means_n: None,
+            emit_frag_sum: false,
+            include_kill_share: false,
+            rank_by: None,
+            dedup_identical_games: false,
+            game_order: GameOrder::Chronological,
+            field_order: SummaryField::DEFAULT_ORDER.to_vec(),
+            emit_null_optionals: false,
+            warn_game_size_bytes: None,
+            parallel_serialize: false,
+            processor_pipeline: HashSet::from([
+                EventAnalyserOperations::Kills
+            ]),
+        }
+    }
+}
+
+/// The optional columns of the Parquet output -- derived from [Config::processor_pipeline]
+#[cfg(feature = "parquet")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParquetColumn {
+    /// `reported_score`, filled in by the `GameReportedScores` logic operation
+    ReportedScore,
+    /// `deaths`, filled in by the `KillLog` logic operation
+    Deaths,
+    /// `map_name`, available for any logic operations -- as long as the log has it
+    MapName,
+    /// `item_events`, filled in by the `ItemActivity` logic operation
+    ItemEvents,
+}
+
+#[cfg(feature = "parquet")]
+impl ParquetColumn {
+
+    /// All columns, in the order they are written
+    pub const ALL: [ParquetColumn; 4] = [
+        ParquetColumn::ReportedScore,
+        ParquetColumn::Deaths,
+        ParquetColumn::MapName,
+        ParquetColumn::ItemEvents,
+    ];
+
+    /// The columns filled in by the logic operations in `processor_pipeline` -- in the order they are written
+    pub fn for_pipeline(processor_pipeline: &HashSet<EventAnalyserOperations>) -> Vec<ParquetColumn> {
+        Self::ALL.into_iter()
+            .filter(|parquet_column| match parquet_column {
+                ParquetColumn::ReportedScore => processor_pipeline.contains(&EventAnalyserOperations::GameReportedScores),
+                ParquetColumn::Deaths        => processor_pipeline.contains(&EventAnalyserOperations::KillLog),
+                ParquetColumn::MapName       => true,
+                ParquetColumn::ItemEvents    => processor_pipeline.contains(&EventAnalyserOperations::ItemActivity),
+            })
+            .collect()
+    }
+
+}
+
+/// The metrics the players may be ranked by -- see [Config::rank_by]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RankMetric {
+    /// By the frags in `kills`
+    Frags,
+    /// By the frags in `kills` divided by the deaths -- zero deaths counting as one, so they don't yield infinity.\
+    /// The deaths are taken from the `raw_kills` (including the ones by `<world>`), so the ranking is only emitted if they are available
+    KillDeathRatio,
+}
+
+/// The orders the games may be written in -- see [Config::game_order]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameOrder {
+    /// As they come in the stream -- the order they were played, for logs
+    Chronological,
+    /// By `total_kills`, the bloodiest games first -- ties are kept in chronological order & errored games come last
+    ByKillsDesc,
+}
+
+/// The fields of each game in the JSON output -- see [Config::field_order]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SummaryField {
+    TotalKills,
+    NetFragSum,
+    Players,
+    Kills,
+    KillShare,
+    MapName,
+    KillsByMeans,
+    KillsByMeansKind,
+    GameReportedScores,
+    DisconnectedPlayers,
+    Sessions,
+    AvgFragGapMinutes,
+    BusiestMinute,
+    LongestDroughtMinutes,
+    KillDistribution,
+    RawKills,
+    HeadToHead,
+    Ranking,
+    Flawless,
+    ItemEvents,
+    Chat,
+    Source,
+    SourceHash,
+    SeriesId,
+}
+
+impl SummaryField {
+
+    /// All fields, in the order they were always emitted
+    pub const DEFAULT_ORDER: [SummaryField; 24] = [
+        SummaryField::TotalKills,
+        SummaryField::NetFragSum,
+        SummaryField::Players,
+        SummaryField::Kills,
+        SummaryField::KillShare,
+        SummaryField::MapName,
+        SummaryField::KillsByMeans,
+        SummaryField::KillsByMeansKind,
+        SummaryField::GameReportedScores,
+        SummaryField::DisconnectedPlayers,
+        SummaryField::Sessions,
+        SummaryField::AvgFragGapMinutes,
+        SummaryField::BusiestMinute,
+        SummaryField::LongestDroughtMinutes,
+        SummaryField::KillDistribution,
+        SummaryField::RawKills,
+        SummaryField::HeadToHead,
+        SummaryField::Ranking,
+        SummaryField::Flawless,
+        SummaryField::ItemEvents,
+        SummaryField::Chat,
+        SummaryField::Source,
+        SummaryField::SourceHash,
+        SummaryField::SeriesId,
+    ];
+
+    /// The JSON key of this field
+    pub fn key(&self) -> &'static str {
+        match self {
+            SummaryField::TotalKills            => "total_kills",
+            SummaryField::NetFragSum            => "net_frag_sum",
+            SummaryField::Players               => "players",
+            SummaryField::Kills                 => "kills",
+            SummaryField::KillShare             => "kill_share",
+            SummaryField::MapName               => "map_name",
+            SummaryField::KillsByMeans          => "kills_by_means",
+            SummaryField::KillsByMeansKind      => "kills_by_means_kind",
+            SummaryField::GameReportedScores    => "game_reported_scores",
+            SummaryField::DisconnectedPlayers   => "disconnected_players",
+            SummaryField::Sessions              => "sessions",
+            SummaryField::AvgFragGapMinutes     => "avg_frag_gap_minutes",
+            SummaryField::BusiestMinute         => "busiest_minute",
+            SummaryField::LongestDroughtMinutes => "longest_drought_minutes",
+            SummaryField::KillDistribution      => "kill_distribution",
+            SummaryField::RawKills              => "raw_kills",
+            SummaryField::HeadToHead            => "head_to_head",
+            SummaryField::Ranking               => "ranking",
+            SummaryField::Flawless              => "flawless",
+            SummaryField::ItemEvents            => "item_events",
+            SummaryField::Chat                  => "chat",
+            SummaryField::Source                => "source",
+            SummaryField::SourceHash            => "source_hash",
+            SummaryField::SeriesId              => "series_id",
         }
     }
-}
\ No newline at end of file
+
+}
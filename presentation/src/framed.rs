@@ -0,0 +1,91 @@
+//! Writes the summaries as length-prefixed frames -- for binary WebSockets or custom socket protocols
+
+use crate::{Config, prepare_game, serialize_game};
+use model::report::GamesSummary;
+use std::fmt::Write as _;
+use std::io::Write;
+use log::warn;
+
+
+/// Writes each game into `writer` as soon as it is summarized, as a frame: a big-endian `u32` with the number of bytes
+/// of the UTF-8 payload that follows -- the game's JSON object, keyed by its `game_N`, just like in [crate::to_json()]:
+/// `{"game_N": {...}}`.\
+/// Each payload may be parsed on its own, so live consumers don't need to parse a JSON stream.
+pub fn to_framed(config: &Config, games_summary_stream: GamesSummary, mut writer: impl Write) -> Result<(), Box<dyn std::error::Error>> {
+
+    let games_summary_stream = futures::executor::block_on_stream(games_summary_stream);
+    // kept across games, so its allocation is reused
+    let mut payload = String::new();
+    for (game_id, summary_result) in (1..).zip(games_summary_stream) {
+        match summary_result {
+            Ok(summary) => {
+                payload.clear();
+                let _ = write!(payload, "{{\"game_{game_id}\": ");
+                serialize_game(&mut payload, prepare_game(config, summary), config);
+                payload.push('}');
+                let length = u32::try_from(payload.len())
+                    .map_err(|_| format!("presentation: to_framed(): game_id {game_id} is too large for a frame: {} bytes", payload.len()))?;
+                writer.write_all(&length.to_be_bytes())
+                    .and_then(|_| writer.write_all(payload.as_bytes()))
+                    .map_err(|err| format!("presentation: to_framed(): Error writing the frame of game_id {game_id} to the given `writer`: {err}"))?;
+            },
+
+            Err(summary_err) => {
+                let msg = format!("presentation: to_framed(): Error in `games_summary_stream` while processing game_id {game_id}: {summary_err}");
+                if config.log_errors {
+                    warn!("{msg}");
+                }
+                if config.stop_on_errors {
+                    return Err(Box::from(msg))
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+
+/// Unit tests the [framed](super) output
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use model::report::{GameMatchSummary, IndexMap, IndexSet};
+    use futures::stream;
+
+
+    /// Writes two games -- with an error between them -- reading the frames back & parsing each payload on its own
+    #[test]
+    fn frames_round_trip() {
+        let summary = |kills: &[(&str, i32)]| GameMatchSummary {
+            total_kills: kills.iter().map(|(_player, frags)| *frags as u32).sum(),
+            players: kills.iter().map(|(player, _frags)| player.to_string()).collect::<IndexSet<_>>(),
+            kills: kills.iter().map(|(player, frags)| (player.to_string(), *frags)).collect::<IndexMap<_, _>>(),
+            total_participants: kills.len() as u32,
+            map_name: Some("q3dm17".to_owned()),
+            ..Default::default()
+        };
+        let summaries: Vec<Result<GameMatchSummary, Box<dyn std::error::Error>>> = vec![
+            Ok(summary(&[("Zeh", 3), ("Isgalamido", 1)])),
+            Err(Box::from("feed error")),
+            Ok(summary(&[("Dono da bola", 2)])),
+        ];
+        let mut output = Vec::new();
+        to_framed(&Config { log_errors: false, ..Config::default() }, Box::pin(stream::iter(summaries)), &mut output)
+            .expect("Failure in generating the frames");
+
+        let mut frames = Vec::new();
+        let mut remaining = output.as_slice();
+        while !remaining.is_empty() {
+            let (length_prefix, rest) = remaining.split_at(4);
+            let length = u32::from_be_bytes(length_prefix.try_into().unwrap()) as usize;
+            let (payload, rest) = rest.split_at(length);
+            let game: serde_json::Value = serde_json::from_slice(payload).expect("Each payload should be valid JSON on its own");
+            frames.push(game);
+            remaining = rest;
+        }
+        assert_eq!(frames.len(), 2, "Each game -- and only them -- should have been framed");
+        assert_eq!(frames[0]["game_1"]["kills"], serde_json::json!({"Zeh": 3, "Isgalamido": 1}), "Wrong first frame");
+        assert_eq!(frames[1]["game_3"]["total_kills"], 2, "The second frame should keep the game id of the game after the error");
+    }
+
+}
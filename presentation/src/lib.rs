@@ -1,60 +1,357 @@
 #![doc = include_str!("../README.md")]
 
 mod config;
-pub use config::Config;
+pub use config::{Config, GameOrder, RankMetric, SummaryField};
 
-use model::report::GamesSummary;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+#[cfg(feature = "sqlite")]
+pub use sqlite::to_sqlite;
+
+#[cfg(feature = "bson")]
+mod bson;
+#[cfg(feature = "bson")]
+pub use self::bson::to_bson;
+
+mod framed;
+pub use framed::to_framed;
+
+#[cfg(feature = "parquet")]
+mod parquet;
+#[cfg(feature = "parquet")]
+pub use self::parquet::to_parquet;
+#[cfg(feature = "parquet")]
+pub use config::ParquetColumn;
+
+use model::{
+    means_of_death::MeansOfDeathKind,
+    report::{GameMatchSummary, GamesSummary, GlobalStats, IndexMap, IndexSet, KillDistribution},
+};
 use std::{
-    collections::{BTreeMap, BTreeSet},
-    fmt::Display,
+    borrow::Cow,
+    cmp::Reverse,
+    collections::BTreeMap,
+    fmt::{Display, Write as _},
     io::Write,
+    ops::Range,
 };
 use log::warn;
+use sha2::{Digest, Sha256};
 
 /// IMPLEMENTATION NOTE: here we use our hand-crafter json instead of the one provided by the serde-json crate so we can better control the formatting of the output
 ///                      to match the exact specification + gain a bit of performance
-pub fn to_json(config: &Config, games_summary_stream: GamesSummary, mut writer: impl Write) -> Result<(), Box<dyn std::error::Error>> {
+pub fn to_json(config: &Config, games_summary_stream: GamesSummary, writer: impl Write) -> Result<(), Box<dyn std::error::Error>> {
+    to_json_with_offsets(config, games_summary_stream, writer)
+        .map(|_games_offsets| ())
+}
+
+/// Pairs of `(N, byte range)` locating the JSON object of each `game_N` in the output -- see [to_json_with_offsets()]
+pub type GamesOffsets = Vec<(u32, Range<usize>)>;
+
+/// Same as [to_json()], but also returns the [GamesOffsets] of the games written
+/// -- a sidecar index for consumers wanting to access the games incrementally (through mmap, for instance)
+pub fn to_json_with_offsets(config: &Config, games_summary_stream: GamesSummary, mut writer: impl Write) -> Result<GamesOffsets, Box<dyn std::error::Error>> {
 
-    let mut write = |text: &str|
-        writer.write(text.as_bytes())
-            .map_err(|err| format!("presentation: to_json(): Error writing `GameMatchSummary` to the given `writer`: {err}"));
+    // writes `text`, returning the total number of bytes written so far
+    let mut bytes_written = 0;
+    let mut write = |text: &str| writer.write_all(text.as_bytes())
+        .map(|_| {
+            bytes_written += text.len();
+            bytes_written
+        })
+        .map_err(|err| format!("presentation: to_json(): Error writing `GameMatchSummary` to the given `writer`: {err}"));
 
+    let mut games_offsets = Vec::new();
+    // the failed games -- skipped, as [Config::stop_on_errors] is off -- reported in the envelope: `[(game_id, error message)]`
+    let mut failed_games = Vec::new();
+    // the unique games, when deduplicating: `{sha256(json): (game_id, occurrences)}`, in order of first appearance
+    let mut unique_games = IndexMap::<[u8; 32], (u32, u32)>::new();
     let mut game_id = 1;
     let games_summary_stream = futures::executor::block_on_stream(games_summary_stream);
-    write("{\n")?;
-    for summary_result in games_summary_stream {
-        match summary_result {
-            Ok(summary) => {
-                if game_id > 1 {
-                    write(",\n")?;
-                }
-                write(&format!("  \"game_{game_id}\": {{\n"))?;
-                write(&format!("    \"total_kills\": {},\n", summary.total_kills))?;
-                write(&format!("    \"players\": {},\n", serialize_set(&summary.players)))?;
-                write(&format!("    \"kills\": {}", serialize_map("    ", &summary.kills)))?;
-
-                // extended/optional field: means_of_death
-                if let Some(means_of_death) = summary.means_of_death {
-                    write(",\n")?;
-                    write(&format!("    \"kills_by_means\": {}", serialize_map("    ", &means_of_death)))?;
+    let mut games_summary_stream: Box<dyn Iterator<Item=_>> = match config.game_order {
+        GameOrder::Chronological => Box::new(games_summary_stream),
+        GameOrder::ByKillsDesc => {
+            let mut summary_results: Vec<_> = games_summary_stream.collect();
+            // the sort is stable, keeping ties in chronological order -- errors, without `total_kills`, go last
+            summary_results.sort_by_key(|summary_result| Reverse(summary_result.as_ref().ok().map(|summary| summary.total_kills)));
+            Box::new(summary_results.into_iter())
+        },
+    };
+    if config.envelope {
+        write("{\"games\": ")?;
+    }
+    write("{")?;
+    // games are serialized in batches: of a single game (so the output flows as the games come in) or, if serializing
+    // in parallel, of enough games to keep the thread pool busy -- either way, they are written in order
+    let batch_size = if parallel_serialize(config) { PARALLEL_SERIALIZATION_BATCH_SIZE } else { 1 };
+    // the JSON of each game in the batch -- see [serialize_games()]
+    let mut games_json = Vec::with_capacity(batch_size);
+    loop {
+        let mut games = Vec::with_capacity(batch_size);
+        let mut errors = Vec::with_capacity(batch_size);
+        for summary_result in games_summary_stream.by_ref().take(batch_size) {
+            match summary_result {
+                Ok(summary) => {
+                    games.push(Some(prepare_game(config, summary)));
+                    errors.push(None);
+                },
+                Err(summary_err) => {
+                    games.push(None);
+                    errors.push(Some(summary_err));
+                },
+            }
+        }
+        if games.is_empty() {
+            break
+        }
+        serialize_games(config, games, &mut games_json);
+        for (game_json, summary_err) in games_json.iter().zip(errors) {
+            if let Some(summary_err) = summary_err {
+                let msg = format!("presentation: to_json(): Error in `games_summary_stream` while processing game_id {game_id}: {summary_err}");
+                if config.log_errors {
+                    warn!("{msg}");
                 }
-                // extended/optional field: game_reported_scores
-                if let Some(game_reported_scores) = summary.game_reported_scores {
-                    write(",\n")?;
-                    write(&format!("    \"game_reported_scores\": {}", serialize_map("    ", &game_reported_scores)))?;
+                if config.stop_on_errors {
+                    return Err(Box::from(msg))
                 }
+                failed_games.push((game_id, summary_err.to_string()));
+            } else if !config.dedup_identical_games || is_first_occurrence(&mut unique_games, game_json, game_id) {
+                write(if games_offsets.is_empty() { "\n" } else { ",\n" })?;
+                let game_start = write(&format!("  \"game_{game_id}\": "))?;
+                let game_end = write(game_json)?;
+                warn_if_oversized(config, game_id, game_end - game_start);
+                games_offsets.push((game_id, game_start..game_end));
+            }
+            game_id += 1;
+        }
+    }
+    // the `occurrences` trailer: the last key of the games object -- or of the envelope, if there is one
+    let occurrences = config.dedup_identical_games.then(|| {
+        let counts = unique_games.values()
+            .map(|(game_id, occurrences)| format!("\"game_{game_id}\": {occurrences}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("\"occurrences\": {{{counts}}}")
+    });
+    if let (Some(occurrences), false) = (&occurrences, config.envelope) {
+        write(&format!("{}  {occurrences}", if games_offsets.is_empty() { "\n" } else { ",\n" }))?;
+        write("\n}")?;
+    } else {
+        // no games yield a clean `{}`
+        write(if games_offsets.is_empty() { "}" } else { "\n}" })?;
+    }
+    if config.envelope {
+        if let Some(occurrences) = &occurrences {
+            write(&format!(", {occurrences}"))?;
+        }
+        let errors = failed_games.iter()
+            .map(|(game_id, msg)| format!("\"game_{game_id}\": \"{}\"", escape_json(msg)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let status = if failed_games.is_empty() { "ok" } else { "partial" };
+        write(&format!(", \"game_count\": {}, \"error_count\": {}, \"errors\": {{{errors}}}, \"status\": \"{status}\"}}",
+                       games_offsets.len(), failed_games.len()))?;
+    }
+    Ok(games_offsets)
+}
+
+/// Registers `game_json` -- from `game_id` -- in `unique_games` (see [Config::dedup_identical_games]), telling if it is the
+/// first occurrence of its content: to be written right away. Repetitions only bump the first occurrence's count
+fn is_first_occurrence(unique_games: &mut IndexMap<[u8; 32], (u32, u32)>, game_json: &str, game_id: u32) -> bool {
+    let (_game_id, occurrences) = unique_games.entry(Sha256::digest(game_json).into()).or_insert((game_id, 0));
+    *occurrences += 1;
+    *occurrences == 1
+}
+
+/// A game ready for [serialize_game()]: its `summary`, as given out by [apply_game_options()], along with what had to be
+/// computed before the options trimmed it
+pub(crate) struct PreparedGame {
+    summary: GameMatchSummary,
+    /// The sum of the `kills` of all players -- the ones omitted by [Config::min_player_frags] included
+    net_frag_sum: i32,
+}
+
+/// Prepares `summary` for [serialize_game()] -- see [PreparedGame]
+pub(crate) fn prepare_game(config: &Config, mut summary: GameMatchSummary) -> PreparedGame {
+    let net_frag_sum = summary.kills.values().sum();
+    apply_game_options(config, &mut summary);
+    PreparedGame { summary, net_frag_sum }
+}
+
+/// Applies, to `summary`, the [Config] options trimming or transforming the JSON of each game:
+/// [Config::min_player_frags], [Config::anonymize_names], [Config::emit_chat] & [Config::emit_source]
+fn apply_game_options(config: &Config, summary: &mut GameMatchSummary) {
+    if let Some(min_player_frags) = config.min_player_frags {
+        omit_players_below(summary, min_player_frags);
+    }
+    if config.anonymize_names {
+        anonymize_names(summary);
+    }
+    if !config.emit_chat {
+        summary.chat = None;
+    }
+    if !config.emit_source {
+        summary.source = None;
+    }
+}
+
+/// Logs a warning -- under [Config::log_errors] -- if the JSON object of `game_N`, having `game_size` bytes, exceeds [Config::warn_game_size_bytes]
+fn warn_if_oversized(config: &Config, game_id: u32, game_size: usize) {
+    if let Some(warn_game_size_bytes) = config.warn_game_size_bytes {
+        if config.log_errors && game_size > warn_game_size_bytes {
+            warn!("presentation: to_json(): game_{game_id} has {game_size} bytes of JSON, more than the {warn_game_size_bytes} bytes of `warn_game_size_bytes`");
+        }
+    }
+}
+
+/// The number of games serialized at once when [Config::parallel_serialize] is set
+const PARALLEL_SERIALIZATION_BATCH_SIZE: usize = 64;
+
+/// Returns whether [Config::parallel_serialize] is set -- `false` if the `parallel` feature is not enabled
+fn parallel_serialize(config: &Config) -> bool {
+    cfg!(feature = "parallel") && config.parallel_serialize
+}
+
+/// Applies [serialize_game()] to each of the `games`, into the respective `games_json` buffer (which is resized to fit
+/// the `games`) -- on the `rayon` thread pool, if [Config::parallel_serialize] is set. `None`s (standing for errored games)
+/// leave their buffers empty.\
+/// The caller keeps `games_json` across batches, so their allocations are reused
+fn serialize_games(config: &Config, games: Vec<Option<PreparedGame>>, games_json: &mut Vec<String>) {
+    games_json.truncate(games.len());
+    games_json.resize_with(games.len(), String::new);
+    let serialize = |(game, json): (Option<PreparedGame>, &mut String)| {
+        json.clear();
+        if let Some(game) = game {
+            serialize_game(json, game, config);
+        }
+    };
+    #[cfg(feature = "parallel")]
+    if parallel_serialize(config) {
+        use rayon::prelude::*;
+        games.into_par_iter()
+            .zip(games_json.par_iter_mut())
+            .for_each(serialize);
+        return
+    }
+    games.into_iter()
+        .zip(games_json.iter_mut())
+        .for_each(serialize);
+}
+
+/// Serializes the JSON object for a single game -- the value of its `game_N` key in [to_json()] -- having the fields
+/// in [Config::field_order] -- the missing ones either omitted or `null`, according to [Config::emit_null_optionals].\
+/// All fields are appended straight into `json` -- a buffer the callers keep (& clear) between games, so no allocations
+/// happen once it has grown to fit them
+fn serialize_game(json: &mut String, game: PreparedGame, config: &Config) {
+    json.push_str("{\n");
+    let mut first_field = true;
+    for field in &config.field_order {
+        let field_start = json.len();
+        if !first_field {
+            json.push_str(",\n");
+        }
+        let _ = write!(json, "    \"{}\": ", field.key());
+        if write_field(json, *field, &game, config).is_none() {
+            if !config.emit_null_optionals {
+                json.truncate(field_start);
+                continue
+            }
+            json.push_str("null");
+        }
+        first_field = false;
+    }
+    json.push_str("\n  }");
+}
 
-                // extended/optional field: disconnected_players
-                if let Some(disconnected_players) = summary.disconnected_players {
-                    write(",\n")?;
-                    write(&format!("    \"disconnected_players\": {}", serialize_vec("    ", &disconnected_players)))?;
+/// Writes, into `out`, the value of `field` out of `summary` -- `None` (with nothing written) if it is not available or not enabled in `config`
+fn write_field(out: &mut String, field: SummaryField, game: &PreparedGame, config: &Config) -> Option<()> {
+    let summary = &game.summary;
+    match field {
+        SummaryField::TotalKills => {
+            write_display(out, summary.total_kills);
+            Some(())
+        },
+        SummaryField::NetFragSum => config.emit_frag_sum
+            .then(|| write_display(out, game.net_frag_sum)),
+        SummaryField::Players => {
+            write_set(out, &summary.players);
+            Some(())
+        },
+        SummaryField::Kills => {
+            write_map(out, "    ", &summary.kills);
+            Some(())
+        },
+        SummaryField::KillShare => config.include_kill_share
+            .then(|| write_map(out, "    ", &kill_share(summary))),
+        SummaryField::MapName => summary.map_name.as_ref()
+            .map(|map_name| write_string(out, map_name)),
+        SummaryField::KillsByMeans => summary.means_of_death.as_ref()
+            .map(|means_of_death| match config.top_means_n {
+                Some(top_means_n) => write_map(out, "    ", &top_means_of_death(means_of_death, top_means_n)),
+                None => write_map(out, "    ", means_of_death),
+            }),
+        SummaryField::KillsByMeansKind => summary.means_of_death.as_ref()
+            .filter(|_| config.emit_means_of_death_kinds)
+            .map(|means_of_death| {
+                let mut means_of_death_kinds = BTreeMap::<String, i32>::new();
+                for (reason_name, casualties) in means_of_death {
+                    *means_of_death_kinds.entry(MeansOfDeathKind::of(reason_name).to_string()).or_default() += casualties;
                 }
+                write_map(out, "    ", &means_of_death_kinds)
+            }),
+        SummaryField::GameReportedScores => summary.game_reported_scores.as_ref()
+            .map(|game_reported_scores| write_map(out, "    ", game_reported_scores)),
+        SummaryField::DisconnectedPlayers => summary.disconnected_players.as_ref()
+            .map(|disconnected_players| write_vec(out, "    ", disconnected_players)),
+        SummaryField::Sessions => summary.sessions.as_ref()
+            .map(|sessions| write_sessions(out, "    ", sessions)),
+        SummaryField::AvgFragGapMinutes => summary.avg_frag_gap_minutes.as_ref()
+            .map(|avg_frag_gap_minutes| write_map(out, "    ", avg_frag_gap_minutes)),
+        SummaryField::BusiestMinute => summary.busiest_minute
+            .map(|(minute, kills)| { let _ = write!(out, "{{\"minute\": {minute}, \"kills\": {kills}}}"); }),
+        SummaryField::LongestDroughtMinutes => summary.longest_drought_minutes
+            .map(|longest_drought_minutes| write_display(out, longest_drought_minutes)),
+        SummaryField::KillDistribution => summary.kill_distribution.as_ref()
+            .map(|KillDistribution { mean, median, stddev }| { let _ = write!(out, "{{\"mean\": {mean}, \"median\": {median}, \"stddev\": {stddev}}}"); }),
+        SummaryField::RawKills => summary.raw_kills.as_ref()
+            .map(|raw_kills| write_raw_kills(out, "    ", raw_kills)),
+        SummaryField::HeadToHead => summary.head_to_head.as_ref()
+            .map(|head_to_head| write_entries(out, "    ", ('{', '}'), head_to_head, |out, (player, opponents)| {
+                let _ = write!(out, "\"{}\": ", escape_json(player));
+                write_map(out, "      ", opponents);
+            })),
+        SummaryField::Ranking => config.rank_by
+            .and_then(|rank_by| ranking(summary, rank_by))
+            .map(|ranking| write_set(out, &ranking)),
+        SummaryField::Flawless => summary.flawless
+            .map(|flawless| write_display(out, flawless)),
+        SummaryField::ItemEvents => summary.item_events
+            .map(|item_events| write_display(out, item_events)),
+        SummaryField::Chat => summary.chat.as_ref()
+            .map(|chat| write_chat(out, "    ", chat)),
+        SummaryField::Source => summary.source.as_ref()
+            .map(|source| write_string(out, source)),
+        SummaryField::SourceHash => summary.source_hash.as_ref()
+            .map(|source_hash| write_string(out, source_hash)),
+        SummaryField::SeriesId => summary.series_id
+            .map(|series_id| write_display(out, series_id)),
+    }
+}
 
-                write(&format!("\n  }}"))?;
-            },
+/// Outputs only the aggregate stats across all games -- total games, total kills, the overall top player & the overall
+/// means of death distribution (if available) -- as a single flat JSON object, without any per-game detail.\
+/// Only the aggregate counters are kept while the `games_summary_stream` is consumed.
+pub fn to_json_global(config: &Config, games_summary_stream: GamesSummary, mut writer: impl Write) -> Result<(), Box<dyn std::error::Error>> {
+
+    let mut global_stats = GlobalStats::default();
+
+    let games_summary_stream = futures::executor::block_on_stream(games_summary_stream);
+    for (game_id, summary_result) in (1..).zip(games_summary_stream) {
+        match summary_result {
+            Ok(summary) => global_stats.add(&summary),
 
             Err(summary_err) => {
-                let msg = format!("presentation: to_json(): Error in `games_summary_stream` while processing game_id {game_id}: {summary_err}");
+                let msg = format!("presentation: to_json_global(): Error in `games_summary_stream` while processing game_id {game_id}: {summary_err}");
                 if config.log_errors {
                     warn!("{msg}");
                 }
@@ -63,9 +360,26 @@ pub fn to_json(config: &Config, games_summary_stream: GamesSummary, mut writer:
                 }
             }
         }
-        game_id += 1;
     }
-    write("\n}")?;
+
+    let GlobalStats { total_games, total_kills, player_frags, means_of_death, games_by_map } = global_stats;
+    // ties are broken by the player name's order
+    let top_player = player_frags.iter()
+        .max_by(|(name_a, frags_a), (name_b, frags_b)| frags_a.cmp(frags_b).then(name_b.cmp(name_a)))
+        .map_or_else(|| String::from("null"), |(name, _frags)| format!("\"{}\"", escape_json(name)));
+
+    let mut json = format!("{{\n  \"total_games\": {total_games},\n  \"total_kills\": {total_kills},\n  \"top_player\": {top_player}");
+    if let Some(means_of_death) = means_of_death {
+        json.push_str(",\n  \"kills_by_means\": ");
+        write_map(&mut json, "  ", &means_of_death);
+    }
+    if !games_by_map.is_empty() {
+        json.push_str(",\n  \"games_by_map\": ");
+        write_map(&mut json, "  ", &games_by_map);
+    }
+    json.push_str("\n}");
+    writer.write_all(json.as_bytes())
+        .map_err(|err| format!("presentation: to_json_global(): Error writing the global stats to the given `writer`: {err}"))?;
     Ok(())
 }
 
@@ -76,57 +390,225 @@ pub async fn to_json_async(_config: &Config, _games_summary_stream: GamesSummary
     todo!("Placeholder for an async implementation, that would be useful for async applications")
 }
 
-fn serialize_set(set: &BTreeSet<String>) -> String {
-    let mut string = set.iter()
-        .fold(String::from("["), |mut acc, element| {
-            if acc.len() != 1 {
-                acc.push_str(", ");
-            }
-            acc.push('"');
-            acc.push_str(element);
-            acc.push('"');
-            acc
+/// Escapes `text` so it may be embedded in a JSON string -- player names, means of death & error messages
+/// (which may contain backslash-separated Windows paths) are user provided and may contain any characters.\
+/// No allocation happens if there is nothing to escape.
+pub fn escape_json(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(|c| c == '"' || c == '\\' || c < ' ') {
+        return Cow::Borrowed(text)
+    }
+    let mut escaped = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        match c {
+            '"'  => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c < ' ' => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Removes, from the `players` & `kills` of `summary`, the players with less than `min_player_frags`
+/// -- players without a `kills` entry count as having 0 frags
+fn omit_players_below(summary: &mut GameMatchSummary, min_player_frags: i32) {
+    let kills = &summary.kills;
+    summary.players.retain(|player| kills.get(player).copied().unwrap_or(0) >= min_player_frags);
+    summary.kills.retain(|_player, frags| *frags >= min_player_frags);
+}
+
+/// Replaces every player name in `summary` by its [pseudonym()] -- see [Config::anonymize_names]
+fn anonymize_names(summary: &mut GameMatchSummary) {
+    fn rename_keys<Map: FromIterator<(String, V)> + IntoIterator<Item=(String, V)>, V>(map: Map) -> Map {
+        map.into_iter().map(|(name, value)| (pseudonym(&name), value)).collect()
+    }
+    summary.players = std::mem::take(&mut summary.players).iter().map(|name| pseudonym(name)).collect();
+    summary.kills = rename_keys(std::mem::take(&mut summary.kills));
+    summary.game_reported_scores = summary.game_reported_scores.take().map(rename_keys);
+    summary.avg_frag_gap_minutes = summary.avg_frag_gap_minutes.take().map(rename_keys);
+    summary.sessions = summary.sessions.take().map(rename_keys);
+    summary.head_to_head = summary.head_to_head.take()
+        .map(|head_to_head| head_to_head.into_iter()
+            .map(|(name, opponents)| (pseudonym(&name), rename_keys(opponents)))
+            .collect());
+    summary.disconnected_players.iter_mut().flatten()
+        .for_each(|(_id, name, _frags)| *name = pseudonym(name));
+    summary.raw_kills.iter_mut().flatten()
+        .for_each(|(_event_id, killer, victim, _reason)| {
+            *killer = pseudonym(killer);
+            *victim = pseudonym(victim);
         });
-    string.push(']');
-    string
+    // private messages have their speaker in the form `<FROM> to <TO>`
+    summary.chat.iter_mut().flatten()
+        .for_each(|(_event_id, speaker, _message)| *speaker = match speaker.split_once(" to ") {
+            Some((from, to)) => format!("{} to {}", pseudonym(from), pseudonym(to)),
+            None => pseudonym(speaker),
+        });
+}
+
+/// The stable pseudonym of the player `name` -- `player_` followed by the first 16 hex digits (64 bits) of its SHA-256 --
+/// with `<world>` kept as is.\
+/// Being a specified hash, the pseudonyms are the same across runs, builds & platforms -- so outputs may be cross-referenced
+fn pseudonym(name: &str) -> String {
+    if name == "<world>" {
+        return name.to_owned()
+    }
+    let digest = Sha256::digest(name);
+    let hex_digits: String = digest[..8].iter().map(|byte| format!("{byte:02x}")).collect();
+    format!("player_{hex_digits}")
+}
+
+/// Computes the share of each player's frags -- see [Config::include_kill_share]
+fn kill_share(summary: &GameMatchSummary) -> BTreeMap<String, f64> {
+    let positive_frags = |frags: i32| frags.max(0) as f64;
+    let total_positive_frags: f64 = summary.kills.values().copied().map(positive_frags).sum();
+    summary.kills.iter()
+        .map(|(player, frags)| {
+            let share = if total_positive_frags > 0.0 { positive_frags(*frags) * 100.0 / total_positive_frags } else { 0.0 };
+            (player.clone(), (share * 100.0).round() / 100.0)
+        })
+        .collect()
 }
 
-fn serialize_map<T: Display>(pre_ident: &str, map: &BTreeMap<String, T>) -> String {
-    let mut string = map.iter()
-        .fold(String::from("{\n  "), |mut acc, (key, value)| {
-            if acc.len() != 4 {
-                acc.push_str(",\n  ");
+/// Ranks the `players` of `summary` by `rank_by` -- best first, ties broken by the player name's order.\
+/// `None` if the deaths required by [RankMetric::KillDeathRatio] are not available
+fn ranking(summary: &GameMatchSummary, rank_by: RankMetric) -> Option<IndexSet<String>> {
+    let frags = |player: &String| summary.kills.get(player).copied().unwrap_or(0) as f64;
+    let score: Box<dyn Fn(&String) -> f64> = match rank_by {
+        RankMetric::Frags => Box::new(frags),
+        RankMetric::KillDeathRatio => {
+            let mut deaths = BTreeMap::<&str, u32>::new();
+            for (_event_id, _killer, victim, _reason) in summary.raw_kills.as_ref()? {
+                *deaths.entry(victim).or_default() += 1;
             }
-            acc.push_str(pre_ident);
-            acc.push_str(&format!("\"{key}\": {value}"));
-            acc
-        });
-    string.push('\n' );
-    string.push_str(pre_ident);
-    string.push('}');
-    string
+            Box::new(move |player| frags(player) / deaths.get(player.as_str()).copied().unwrap_or(0).max(1) as f64)
+        },
+    };
+    let mut players: Vec<&String> = summary.players.iter().collect();
+    players.sort_by(|player_a, player_b| score(player_b).total_cmp(&score(player_a)).then(player_a.cmp(player_b)));
+    Some(players.into_iter().cloned().collect())
+}
+
+/// Keeps the `n` most common `means_of_death` -- ties broken by name -- summing the kills by the others into `"other"`, if any
+fn top_means_of_death(means_of_death: &BTreeMap<String, i32>, n: usize) -> BTreeMap<String, i32> {
+    let mut by_kills = means_of_death.iter().collect::<Vec<_>>();
+    by_kills.sort_by(|(name_a, kills_a), (name_b, kills_b)| kills_b.cmp(kills_a).then_with(|| name_a.cmp(name_b)));
+    let others = by_kills.split_off(n.min(by_kills.len()));
+    let mut top_means = by_kills.into_iter()
+        .map(|(name, kills)| (name.clone(), *kills))
+        .collect::<BTreeMap<_, _>>();
+    if !others.is_empty() {
+        *top_means.entry(String::from("other")).or_default() += others.into_iter().map(|(_name, kills)| kills).sum::<i32>();
+    }
+    top_means
+}
+
+/// Writes `value` into `out`, as formatted by its [Display] -- writing to a `String` never fails
+fn write_display(out: &mut String, value: impl Display) {
+    let _ = write!(out, "{value}");
+}
+
+/// Writes `text` into `out` as an escaped JSON string
+fn write_string(out: &mut String, text: &str) {
+    out.push('"');
+    out.push_str(&escape_json(text));
+    out.push('"');
+}
+
+/// Writes the elements of `set` into `out` as a single line JSON array of strings
+fn write_set(out: &mut String, set: &IndexSet<String>) {
+    out.push('[');
+    for (i, element) in set.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_string(out, element);
+    }
+    out.push(']');
+}
+
+/// Writes, into `out`, a JSON object or array -- according to the `delimiters` -- having each of the `entries` written
+/// by `write_entry` in its own line, indented by `pre_ident`
+fn write_entries<T>(out: &mut String, pre_ident: &str, delimiters: (char, char), entries: impl IntoIterator<Item=T>, mut write_entry: impl FnMut(&mut String, T)) {
+    let (open, close) = delimiters;
+    out.push(open);
+    out.push_str("\n  ");
+    for (i, entry) in entries.into_iter().enumerate() {
+        if i > 0 {
+            out.push_str(",\n  ");
+        }
+        out.push_str(pre_ident);
+        write_entry(out, entry);
+    }
+    out.push('\n');
+    out.push_str(pre_ident);
+    out.push(close);
 }
 
-fn serialize_vec(pre_ident: &str, vec: &Vec<(u32, String, i32)>) -> String {
-    let mut string = vec.iter()
-        .fold(String::from("[\n  "), |mut acc, (id, name, frags)| {
-            if acc.len() != 4 {
-                acc.push_str(",\n  ");
+/// Writes the entries of `map` into `out` in their iteration order -- sorted for [BTreeMap]s, as stored for [IndexMap]s
+fn write_map<'a, T: Display + 'a>(out: &mut String, pre_ident: &str, map: impl IntoIterator<Item=(&'a String, &'a T)>) {
+    write_entries(out, pre_ident, ('{', '}'), map, |out, (key, value)| { let _ = write!(out, "\"{}\": {value}", escape_json(key)); });
+}
+
+/// Writes the `(id, name, frags)` players of `vec` into `out`
+fn write_vec(out: &mut String, pre_ident: &str, vec: &[(u32, String, i32)]) {
+    write_entries(out, pre_ident, ('[', ']'), vec, |out, (id, name, frags)| {
+        let _ = write!(out, "{{\"id\": {id}, \"name\": \"{}\", \"frags\": {frags}}}", escape_json(name));
+    });
+}
+
+/// Writes each player's `sessions` into `out`, as single line arrays of `[connect_minute, disconnect_minute]` pairs -- `null` for the still connected
+fn write_sessions(out: &mut String, pre_ident: &str, sessions: &BTreeMap<String, Vec<(u32, Option<u32>)>>) {
+    write_entries(out, pre_ident, ('{', '}'), sessions, |out, (player, player_sessions)| {
+        let _ = write!(out, "\"{}\": [", escape_json(player));
+        for (i, (connect_minute, disconnect_minute)) in player_sessions.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
             }
-            acc.push_str(pre_ident);
-            acc.push_str(&format!("{{\"id\": {id}, \"name\": \"{name}\", \"frags\": {frags}}}"));
-            acc
-        });
-    string.push('\n' );
-    string.push_str(pre_ident);
-    string.push(']');
+            match disconnect_minute {
+                Some(disconnect_minute) => { let _ = write!(out, "[{connect_minute}, {disconnect_minute}]"); },
+                None => { let _ = write!(out, "[{connect_minute}, null]"); },
+            }
+        }
+        out.push(']');
+    });
+}
+
+fn write_raw_kills(out: &mut String, pre_ident: &str, vec: &[(u32, String, String, String)]) {
+    write_entries(out, pre_ident, ('[', ']'), vec, |out, (event_id, killer, victim, reason)| {
+        let _ = write!(out, "{{\"event_id\": {event_id}, \"killer\": \"{}\", \"victim\": \"{}\", \"reason\": \"{}\"}}", escape_json(killer), escape_json(victim), escape_json(reason));
+    });
+}
+
+fn write_chat(out: &mut String, pre_ident: &str, vec: &[(u32, String, String)]) {
+    write_entries(out, pre_ident, ('[', ']'), vec, |out, (event_id, speaker, message)| {
+        let _ = write!(out, "{{\"event_id\": {event_id}, \"speaker\": \"{}\", \"message\": \"{}\"}}", escape_json(speaker), escape_json(message));
+    });
+}
+
+/// Same as [write_map()], but returning a new `String`
+#[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
+pub(crate) fn serialize_map<'a, T: Display + 'a>(pre_ident: &str, map: impl IntoIterator<Item=(&'a String, &'a T)>) -> String {
+    let mut string = String::new();
+    write_map(&mut string, pre_ident, map);
+    string
+}
+
+/// Same as [write_vec()], but returning a new `String`
+#[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
+pub(crate) fn serialize_vec(pre_ident: &str, vec: &[(u32, String, i32)]) -> String {
+    let mut string = String::new();
+    write_vec(&mut string, pre_ident, vec);
     string
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use model::report::GameMatchSummary;
+    use model::report::IndexMap;
     use std::io::Cursor;
     use futures::stream;
     use serde_json::Error;
@@ -136,15 +618,14 @@ mod tests {
         let summaries = vec![
             GameMatchSummary {
                 total_kills: 45,
-                players: BTreeSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
-                kills: BTreeMap::from([
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
                     ("Dono da bola".to_owned(), 5),
                     ("Isgalamido".to_owned(), 18),
                     ("Zeh".to_owned(), 20),
                 ]),
-                means_of_death: None,
-                game_reported_scores: None,
-                disconnected_players: None,
+                total_participants: 3,
+                ..Default::default()
             }
         ];
         assert_json(summaries)
@@ -155,12 +636,13 @@ mod tests {
         let summaries = vec![
             GameMatchSummary {
                 total_kills: 45,
-                players: BTreeSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
-                kills: BTreeMap::from([
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
                     ("Dono da bola".to_owned(), 5),
                     ("Isgalamido".to_owned(), 18),
                     ("Zeh".to_owned(), 20),
                 ]),
+                total_participants: 3,
                 means_of_death: Some(BTreeMap::from([
                     ("MOD_BRUTE_FORCE".to_owned(), 3),
                     ("MOD_PUNCH".to_owned(), 8),
@@ -175,6 +657,22 @@ mod tests {
                     (3, "Zeh Maneh".to_owned(), 2),
                     (7, "Alcantara".to_owned(), -3),
                 ]),
+                avg_frag_gap_minutes: Some(BTreeMap::from([
+                    ("Isgalamido".to_owned(), 0.75),
+                    ("Zeh".to_owned(), 2.0),
+                ])),
+                busiest_minute: Some((3, 7)),
+                raw_kills: Some(vec![
+                    (12, "Zeh".to_owned(), "Isgalamido".to_owned(), "MOD_PUNCH".to_owned()),
+                    (13, "<world>".to_owned(), "Zeh".to_owned(), "MOD_FALLING".to_owned()),
+                ]),
+                head_to_head: Some(BTreeMap::from([
+                    ("Isgalamido".to_owned(), BTreeMap::from([("Zeh".to_owned(), -1)])),
+                    ("Zeh".to_owned(), BTreeMap::from([("Isgalamido".to_owned(), 1)])),
+                ])),
+                flawless: Some(false),
+                longest_drought_minutes: Some(4),
+                ..Default::default()
             }
         ];
         assert_json(summaries)
@@ -185,32 +683,771 @@ mod tests {
         let summaries = vec![
             GameMatchSummary {
                 total_kills: 45,
-                players: BTreeSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
-                kills: BTreeMap::from([
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
                     ("Dono da bola".to_owned(), 5),
                     ("Isgalamido".to_owned(), 18),
                     ("Zeh".to_owned(), 20),
                 ]),
-                means_of_death: None,
-                game_reported_scores: None,
-                disconnected_players: None,
+                total_participants: 3,
+                ..Default::default()
             },
             GameMatchSummary {
                 total_kills: 45,
-                players: BTreeSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
-                kills: BTreeMap::from([
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
                     ("Dono da bola".to_owned(), 5),
                     ("Isgalamido".to_owned(), 18),
                     ("Zeh".to_owned(), 20),
                 ]),
-                means_of_death: None,
-                game_reported_scores: None,
-                disconnected_players: None,
+                total_participants: 3,
+                ..Default::default()
             }
         ];
         assert_json(summaries)
     }
 
+    /// Names and error messages with quotes, backslashes (like Windows paths) & control characters must still yield valid JSON
+    #[test]
+    fn escaped_names_and_errors() {
+        let summaries = vec![
+            GameMatchSummary {
+                total_kills: 1,
+                players: IndexSet::from(["C:\\quake3\\\"Zeh\"".to_owned(), "Tab\tbed".to_owned()]),
+                kills: IndexMap::from([
+                    ("C:\\quake3\\\"Zeh\"".to_owned(), 1),
+                    ("Tab\tbed".to_owned(), 0),
+                ]),
+                total_participants: 2,
+                disconnected_players: Some(vec![
+                    (3, "back\\slash".to_owned(), 0),
+                ]),
+                ..Default::default()
+            }
+        ];
+        assert_json(summaries);
+
+        // the errors reach the output through the envelope -- which must stay valid JSON, whatever they carry
+        let log_file_path = r"C:\Users\zeh\quake3\games.log";
+        let error_message = format!("IO read error when processing log file '{log_file_path}' at line 1: \"Interrupted\"");
+        let summaries: Vec<Result<GameMatchSummary, Box<dyn std::error::Error>>> = vec![
+            Err(Box::from(error_message.as_str())),
+            Ok(GameMatchSummary { total_kills: 0, players: IndexSet::new(), kills: IndexMap::new(), total_participants: 0, ..Default::default() }),
+        ];
+        let mut buffer = Cursor::new(Vec::new());
+        to_json(&Config { log_errors: false, envelope: true, ..Config::default() }, Box::pin(stream::iter(summaries)), &mut buffer)
+            .expect("Failure in generating the json");
+        let json_string = String::from_utf8(buffer.into_inner()).unwrap();
+        let json_error = validate_json(&json_string);
+        assert!(json_error.is_none(), "The JSON with the embedded error is not valid: {:?} -- JSON: {json_string}", json_error.unwrap());
+        let parsed: serde_json::Value = serde_json::from_str(&json_string).unwrap();
+        assert_eq!(parsed["errors"]["game_1"].as_str(), Some(error_message.as_str()), "The escaped error didn't round-trip");
+    }
+
+    /// Players & kills must be emitted in the order they are stored in the summary -- not re-sorted
+    #[test]
+    fn players_order() {
+        let summaries = vec![
+            GameMatchSummary {
+                total_kills: 2,
+                players: IndexSet::from(["Zeh".to_owned(), "Isgalamido".to_owned()]),
+                kills: IndexMap::from([
+                    ("Zeh".to_owned(), 1),
+                    ("Isgalamido".to_owned(), 1),
+                ]),
+                total_participants: 2,
+                ..Default::default()
+            }
+        ];
+        let mut buffer = Cursor::new(Vec::new());
+        to_json(&Config::default(), Box::pin(stream::iter(summaries.into_iter().map(Ok))), &mut buffer)
+            .expect("Failure in generating the json");
+        let json_string = String::from_utf8(buffer.into_inner()).unwrap();
+        assert!(json_string.contains(r#""players": ["Zeh", "Isgalamido"]"#), "Players are not in their stored order -- JSON: {json_string}");
+        let zeh_kills = json_string.find(r#""Zeh": 1"#).expect("Zeh's kills are missing");
+        let isgalamido_kills = json_string.find(r#""Isgalamido": 1"#).expect("Isgalamido's kills are missing");
+        assert!(zeh_kills < isgalamido_kills, "Kills are not in their stored order -- JSON: {json_string}");
+    }
+
+    /// Tests that [Config::min_player_frags] omits the players below the threshold, keeping `total_kills` whole
+    #[test]
+    fn min_player_frags() {
+        let summaries = || vec![
+            GameMatchSummary {
+                total_kills: 9,
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
+                    ("Dono da bola".to_owned(), -2),
+                    ("Isgalamido".to_owned(), 0),
+                    ("Zeh".to_owned(), 5),
+                ]),
+                total_participants: 3,
+                ..Default::default()
+            }
+        ];
+        let game_json = |min_player_frags| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { min_player_frags, ..Config::default() }, Box::pin(stream::iter(summaries().into_iter().map(Ok))), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            json["game_1"].clone()
+        };
+
+        let game = game_json(Some(1));
+        assert_eq!(game["total_kills"], 9, "`total_kills` should be kept whole");
+        assert_eq!(game["players"], serde_json::json!(["Zeh"]), "Wrong players for the positive threshold");
+        assert_eq!(game["kills"], serde_json::json!({"Zeh": 5}), "Wrong kills for the positive threshold");
+
+        let game = game_json(Some(-1));
+        assert_eq!(game["players"], serde_json::json!(["Isgalamido", "Zeh"]), "Wrong players for the negative threshold");
+        assert_eq!(game["kills"], serde_json::json!({"Isgalamido": 0, "Zeh": 5}), "Wrong kills for the negative threshold");
+    }
+
+    /// Tests that, under [Config::anonymize_names], each name gets the same pseudonym in all games -- in all fields --
+    /// `<world>` being kept & the output still being valid JSON
+    #[test]
+    fn anonymize_names() {
+        let summary = |players: &[&str]| GameMatchSummary {
+            total_kills: 1,
+            players: players.iter().map(|player| player.to_string()).collect(),
+            kills: players.iter().map(|player| (player.to_string(), 1)).collect(),
+            total_participants: players.len() as u32,
+            raw_kills: Some(vec![(7, String::from("<world>"), players[0].to_string(), String::from("MOD_TRIGGER_HURT"))]),
+            ..Default::default()
+        };
+        let summaries = vec![summary(&["Zeh", "Isgalamido"]), summary(&["Dono da bola", "Zeh"])];
+        let mut buffer = Cursor::new(Vec::new());
+        to_json(&Config { anonymize_names: true, ..Config::default() }, Box::pin(stream::iter(summaries.into_iter().map(Ok))), &mut buffer)
+            .expect("Failure in generating the json");
+        let json_string = String::from_utf8(buffer.into_inner()).expect("The JSON should be UTF-8");
+        let json_error = validate_json(&json_string);
+        assert!(json_error.is_none(), "The anonymized JSON is not valid: {:?} -- JSON: {json_string}", json_error.unwrap());
+        for real_name in ["Zeh", "Isgalamido", "Dono da bola"] {
+            assert!(!json_string.contains(real_name), "The real name {real_name:?} leaked into the output: {json_string}");
+        }
+
+        let json: serde_json::Value = serde_json::from_str(&json_string).unwrap();
+        let zeh = pseudonym("Zeh");
+        assert_eq!(zeh, "player_3d394874d9321d01", "The pseudonyms should be the first 64 bits of the names' SHA-256");
+        assert_ne!(zeh, pseudonym("Isgalamido"), "Distinct names should get distinct pseudonyms");
+        assert_eq!(json["game_1"]["kills"][&zeh], 1, "Zeh's pseudonym is missing from game 1");
+        assert_eq!(json["game_2"]["kills"][&zeh], 1, "Zeh's pseudonym should be the same in game 2");
+        assert_eq!(json["game_1"]["raw_kills"][0]["killer"], "<world>", "`<world>` should be kept as is");
+        assert_eq!(json["game_1"]["raw_kills"][0]["victim"], serde_json::json!(zeh), "The raw kills should also be anonymized");
+    }
+
+    /// Tests that the `ranking` is only emitted under [Config::rank_by] -- and that frags & K/D ratios may rank the players differently
+    #[test]
+    fn rank_by() {
+        let raw_kill = |killer: &str, victim: &str| (0, killer.to_owned(), victim.to_owned(), "MOD_RAILGUN".to_owned());
+        let summaries = || vec![
+            GameMatchSummary {
+                total_kills: 8,
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
+                    ("Dono da bola".to_owned(), 5),
+                    ("Isgalamido".to_owned(), 3),
+                ]),
+                total_participants: 3,
+                raw_kills: Some([
+                    vec![raw_kill("Dono da bola", "Zeh"); 5],
+                    vec![raw_kill("Isgalamido", "Dono da bola"); 3],
+                ].concat()),
+                ..Default::default()
+            }
+        ];
+        let game_json = |rank_by| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { rank_by, ..Config::default() }, Box::pin(stream::iter(summaries().into_iter().map(Ok))), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            json["game_1"].clone()
+        };
+
+        assert_eq!(game_json(None).get("ranking"), None, "`ranking` should not be emitted by default");
+        // frags: 5, 3 & 0
+        assert_eq!(game_json(Some(RankMetric::Frags))["ranking"], serde_json::json!(["Dono da bola", "Isgalamido", "Zeh"]), "Wrong ranking by frags");
+        // K/D: 5/3, 3/1 (no deaths counting as one) & 0/5
+        assert_eq!(game_json(Some(RankMetric::KillDeathRatio))["ranking"], serde_json::json!(["Isgalamido", "Dono da bola", "Zeh"]), "Wrong ranking by K/D ratio");
+    }
+
+    /// Tests that the chat transcript is only emitted under [Config::emit_chat]
+    #[test]
+    fn emit_chat() {
+        let summaries = || vec![
+            GameMatchSummary {
+                total_kills: 0,
+                players: IndexSet::from(["Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::new(),
+                total_participants: 2,
+                chat: Some(vec![
+                    (2, "Isgalamido".to_owned(), "team \"blue\"".to_owned()),
+                    (4, "Zeh to Isgalamido".to_owned(), "gg".to_owned()),
+                ]),
+                ..Default::default()
+            }
+        ];
+        let game_json = |emit_chat| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { emit_chat, ..Config::default() }, Box::pin(stream::iter(summaries().into_iter().map(Ok))), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            json["game_1"].clone()
+        };
+        assert_eq!(game_json(true)["chat"], serde_json::json!([
+            {"event_id": 2, "speaker": "Isgalamido", "message": "team \"blue\""},
+            {"event_id": 4, "speaker": "Zeh to Isgalamido", "message": "gg"},
+        ]), "Wrong chat transcript");
+        assert!(game_json(false).get("chat").is_none(), "The chat should have been omitted");
+    }
+
+    /// Tests that the source of each game is only emitted under [Config::emit_source]
+    #[test]
+    fn emit_source() {
+        let summaries = || vec![
+            GameMatchSummary {
+                total_kills: 0,
+                players: IndexSet::new(),
+                kills: IndexMap::new(),
+                total_participants: 0,
+                source: Some("C:\\logs\\games.log".to_owned()),
+                ..Default::default()
+            }
+        ];
+        let game_json = |emit_source| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { emit_source, ..Config::default() }, Box::pin(stream::iter(summaries().into_iter().map(Ok))), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            json["game_1"].clone()
+        };
+        assert_eq!(game_json(true)["source"], "C:\\logs\\games.log", "Wrong source");
+        assert!(game_json(false).get("source").is_none(), "The source should have been omitted");
+    }
+
+    /// Tests `net_frag_sum` under [Config::emit_frag_sum], for a game having only `<world>` kills -- 3 of them --
+    /// also when [Config::min_player_frags] omits some of the players: they are still accounted for
+    #[test]
+    fn emit_frag_sum() {
+        let summaries = || vec![
+            GameMatchSummary {
+                total_kills: 3,
+                players: IndexSet::from(["Player1".to_owned(), "Player2".to_owned()]),
+                kills: IndexMap::from([
+                    ("Player1".to_owned(), -2),
+                    ("Player2".to_owned(), -1),
+                ]),
+                total_participants: 2,
+                ..Default::default()
+            }
+        ];
+        let game_json = |emit_frag_sum, min_player_frags| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { emit_frag_sum, min_player_frags, ..Config::default() }, Box::pin(stream::iter(summaries().into_iter().map(Ok))), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            json["game_1"].clone()
+        };
+        let game = game_json(true, None);
+        assert_eq!(game["total_kills"], 3, "Wrong `total_kills`");
+        assert_eq!(game["net_frag_sum"], -3, "Wrong `net_frag_sum`");
+        assert_eq!(game["total_kills"].as_i64().zip(game["net_frag_sum"].as_i64()).map(|(total_kills, net_frag_sum)| total_kills - net_frag_sum), Some(2 * 3),
+                   "The difference should be twice the number of `<world>` kills");
+        assert!(game_json(false, None).get("net_frag_sum").is_none(), "`net_frag_sum` should have been omitted");
+
+        let game = game_json(true, Some(-1));
+        assert_eq!(game["kills"], serde_json::json!({"Player2": -1}), "`Player1` should have been omitted");
+        assert_eq!(game["net_frag_sum"], -3, "`net_frag_sum` should still account for the omitted players");
+    }
+
+    /// Tests that all optional fields are emitted as `null` under [Config::emit_null_optionals] -- and omitted otherwise
+    #[test]
+    fn emit_null_optionals() {
+        let summaries = || vec![
+            GameMatchSummary {
+                total_kills: 0,
+                players: IndexSet::new(),
+                kills: IndexMap::new(),
+                total_participants: 0,
+                ..Default::default()
+            }
+        ];
+        let game_json = |emit_null_optionals| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { emit_null_optionals, ..Config::default() }, Box::pin(stream::iter(summaries().into_iter().map(Ok))), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            json["game_1"].as_object().expect("The game should be an object").clone()
+        };
+        let keys = |fields: &[SummaryField]| fields.iter().map(SummaryField::key).collect::<std::collections::BTreeSet<_>>();
+        let mandatory_fields = [SummaryField::TotalKills, SummaryField::Players, SummaryField::Kills];
+        let game = game_json(true);
+        assert_eq!(game.keys().map(String::as_str).collect::<std::collections::BTreeSet<_>>(), keys(&SummaryField::DEFAULT_ORDER), "Not all keys were emitted");
+        for field in SummaryField::DEFAULT_ORDER.iter().filter(|field| !mandatory_fields.contains(field)) {
+            assert_eq!(game[field.key()], serde_json::Value::Null, "`{}` should have been null", field.key());
+        }
+        let game = game_json(false);
+        assert_eq!(game.keys().map(String::as_str).collect::<std::collections::BTreeSet<_>>(), keys(&mandatory_fields), "Only the mandatory keys should have been emitted");
+    }
+
+    /// Tests that games whose JSON exceeds [Config::warn_game_size_bytes] are warned about -- and only them
+    #[test]
+    fn warn_game_size_bytes() {
+        let summary = |map_name: &str, means: usize| GameMatchSummary {
+            total_kills: means as u32,
+            players: IndexSet::from(["Player1".to_owned()]),
+            kills: IndexMap::from([
+                ("Player1".to_owned(), means as i32),
+            ]),
+            total_participants: 1,
+            map_name: Some(map_name.to_owned()),
+            means_of_death: Some((0..means).map(|mean| (format!("MOD_CUSTOM_{mean}"), 1)).collect()),
+            ..Default::default()
+        };
+        let summaries = vec![summary("q3dm_size_normal", 2), summary("q3dm_size_outlier", 1000)];
+        let mut buffer = Cursor::new(Vec::new());
+        let warnings = capture_warnings(|| to_json(&Config { warn_game_size_bytes: Some(1024), ..Config::default() }, Box::pin(stream::iter(summaries.into_iter().map(Ok))), &mut buffer)
+            .expect("Failure in generating the json"));
+        let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+        assert_eq!(json["game_2"]["map_name"], "q3dm_size_outlier", "The oversized game should still have been written");
+        let size_warnings = warnings.iter()
+            .filter(|warning| warning.contains("warn_game_size_bytes"))
+            .collect::<Vec<_>>();
+        assert_eq!(size_warnings.len(), 1, "Exactly one size warning was expected -- got {size_warnings:?}");
+        assert!(size_warnings[0].contains("game_2 "), "The warning should be about `game_2`: {}", size_warnings[0]);
+    }
+
+    /// Tests that the output, with every field present & enabled, is byte-identical to the one in `tests/resources/all_fields.json`
+    /// -- guarding the hand-crafted serialization against formatting changes
+    #[test]
+    fn byte_identical_output() {
+        let summaries = vec![
+            GameMatchSummary {
+                total_kills: 7,
+                players: IndexSet::from(["Zeh".to_owned(), "Isga\"lamido\\".to_owned(), "Dono da bola".to_owned()]),
+                kills: IndexMap::from([
+                    ("Zeh".to_owned(), 4),
+                    ("Isga\"lamido\\".to_owned(), 3),
+                    ("Dono da bola".to_owned(), -1),
+                ]),
+                total_participants: 4,
+                map_name: Some("q3dm17".to_owned()),
+                means_of_death: Some(BTreeMap::from([
+                    ("MOD_RAILGUN".to_owned(), 4),
+                    ("MOD_ROCKET_SPLASH".to_owned(), 2),
+                    ("MOD_TRIGGER_HURT".to_owned(), 1),
+                ])),
+                game_reported_scores: Some(BTreeMap::from([
+                    ("Zeh".to_owned(), 4),
+                    ("Isga\"lamido\\".to_owned(), 3),
+                ])),
+                disconnected_players: Some(vec![(4, "Mal".to_owned(), 0)]),
+                avg_frag_gap_minutes: Some(BTreeMap::from([("Zeh".to_owned(), 1.5)])),
+                busiest_minute: Some((2, 3)),
+                series_id: Some(1),
+                raw_kills: Some(vec![
+                    (3, "Zeh".to_owned(), "Isga\"lamido\\".to_owned(), "MOD_RAILGUN".to_owned()),
+                    (4, "<world>".to_owned(), "Dono da bola".to_owned(), "MOD_TRIGGER_HURT".to_owned()),
+                ]),
+                head_to_head: Some(BTreeMap::from([
+                    ("Isga\"lamido\\".to_owned(), BTreeMap::from([("Zeh".to_owned(), -1)])),
+                    ("Zeh".to_owned(), BTreeMap::from([("Isga\"lamido\\".to_owned(), 1)])),
+                ])),
+                flawless: Some(false),
+                item_events: Some(12),
+                chat: Some(vec![(5, "Zeh".to_owned(), "gg\nwp".to_owned())]),
+                source: Some("C:\\logs\\games.log".to_owned()),
+                longest_drought_minutes: Some(3),
+                source_hash: Some("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08".to_owned()),
+                ..Default::default()
+            },
+            GameMatchSummary {
+                total_kills: 0,
+                players: IndexSet::new(),
+                kills: IndexMap::new(),
+                total_participants: 0,
+                means_of_death: Some(BTreeMap::new()),
+                disconnected_players: Some(vec![]),
+                raw_kills: Some(vec![]),
+                head_to_head: Some(BTreeMap::new()),
+                chat: Some(vec![]),
+                ..Default::default()
+            },
+        ];
+        let config = Config {
+            emit_chat: true,
+            emit_source: true,
+            emit_means_of_death_kinds: true,
+            emit_frag_sum: true,
+            include_kill_share: true,
+            rank_by: Some(RankMetric::Frags),
+            ..Config::default()
+        };
+        let mut buffer = Cursor::new(Vec::new());
+        to_json(&config, Box::pin(stream::iter(summaries.into_iter().map(Ok))), &mut buffer)
+            .expect("Failure in generating the json");
+        let json_string = String::from_utf8(buffer.into_inner()).expect("The JSON should be UTF-8");
+        assert_eq!(json_string, include_str!("../tests/resources/all_fields.json"), "The serialized output changed");
+    }
+
+    /// Tests that [Config::top_means_n] keeps only the most common means in `kills_by_means`, summing the others into `"other"`
+    #[test]
+    fn top_means_n() {
+        let summaries = || vec![
+            GameMatchSummary {
+                total_kills: 15,
+                players: IndexSet::from(["Player1".to_owned(), "Player2".to_owned()]),
+                kills: IndexMap::from([
+                    ("Player1".to_owned(), 9),
+                    ("Player2".to_owned(), 6),
+                ]),
+                total_participants: 2,
+                means_of_death: Some(BTreeMap::from([
+                    ("MOD_GRENADE".to_owned(), 2),
+                    ("MOD_MACHINEGUN".to_owned(), 1),
+                    ("MOD_RAILGUN".to_owned(), 4),
+                    ("MOD_ROCKET".to_owned(), 6),
+                    ("MOD_SHOTGUN".to_owned(), 2),
+                ])),
+                ..Default::default()
+            }
+        ];
+        let kills_by_means = |top_means_n| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { top_means_n, ..Config::default() }, Box::pin(stream::iter(summaries().into_iter().map(Ok))), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            json["game_1"]["kills_by_means"].clone()
+        };
+        assert_eq!(kills_by_means(Some(2)), serde_json::json!({"MOD_ROCKET": 6, "MOD_RAILGUN": 4, "other": 5}), "Wrong top 2 `kills_by_means`");
+        assert_eq!(kills_by_means(Some(5)), kills_by_means(None), "No `other` should be emitted when all means fit in the top N");
+    }
+
+    /// Tests the `kill_share` of each player under [Config::include_kill_share] -- negative frags counting as none
+    #[test]
+    fn include_kill_share() {
+        let summaries = || vec![
+            GameMatchSummary {
+                total_kills: 8,
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Mocinha".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
+                    ("Dono da bola".to_owned(), 1),
+                    ("Isgalamido".to_owned(), 5),
+                    ("Mocinha".to_owned(), -2),
+                    ("Zeh".to_owned(), 2),
+                ]),
+                total_participants: 4,
+                ..Default::default()
+            }
+        ];
+        let game_json = |include_kill_share| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { include_kill_share, ..Config::default() }, Box::pin(stream::iter(summaries().into_iter().map(Ok))), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            json["game_1"].clone()
+        };
+        assert_eq!(game_json(true)["kill_share"], serde_json::json!({"Dono da bola": 12.5, "Isgalamido": 62.5, "Mocinha": 0, "Zeh": 25}), "Wrong `kill_share`");
+        assert!(game_json(false).get("kill_share").is_none(), "`kill_share` should have been omitted");
+    }
+
+    /// Tests that the means of death are only grouped by kind under [Config::emit_means_of_death_kinds]
+    #[test]
+    fn emit_means_of_death_kinds() {
+        let summaries = || vec![
+            GameMatchSummary {
+                total_kills: 10,
+                players: IndexSet::new(),
+                kills: IndexMap::new(),
+                total_participants: 0,
+                means_of_death: Some(BTreeMap::from([
+                    ("MOD_RAILGUN".to_owned(), 3),
+                    ("MOD_ROCKET".to_owned(), 4),
+                    ("MOD_ROCKET_SPLASH".to_owned(), 2),
+                    ("MOD_TRIGGER_HURT".to_owned(), 1),
+                ])),
+                ..Default::default()
+            }
+        ];
+        let game_json = |emit_means_of_death_kinds| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { emit_means_of_death_kinds, ..Config::default() }, Box::pin(stream::iter(summaries().into_iter().map(Ok))), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            json["game_1"].clone()
+        };
+        assert_eq!(game_json(true)["kills_by_means_kind"], serde_json::json!({"Environmental": 1, "Explosive": 6, "Hitscan": 3}), "Wrong `kills_by_means_kind`");
+        assert!(game_json(false).get("kills_by_means_kind").is_none(), "The means of death kinds should have been omitted");
+    }
+
+    /// Tests that feeding no games -- or only failed ones -- yields a clean, empty, JSON object. Also tests that a failed
+    /// 1st game doesn't leave a dangling comma behind
+    #[test]
+    fn no_games() {
+        let json_string = |summaries: Vec<Result<GameMatchSummary, Box<dyn std::error::Error>>>, envelope| {
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { log_errors: false, envelope, ..Config::default() }, Box::pin(stream::iter(summaries)), &mut buffer)
+                .expect("Failure in generating the json");
+            String::from_utf8(buffer.into_inner()).unwrap()
+        };
+        assert_eq!(json_string(vec![], false), "{}", "Wrong output for an empty stream");
+        assert_eq!(json_string(vec![Err(Box::from("failed game"))], false), "{}", "Wrong output for a stream of errors");
+        assert_eq!(json_string(vec![Err(Box::from("failed game"))], true), r#"{"games": {}, "game_count": 0, "error_count": 1, "errors": {"game_1": "failed game"}, "status": "partial"}"#,
+                   "Wrong enveloped output for a stream of errors");
+        assert_eq!(json_string(vec![], true), r#"{"games": {}, "game_count": 0, "error_count": 0, "errors": {}, "status": "ok"}"#, "Wrong enveloped output for an empty stream");
+
+        let summary = GameMatchSummary {
+            total_kills: 0,
+            players: IndexSet::new(),
+            kills: IndexMap::new(),
+            total_participants: 0,
+            ..Default::default()
+        };
+        let json_string = json_string(vec![Err(Box::from("failed game")), Ok(summary)], false);
+        let json_error = validate_json(&json_string);
+        assert!(json_error.is_none(), "The produced JSON is not valid: {:?}", json_error.unwrap());
+    }
+
+    /// Tests the [Config::envelope] wrapping: errors are skipped & left out of the `game_count` -- but are told by the `status`
+    #[test]
+    fn envelope() {
+        let summary = || GameMatchSummary {
+            total_kills: 1,
+            players: IndexSet::from(["Isgalamido".to_owned()]),
+            kills: IndexMap::from([("Isgalamido".to_owned(), 1)]),
+            total_participants: 1,
+            ..Default::default()
+        };
+        let summaries: Vec<Result<GameMatchSummary, Box<dyn std::error::Error>>> = vec![
+            Ok(summary()),
+            Err(Box::from("skipped game")),
+            Ok(summary()),
+        ];
+        let mut buffer = Cursor::new(Vec::new());
+        let games_offsets = to_json_with_offsets(
+            &Config { log_errors: false, envelope: true, ..Config::default() },
+            Box::pin(stream::iter(summaries)),
+            &mut buffer
+        ).expect("Failure in generating the json");
+        let json_string = String::from_utf8(buffer.into_inner()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json_string).expect("Invalid JSON");
+        assert_eq!(json["status"], "partial", "Wrong envelope status");
+        assert_eq!(json["error_count"], 1, "Wrong envelope `error_count`");
+        assert_eq!(json["errors"], serde_json::json!({"game_2": "skipped game"}), "Wrong envelope `errors`");
+        assert_eq!(json["game_count"], 2, "Wrong envelope `game_count`");
+        let games = json["games"].as_object().expect("`games` should be an object");
+        assert_eq!(games.keys().collect::<Vec<_>>(), vec!["game_1", "game_3"], "Wrong enveloped games");
+        let (_game_id, range) = &games_offsets[0];
+        let game_json: serde_json::Value = serde_json::from_str(&json_string[range.clone()]).expect("Offsets should slice out a valid JSON object");
+        assert_eq!(game_json, json["games"]["game_1"], "Offsets should take the envelope into account");
+    }
+
+    /// Tests that [Config::parallel_serialize] yields the very same output as the serial path -- for more games than fit in a batch
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_serialize() {
+        let summaries = || (1..=150)
+            .map(|game| if game % 50 == 0 {
+                Err(Box::from(format!("skipped game {game}")))
+            } else {
+                Ok(GameMatchSummary {
+                    total_kills: game,
+                    players: IndexSet::from(["Isgalamido".to_owned(), "Zeh".to_owned()]),
+                    kills: IndexMap::from([
+                        ("Isgalamido".to_owned(), game as i32),
+                        ("Zeh".to_owned(), 0),
+                    ]),
+                    total_participants: 2,
+                    map_name: Some("q3dm17".to_owned()),
+                    means_of_death: Some(BTreeMap::from([("MOD_RAILGUN".to_owned(), game as i32)])),
+                    busiest_minute: Some((1, game)),
+                    ..Default::default()
+                })
+            })
+            .collect::<Vec<Result<GameMatchSummary, Box<dyn std::error::Error>>>>();
+        let json_and_offsets = |parallel_serialize| {
+            let mut buffer = Cursor::new(Vec::new());
+            let games_offsets = to_json_with_offsets(
+                &Config { log_errors: false, parallel_serialize, ..Config::default() },
+                Box::pin(stream::iter(summaries())),
+                &mut buffer
+            ).expect("Failure in generating the json");
+            (buffer.into_inner(), games_offsets)
+        };
+        let (serial_json, serial_offsets) = json_and_offsets(false);
+        let (parallel_json, parallel_offsets) = json_and_offsets(true);
+        assert!(serial_json == parallel_json, "The parallel output differs from the serial one");
+        assert_eq!(parallel_offsets, serial_offsets, "The parallel offsets differ from the serial ones");
+        assert_eq!(parallel_offsets.len(), 147, "Wrong number of games written");
+    }
+
+    /// Tests that the byte ranges returned by [to_json_with_offsets()] slice out each game's JSON object
+    #[test]
+    fn games_offsets() {
+        let summary = |total_kills| GameMatchSummary {
+            total_kills,
+            players: IndexSet::from(["Isgalamido".to_owned(), "Zeh".to_owned()]),
+            kills: IndexMap::from([
+                ("Isgalamido".to_owned(), total_kills as i32),
+                ("Zeh".to_owned(), 0),
+            ]),
+            total_participants: 2,
+            disconnected_players: Some(vec![(3, "Zeh \"Maneh\"".to_owned(), 2)]),
+            ..Default::default()
+        };
+        let summaries: Vec<Result<GameMatchSummary, Box<dyn std::error::Error>>> = vec![
+            Ok(summary(3)),
+            Err(Box::from("skipped game")),
+            Ok(summary(7)),
+        ];
+        let mut buffer = Cursor::new(Vec::new());
+        let games_offsets = to_json_with_offsets(
+            &Config { stop_on_errors: false, log_errors: false, ..Config::default() },
+            Box::pin(stream::iter(summaries)),
+            &mut buffer
+        ).expect("Failure in generating the json");
+        let json_string = String::from_utf8(buffer.into_inner()).unwrap();
+        assert_eq!(games_offsets.iter().map(|(game_id, _range)| *game_id).collect::<Vec<_>>(), vec![1, 3], "Wrong game ids");
+        for ((_game_id, range), expected_total_kills) in games_offsets.into_iter().zip([3, 7]) {
+            let game_json: serde_json::Value = serde_json::from_str(&json_string[range.clone()])
+                .unwrap_or_else(|err| panic!("The range {range:?} doesn't slice out a valid JSON object: {err:?} -- JSON: {json_string}"));
+            assert_eq!(game_json["total_kills"], expected_total_kills, "The range {range:?} sliced out the wrong game");
+        }
+    }
+
+    /// Tests that content-identical games are collapsed into their first occurrence under [Config::dedup_identical_games]
+    #[test]
+    fn dedup_identical_games() {
+        let summary = |total_kills| GameMatchSummary {
+            total_kills,
+            players: IndexSet::from(["Isgalamido".to_owned()]),
+            kills: IndexMap::from([
+                ("Isgalamido".to_owned(), total_kills as i32),
+            ]),
+            total_participants: 1,
+            ..Default::default()
+        };
+        let games_json = |dedup_identical_games, envelope| {
+            let summaries = [3, 7, 3, 3].map(|total_kills| Ok(summary(total_kills)));
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { dedup_identical_games, envelope, ..Config::default() }, Box::pin(stream::iter(summaries)), &mut buffer)
+                .expect("Failure in generating the json");
+            serde_json::from_slice::<serde_json::Value>(&buffer.into_inner()).expect("Invalid JSON")
+        };
+
+        let json = games_json(true, false);
+        assert_eq!(json.as_object().map(|games| games.len()), Some(3), "Only the unique games -- and the `occurrences` trailer -- should have been written: {json}");
+        assert_eq!(json["game_1"]["total_kills"], 3, "Wrong first unique game");
+        assert_eq!(json["game_2"]["total_kills"], 7, "Wrong second unique game");
+        assert_eq!(json["occurrences"], serde_json::json!({"game_1": 3, "game_2": 1}), "Wrong `occurrences` trailer");
+
+        let json = games_json(true, true);
+        assert_eq!(json["games"].as_object().map(|games| games.len()), Some(2), "Only the unique games should have been written: {json}");
+        assert_eq!(json["occurrences"], serde_json::json!({"game_1": 3, "game_2": 1}), "Wrong `occurrences` trailer in the envelope");
+        assert_eq!(json["game_count"], 2, "Wrong `game_count` in the envelope");
+
+        let json = games_json(false, false);
+        assert_eq!(json.as_object().map(|games| games.len()), Some(4), "All games should have been written without deduplication");
+        assert!(json.get("occurrences").is_none(), "`occurrences` should only be emitted when deduplicating");
+    }
+
+    /// Tests that, under [GameOrder::ByKillsDesc], the games are written with the bloodiest first -- keys reassigned
+    #[test]
+    fn game_order() {
+        let summary = |total_kills| GameMatchSummary {
+            total_kills,
+            players: IndexSet::from(["Isgalamido".to_owned()]),
+            kills: IndexMap::from([
+                ("Isgalamido".to_owned(), total_kills as i32),
+            ]),
+            total_participants: 1,
+            ..Default::default()
+        };
+        let total_kills_in_order = |game_order| {
+            let summaries = [3, 9, 5].map(|total_kills| Ok(summary(total_kills)));
+            let mut buffer = Cursor::new(Vec::new());
+            to_json(&Config { game_order, ..Config::default() }, Box::pin(stream::iter(summaries)), &mut buffer)
+                .expect("Failure in generating the json");
+            let json: serde_json::Value = serde_json::from_slice(&buffer.into_inner()).expect("Invalid JSON");
+            (1..=3).map(|game_id| json[format!("game_{game_id}")]["total_kills"].clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(total_kills_in_order(GameOrder::Chronological), vec![3, 9, 5], "Games should be in the stream order");
+        assert_eq!(total_kills_in_order(GameOrder::ByKillsDesc), vec![9, 5, 3], "Games should be ordered by `total_kills`, descending");
+    }
+
+    /// Tests that only the fields in [Config::field_order] are emitted -- and in the given order
+    #[test]
+    fn field_order() {
+        let summaries = vec![
+            GameMatchSummary {
+                total_kills: 7,
+                players: IndexSet::from(["Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
+                    ("Isgalamido".to_owned(), 5),
+                    ("Zeh".to_owned(), 2),
+                ]),
+                total_participants: 2,
+                map_name: Some("q3dm17".to_owned()),
+                ..Default::default()
+            }
+        ];
+        let mut buffer = Cursor::new(Vec::new());
+        to_json(&Config { field_order: vec![SummaryField::Kills, SummaryField::TotalKills], ..Config::default() }, Box::pin(stream::iter(summaries.into_iter().map(Ok))), &mut buffer)
+            .expect("Failure in generating the json");
+        let json_string = String::from_utf8(buffer.into_inner()).unwrap();
+        assert_eq!(json_string, "{\n  \"game_1\": {\n    \"kills\": {\n      \"Isgalamido\": 5,\n      \"Zeh\": 2\n    },\n    \"total_kills\": 7\n  }\n}",
+                   "Wrong fields or order");
+    }
+
+    /// Tests the aggregate stats across two games
+    #[test]
+    fn global_stats() {
+        let summaries = vec![
+            GameMatchSummary {
+                total_kills: 45,
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
+                    ("Dono da bola".to_owned(), 5),
+                    ("Isgalamido".to_owned(), 18),
+                    ("Zeh".to_owned(), 20),
+                ]),
+                total_participants: 3,
+                means_of_death: Some(BTreeMap::from([
+                    ("MOD_PUNCH".to_owned(), 8),
+                    ("MOD_ROCKET".to_owned(), 37),
+                ])),
+                ..Default::default()
+            },
+            GameMatchSummary {
+                total_kills: 12,
+                players: IndexSet::from(["Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
+                    ("Isgalamido".to_owned(), 7),
+                    ("Zeh".to_owned(), -1),
+                ]),
+                total_participants: 2,
+                means_of_death: Some(BTreeMap::from([
+                    ("MOD_FALLING".to_owned(), 2),
+                    ("MOD_ROCKET".to_owned(), 10),
+                ])),
+                ..Default::default()
+            }
+        ];
+        let mut buffer = Cursor::new(Vec::new());
+        to_json_global(
+            &Config::default(),
+            Box::pin(stream::iter(summaries.into_iter().map(Ok))),
+            &mut buffer
+        ).expect("Failure in generating the global json");
+        let json_string = String::from_utf8(buffer.into_inner()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json_string)
+            .unwrap_or_else(|err| panic!("The produced JSON is not valid: {err:?} -- JSON: {json_string}"));
+        assert_eq!(json["total_games"], 2, "Wrong `total_games`");
+        assert_eq!(json["total_kills"], 57, "Wrong `total_kills`");
+        assert_eq!(json["top_player"], "Isgalamido", "Wrong `top_player`");
+        assert_eq!(json["kills_by_means"], serde_json::json!({"MOD_FALLING": 2, "MOD_PUNCH": 8, "MOD_ROCKET": 47}), "Wrong `kills_by_means`");
+    }
+
     fn assert_json(summaries: Vec<GameMatchSummary>) {
         let summaries = summaries.into_iter()
             .map(|summary| Ok(summary));
@@ -231,4 +1468,29 @@ mod tests {
             Ok(_) => None,
             Err(err) => Some(err),
         }
+    }
+
+    /// Runs `operation`, returning the warnings logged meanwhile. As the logger is global, warnings from concurrent tests
+    /// may also be captured -- so they should be told apart by their contents
+    fn capture_warnings(operation: impl FnOnce()) -> Vec<String> {
+        static CAPTURED_WARNINGS: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+        struct CapturingLogger;
+        impl log::Log for CapturingLogger {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.level() <= log::Level::Warn
+            }
+            fn log(&self, record: &log::Record) {
+                if self.enabled(record.metadata()) {
+                    CAPTURED_WARNINGS.lock().unwrap().push(record.args().to_string());
+                }
+            }
+            fn flush(&self) {}
+        }
+        static LOGGER: CapturingLogger = CapturingLogger;
+        if log::set_logger(&LOGGER).is_ok() {
+            log::set_max_level(log::LevelFilter::Warn);
+        }
+        let already_captured = CAPTURED_WARNINGS.lock().unwrap().len();
+        operation();
+        CAPTURED_WARNINGS.lock().unwrap()[already_captured..].to_vec()
     }}
\ No newline at end of file
@@ -0,0 +1,164 @@
+//! Writes the summaries into a Parquet file -- enabled by the `parquet` feature
+
+use crate::{Config, ParquetColumn, apply_game_options};
+use model::report::{FlatRecord, GamesSummary};
+use std::{
+    io::Write,
+    sync::Arc,
+};
+use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray, UInt32Array};
+use arrow_schema::{DataType, Field, Schema};
+use ::parquet::arrow::ArrowWriter;
+use log::warn;
+
+
+/// Writes the summaries into `writer` as a Parquet file having one row per player per game -- the [FlatRecord]s of each game.\
+/// Besides the mandatory `game_id`, `player` & `kills` columns, the nullable ones filled in by the operations of
+/// [Config::processor_pipeline] are written -- see [ParquetColumn::for_pipeline()] -- `null` for the games not having them.
+/// Each game goes out as its own record batch.
+pub fn to_parquet(config: &Config, games_summary_stream: GamesSummary, writer: impl Write + Send) -> Result<(), Box<dyn std::error::Error>> {
+
+    let parquet_columns = ParquetColumn::for_pipeline(&config.processor_pipeline);
+    let schema = Arc::new(schema(&parquet_columns));
+    let mut parquet_writer = ArrowWriter::try_new(writer, Arc::clone(&schema), None)
+        .map_err(|err| format!("presentation: to_parquet(): Error creating the Parquet writer: {err}"))?;
+
+    let games_summary_stream = futures::executor::block_on_stream(games_summary_stream);
+    for (game_id, summary_result) in (1..).zip(games_summary_stream) {
+        match summary_result {
+            Ok(mut summary) => {
+                apply_game_options(config, &mut summary);
+                let records = summary.to_flat_records(game_id);
+                let batch = RecordBatch::try_new(Arc::clone(&schema), columns(&parquet_columns, &records))
+                    .map_err(|err| format!("presentation: to_parquet(): Error building the record batch for game_id {game_id}: {err}"))?;
+                parquet_writer.write(&batch)
+                    .map_err(|err| format!("presentation: to_parquet(): Error writing game_id {game_id} to the given `writer`: {err}"))?;
+            },
+
+            Err(summary_err) => {
+                let msg = format!("presentation: to_parquet(): Error in `games_summary_stream` while processing game_id {game_id}: {summary_err}");
+                if config.log_errors {
+                    warn!("{msg}");
+                }
+                if config.stop_on_errors {
+                    return Err(Box::from(msg))
+                }
+            }
+        }
+    }
+
+    parquet_writer.close()
+        .map_err(|err| format!("presentation: to_parquet(): Error finishing the Parquet file: {err}"))?;
+    Ok(())
+}
+
+/// The schema of the Parquet output: the mandatory columns followed by the nullable `parquet_columns`
+fn schema(parquet_columns: &[ParquetColumn]) -> Schema {
+    let mandatory_fields = [
+        Field::new("game_id", DataType::UInt32, false),
+        Field::new("player",  DataType::Utf8,   false),
+        Field::new("kills",   DataType::Int32,  false),
+    ];
+    let optional_fields = parquet_columns.iter()
+        .map(|parquet_column| match parquet_column {
+            ParquetColumn::ReportedScore => Field::new("reported_score", DataType::Int32,  true),
+            ParquetColumn::Deaths        => Field::new("deaths",         DataType::UInt32, true),
+            ParquetColumn::MapName       => Field::new("map_name",       DataType::Utf8,   true),
+            ParquetColumn::ItemEvents    => Field::new("item_events",    DataType::UInt32, true),
+        });
+    Schema::new(mandatory_fields.into_iter().chain(optional_fields).collect::<Vec<_>>())
+}
+
+/// The columns of the `records`, in the order of [schema()]
+fn columns(parquet_columns: &[ParquetColumn], records: &[FlatRecord]) -> Vec<ArrayRef> {
+    let mandatory_columns: [ArrayRef; 3] = [
+        Arc::new(records.iter().map(|record| record.game_id).collect::<UInt32Array>()),
+        Arc::new(records.iter().map(|record| Some(record.player.as_str())).collect::<StringArray>()),
+        Arc::new(records.iter().map(|record| record.kills).collect::<Int32Array>()),
+    ];
+    let optional_columns = parquet_columns.iter()
+        .map(|parquet_column| -> ArrayRef { match parquet_column {
+            ParquetColumn::ReportedScore => Arc::new(records.iter().map(|record| record.reported_score).collect::<Int32Array>()),
+            ParquetColumn::Deaths        => Arc::new(records.iter().map(|record| record.deaths).collect::<UInt32Array>()),
+            ParquetColumn::MapName       => Arc::new(records.iter().map(|record| record.map_name.as_deref()).collect::<StringArray>()),
+            ParquetColumn::ItemEvents    => Arc::new(records.iter().map(|record| record.item_events).collect::<UInt32Array>()),
+        }});
+    mandatory_columns.into_iter().chain(optional_columns).collect()
+}
+
+
+/// Unit tests the [parquet](super) exporter
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use model::report::{GameMatchSummary, IndexMap, IndexSet};
+    use bll_api::EventAnalyserOperations;
+    use std::collections::{BTreeMap, HashSet};
+    use arrow_array::Array;
+    use futures::stream;
+    use ::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    /// Writes two games, reading the Parquet back to check the row count, a known kill value & the nulls
+    /// -- and that the columns follow the logic operations in [Config::processor_pipeline]
+    #[test]
+    fn round_trip() {
+        let summary = |kills: &[(&str, i32)], game_reported_scores| GameMatchSummary {
+            total_kills: kills.iter().map(|(_player, frags)| *frags as u32).sum(),
+            players: kills.iter().map(|(player, _frags)| player.to_string()).collect::<IndexSet<_>>(),
+            kills: kills.iter().map(|(player, frags)| (player.to_string(), *frags)).collect::<IndexMap<_, _>>(),
+            total_participants: kills.len() as u32,
+            map_name: Some("q3dm17".to_owned()),
+            game_reported_scores,
+            ..Default::default()
+        };
+        let summaries = || vec![
+            summary(&[("Dono da bola", 5), ("Isgalamido", 18), ("Zeh", 20)], Some(BTreeMap::from([("Isgalamido".to_owned(), 19)]))),
+            summary(&[("Zeh", 3)], None),
+        ];
+        let parquet_path = std::env::temp_dir().join(format!("presentation_parquet_round_trip_{}.parquet", std::process::id()));
+        let write_and_read_back = |processor_pipeline, summaries: Vec<GameMatchSummary>| {
+            let parquet_file = std::fs::File::create(&parquet_path).expect("Couldn't create the Parquet file");
+            let config = Config { processor_pipeline, ..Config::default() };
+            to_parquet(&config, Box::pin(stream::iter(summaries.into_iter().map(Ok))), parquet_file)
+                .expect("Failure in writing the Parquet file");
+            let parquet_file = std::fs::File::open(&parquet_path).expect("Couldn't open the Parquet file");
+            let batches = ParquetRecordBatchReaderBuilder::try_new(parquet_file)
+                .and_then(|builder| builder.build())
+                .expect("Couldn't read the Parquet file back")
+                .collect::<Result<Vec<_>, _>>()
+                .expect("Couldn't read the record batches back");
+            let _ = std::fs::remove_file(&parquet_path);
+            batches
+        };
+        let column_names = |batch: &RecordBatch| batch.schema().fields().iter().map(|field| field.name().to_owned()).collect::<Vec<_>>();
+
+        // scenario: only the columns of the enabled operations
+        let batches = write_and_read_back(HashSet::from([EventAnalyserOperations::Kills]), summaries());
+        assert_eq!(column_names(&batches[0]), vec!["game_id", "player", "kills", "map_name"], "Unexpected columns for the default pipeline");
+
+        // scenario: all columns
+        let all_columns_pipeline = HashSet::from([
+            EventAnalyserOperations::Kills,
+            EventAnalyserOperations::GameReportedScores,
+            EventAnalyserOperations::KillLog,
+            EventAnalyserOperations::ItemActivity,
+        ]);
+        let batches = write_and_read_back(all_columns_pipeline, summaries());
+        assert_eq!(batches.iter().map(RecordBatch::num_rows).sum::<usize>(), 4, "Unexpected number of rows");
+        let batch = &batches[0];
+        assert_eq!(column_names(batch), vec!["game_id", "player", "kills", "reported_score", "deaths", "map_name", "item_events"],
+                   "Unexpected columns");
+        let column = |name| batch.column_by_name(name).unwrap_or_else(|| panic!("Column `{name}` is missing"));
+        let players = column("player").as_any().downcast_ref::<StringArray>().expect("`player` should be a string column");
+        let kills = column("kills").as_any().downcast_ref::<Int32Array>().expect("`kills` should be an int column");
+        let isgalamido = (0..players.len()).find(|row| players.value(*row) == "Isgalamido").expect("Isgalamido's row is missing");
+        assert_eq!(kills.value(isgalamido), 18, "Wrong kills for Isgalamido");
+        let reported_scores = column("reported_score").as_any().downcast_ref::<Int32Array>().expect("`reported_score` should be an int column");
+        assert_eq!(reported_scores.value(isgalamido), 19, "Wrong reported score for Isgalamido");
+        let null_count = |name| batches.iter().map(|batch| batch.column_by_name(name).map_or(0, |column| column.null_count())).sum::<usize>();
+        assert_eq!(null_count("reported_score"), 3, "Players without a reported score should have it null");
+        assert_eq!(null_count("deaths"), 4, "Deaths should be null without the raw kills");
+        assert_eq!(null_count("map_name"), 0, "The map name should be present");
+    }
+
+}
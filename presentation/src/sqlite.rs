@@ -0,0 +1,135 @@
+//! Exports the summaries into a SQLite database -- enabled by the `sqlite` feature
+
+use crate::{Config, anonymize_names, serialize_map, serialize_vec};
+use model::report::{FlatRecord, GamesSummary};
+use std::path::Path;
+use rusqlite::{Connection, params};
+use log::warn;
+
+
+/// Tables created by [to_sqlite()], if they don't exist yet.\
+/// Extended / optional fields are nullable: `kills_by_means` & `disconnected_players` are stored as JSON texts
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS games (
+        game_id              INTEGER NOT NULL PRIMARY KEY,
+        total_kills          INTEGER NOT NULL,
+        kills_by_means       TEXT,
+        disconnected_players TEXT
+    );
+    CREATE TABLE IF NOT EXISTS player_kills (
+        game_id              INTEGER NOT NULL REFERENCES games(game_id),
+        player               TEXT    NOT NULL,
+        kills                INTEGER NOT NULL,
+        game_reported_score  INTEGER,
+        PRIMARY KEY (game_id, player)
+    );
+";
+
+/// Inserts the summaries into the SQLite database at `db_path` (created if needed): one row per game in the `games` table
+/// and one row per game/player in the `player_kills` table -- all within a single transaction, which is only committed
+/// if `games_summary_stream` is fully consumed (errors in it are skipped unless [Config::stop_on_errors] is set).
+pub fn to_sqlite(config: &Config, games_summary_stream: GamesSummary, db_path: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+
+    let db_path = db_path.as_ref();
+    let mut connection = Connection::open(db_path)
+        .map_err(|err| format!("presentation: to_sqlite(): Error opening the SQLite database at '{}': {err}", db_path.display()))?;
+    connection.execute_batch(SCHEMA)
+        .map_err(|err| format!("presentation: to_sqlite(): Error creating the tables: {err}"))?;
+    let transaction = connection.transaction()
+        .map_err(|err| format!("presentation: to_sqlite(): Error starting the transaction: {err}"))?;
+
+    let games_summary_stream = futures::executor::block_on_stream(games_summary_stream);
+    for (game_id, summary_result) in (1..).zip(games_summary_stream) {
+        match summary_result {
+            Ok(mut summary) => {
+                if config.anonymize_names {
+                    anonymize_names(&mut summary);
+                }
+                let kills_by_means = summary.means_of_death.as_ref()
+                    .map(|means_of_death| serialize_map("", means_of_death));
+                let disconnected_players = summary.disconnected_players.as_ref()
+                    .map(|disconnected_players| serialize_vec("", disconnected_players));
+                transaction.execute("INSERT INTO games (game_id, total_kills, kills_by_means, disconnected_players) VALUES (?1, ?2, ?3, ?4)",
+                                    params![game_id, summary.total_kills, kills_by_means, disconnected_players])
+                    .map_err(|err| format!("presentation: to_sqlite(): Error inserting game_id {game_id} into the `games` table: {err}"))?;
+                for FlatRecord { player, kills, reported_score, .. } in summary.to_flat_records(game_id) {
+                    transaction.execute("INSERT INTO player_kills (game_id, player, kills, game_reported_score) VALUES (?1, ?2, ?3, ?4)",
+                                        params![game_id, player, kills, reported_score])
+                        .map_err(|err| format!("presentation: to_sqlite(): Error inserting player '{player}' of game_id {game_id} into the `player_kills` table: {err}"))?;
+                }
+            },
+
+            Err(summary_err) => {
+                let msg = format!("presentation: to_sqlite(): Error in `games_summary_stream` while processing game_id {game_id}: {summary_err}");
+                if config.log_errors {
+                    warn!("{msg}");
+                }
+                if config.stop_on_errors {
+                    return Err(Box::from(msg))
+                }
+            }
+        }
+    }
+
+    transaction.commit()
+        .map_err(|err| format!("presentation: to_sqlite(): Error committing the transaction: {err}"))?;
+    Ok(())
+}
+
+
+/// Unit tests the [sqlite](super) exporter
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use model::report::{GameMatchSummary, IndexMap, IndexSet};
+    use std::collections::BTreeMap;
+    use futures::stream;
+
+    /// Inserts a happy-path summary and queries the kill counts back
+    #[test]
+    fn happy_path() {
+        let summaries = vec![
+            GameMatchSummary {
+                total_kills: 45,
+                players: IndexSet::from(["Dono da bola".to_owned(), "Isgalamido".to_owned(), "Zeh".to_owned()]),
+                kills: IndexMap::from([
+                    ("Dono da bola".to_owned(), 5),
+                    ("Isgalamido".to_owned(), 18),
+                    ("Zeh".to_owned(), 20),
+                ]),
+                total_participants: 3,
+                game_reported_scores: Some(BTreeMap::from([
+                    ("Isgalamido".to_owned(), 19),
+                ])),
+                ..Default::default()
+            }
+        ];
+        let db_path = std::env::temp_dir().join(format!("presentation_sqlite_happy_path_{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&db_path);
+        to_sqlite(
+            &Config::default(),
+            Box::pin(stream::iter(summaries.into_iter().map(Ok))),
+            &db_path
+        ).expect("Failure in exporting to SQLite");
+
+        let connection = Connection::open(&db_path).expect("Couldn't reopen the SQLite database");
+        let total_kills: u32 = connection.query_row("SELECT total_kills FROM games WHERE game_id = 1", [], |row| row.get(0))
+            .expect("Couldn't query the `games` table");
+        assert_eq!(total_kills, 45, "Wrong `total_kills`");
+        let mut statement = connection.prepare("SELECT player, kills, game_reported_score FROM player_kills WHERE game_id = 1 ORDER BY player")
+            .expect("Couldn't prepare the `player_kills` query");
+        let player_kills: Vec<(String, i32, Option<i32>)> = statement.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .expect("Couldn't query the `player_kills` table")
+            .collect::<Result<_, _>>()
+            .expect("Couldn't read the `player_kills` rows");
+        assert_eq!(player_kills, vec![
+            ("Dono da bola".to_owned(), 5,  None),
+            ("Isgalamido".to_owned(),   18, Some(19)),
+            ("Zeh".to_owned(),          20, None),
+        ], "Wrong kill counts");
+        drop(statement);
+        drop(connection);
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+}
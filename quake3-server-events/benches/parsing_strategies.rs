@@ -44,7 +44,8 @@ const LOG_EXERPT: &[&str] = &[
 // 2) `*_data_extraction()` are the functions that will parse the `data` component of some events
 
 const LOG_PARSING_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"^ *(?P<time>\d{1,3}:\d{2}) (?P<event_name>[^:]*):? ?(?P<data>.*)$"#)
+    // as in `deserialize_log_line()`, all leading spaces of the data are trimmed
+    Regex::new(r#"^ *(?P<time>\d{1,3}:\d{2}) (?P<event_name>[^:]*):? *(?P<data>.*)$"#)
         .expect("LOG_PARSING_REGEX compilation failed")
 });
 fn regex_event_identification(log_line: &str) -> (&str, &str, &str) {
@@ -74,7 +75,8 @@ fn split_event_identification(log_line: &str) -> (&str, &str, &str) {
         parts.next()
             .expect("split_event_identification(): Couldn't extract `event_name` from the log line"),
         parts.next()
-            .unwrap_or(""),
+            .unwrap_or("")
+            .trim_start_matches(' '),
     )
 }
 
@@ -10,23 +10,149 @@ use std::{
 };
 
 
+/// The names of the events that, although known to be produced by Quake 3 servers, carry nothing of interest:
+/// they are deserialized into [Quake3FullEvents::Ignored] rather than failing with [EventParsingError::UnknownEventName]
+pub const DEFAULT_IGNORED_EVENT_NAMES: &[&str] = &["Warmup", "broadcast", "challenge"];
+
 /// Transforms raw Quake 3 Log lines into the appropriate [model::quake3_logs::LogEvent] variants,
-/// returning any errors that prevents the correct parsing.
+/// returning any errors that prevents the correct parsing.\
+/// All leading spaces of the event data are trimmed: `ClientConnect:  2` is the same as `ClientConnect: 2`.\
+/// Trailing line breaks are trimmed as well -- see [deserialize_log_line_with_options()].\
+/// Records cut short -- like the last line of a log being written -- yield [LogParsingError::TruncatedLine], so they may be retried.
 pub fn deserialize_log_line<'a>(log_line: &str) -> Result<Quake3FullEvents<'a>, LogParsingError> {
-    let log_line = log_line.trim_start_matches(" ");
+    deserialize_log_line_with_options(log_line, &DeserializationOptions::default())
+        .map(|(_time_secs, event)| event)
+}
+
+/// Same as [deserialize_log_line()], but tunable through the given `options` -- see [DeserializationOptions] --
+/// & also returning the line's `MM:SS` time, converted to seconds: `None` if it couldn't be parsed.\
+/// Seconds beyond 59 yield [LogParsingError::UnparseableTime], regardless of the options.\
+/// Only the trailing `\r` & `\n` are trimmed from the line -- the `\r` being left behind by CRLF line endings -- but not
+/// other trailing whitespace: names may end in spaces, and the last field of some records (as in `ClientUserinfoChanged: 2 n\Zeh `)
+/// is a name.
+pub fn deserialize_log_line_with_options<'a>(log_line: &str, options: &DeserializationOptions) -> Result<(Option<u32>, Quake3FullEvents<'a>), LogParsingError> {
+    let DeserializationOptions { time_ranges, strict_init_game, ignored_event_names } = *options;
+    let log_line = log_line.trim_start_matches(" ").trim_end_matches(['\r', '\n']);
     if log_line.len() == 0 {
         return Err(LogParsingError::EmptyLine)
     }
 
-    let (_time, event_name_and_data) = log_line.split_once(" ")
+    let (time, event_name_and_data) = log_line.split_once(" ")
         .map_or(Err(LogParsingError::UnrecognizedLineFormat), Ok)?;
+    let time_secs = match time_ranges {
+        Some(time_ranges) => Some(validated_seconds_from(time, time_ranges)?),
+        None => seconds_from(time)?,
+    };
     if event_name_and_data.starts_with("-") {
-        return Ok(Quake3FullEvents::Comment)
+        return Ok((time_secs, Quake3FullEvents::Comment))
     }
     let (event_name, data) = event_name_and_data.split_once(":")
         .map_or(Err(LogParsingError::UnrecognizedLineFormat), Ok)?;
-    from_parts(event_name, data.trim_start_matches(" "))
-        .map_err(|event_parsing_error| LogParsingError::EventParsingError { event_name: event_name.to_string(), event_parsing_error })
+    let data = data.trim_start_matches(" ");
+    from_parts(event_name, data, strict_init_game, ignored_event_names)
+        .map(|event| (time_secs, event))
+        .map_err(|event_parsing_error| log_parsing_error(event_name, data, event_parsing_error))
+}
+
+/// Same as [deserialize_log_line_with_options()], but splitting the line into its parts with the given `log_line_regex`
+/// -- for nonstandard log formats, having different times or prefixes.\
+/// The regex should be built with [log_line_regex()], which assures the required named groups are present:
+/// `event_name` & `data` are dismembered into the event, whereas `hour`, `minute` & `second` (only `minute` is required)
+/// make up the line's time, in seconds -- `None` if any of them couldn't be parsed, whereas seconds beyond 59
+/// yield [LogParsingError::UnparseableTime].\
+/// The `options` are honored as in the standard format -- [DeserializationOptions::time_ranges] limiting the total minutes,
+/// hours included -- & the line is trimmed in the same way.\
+/// Lines not matching the regex yield [LogParsingError::UnrecognizedLineFormat].
+#[cfg(feature = "regex")]
+pub fn deserialize_log_line_with_regex<'a>(log_line: &str, log_line_regex: &regex::Regex, options: &DeserializationOptions) -> Result<(Option<u32>, Quake3FullEvents<'a>), LogParsingError> {
+    let DeserializationOptions { time_ranges, strict_init_game, ignored_event_names } = *options;
+    let log_line = log_line.trim_end_matches(['\r', '\n']);
+    if log_line.trim_start_matches(" ").is_empty() {
+        return Err(LogParsingError::EmptyLine)
+    }
+    let captures = log_line_regex.captures(log_line)
+        .ok_or(LogParsingError::UnrecognizedLineFormat)?;
+    let group = |group_name| captures.name(group_name).map(|matched| matched.as_str());
+    if let Some(seconds) = group("second").filter(|seconds| number_from::<u32>(seconds).is_some_and(|seconds| seconds >= 60)) {
+        return Err(LogParsingError::UnparseableTime { field_name: "seconds", observed_number: seconds.to_string() })
+    }
+    let time_secs = ["hour", "minute", "second"].into_iter()
+        .zip([3600, 60, 1])
+        .try_fold(0, |time_secs, (group_name, multiplier)| match group(group_name) {
+            Some(number) => number_from::<u32>(number).map(|number| time_secs + number * multiplier),
+            None if group_name == "minute" => None,
+            None => Some(time_secs),
+        });
+    if let Some(time_ranges) = time_ranges {
+        let minutes = time_secs
+            .ok_or_else(|| LogParsingError::UnparseableTime { field_name: "time", observed_number: group("minute").unwrap_or_default().to_string() })?
+            / 60;
+        if time_ranges.max_minutes.is_some_and(|max_minutes| minutes > max_minutes) {
+            return Err(LogParsingError::UnparseableTime { field_name: "minutes", observed_number: minutes.to_string() })
+        }
+    }
+    let event_name = group("event_name").unwrap_or_default();
+    if event_name.starts_with("-") {
+        return Ok((time_secs, Quake3FullEvents::Comment))
+    }
+    let data = group("data").unwrap_or_default().trim_start_matches(" ");
+    from_parts(event_name, data, strict_init_game, ignored_event_names)
+        .map(|event| (time_secs, event))
+        .map_err(|event_parsing_error| log_parsing_error(event_name, data, event_parsing_error))
+}
+
+/// Compiles `pattern` into a regex suitable for [deserialize_log_line_with_regex()] -- failing if it is invalid
+/// or lacks any of the required named groups: `minute`, `event_name` & `data`
+#[cfg(feature = "regex")]
+pub fn log_line_regex(pattern: &str) -> Result<regex::Regex, LogLineRegexError> {
+    let regex = regex::Regex::new(pattern)
+        .map_err(|err| LogLineRegexError::InvalidPattern { description: err.to_string() })?;
+    let group_names = regex.capture_names().flatten().collect::<Vec<_>>();
+    match ["minute", "event_name", "data"].into_iter().find(|required_group| !group_names.contains(required_group)) {
+        Some(group_name) => Err(LogLineRegexError::MissingGroup { group_name }),
+        None => Ok(regex),
+    }
+}
+
+/// The errors that could prevent a custom log line regex from being used -- see [log_line_regex()]
+#[cfg(feature = "regex")]
+#[derive(Debug, PartialEq)]
+pub enum LogLineRegexError {
+    InvalidPattern { description: String },
+    MissingGroup { group_name: &'static str },
+}
+
+/// Limits for the `MM:SS` log line time -- see [DeserializationOptions::time_ranges]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TimeRanges {
+    /// The maximum accepted minutes -- `None` for no limit.
+    /// Seconds are always required to be within 0..=59
+    pub max_minutes: Option<u32>,
+}
+
+/// The options for [deserialize_log_line_with_options()] -- whose defaults are the ones used by [deserialize_log_line()]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeserializationOptions<'o> {
+    /// If given, the line's time is mandatory and must be within these ranges -- otherwise, [LogParsingError::UnparseableTime]
+    /// is returned
+    pub time_ranges: Option<TimeRanges>,
+    /// If set, `InitGame` events must have their key/value data -- with, at least, the `mapname` key -- otherwise
+    /// [EventParsingError::AbsentKey] is returned.
+    /// Suitable for pedantic processing, as, otherwise, a malformed `InitGame` yields an event with no info at all
+    pub strict_init_game: bool,
+    /// The names of the events to be deserialized into [Quake3FullEvents::Ignored] -- rather than failing with
+    /// [EventParsingError::UnknownEventName]
+    pub ignored_event_names: &'o [&'o str],
+}
+
+impl Default for DeserializationOptions<'_> {
+    fn default() -> Self {
+        Self {
+            time_ranges: None,
+            strict_init_game: false,
+            ignored_event_names: DEFAULT_IGNORED_EVENT_NAMES,
+        }
+    }
 }
 
 /// The errors that could prevent the parsing of a log line
@@ -37,6 +163,24 @@ pub enum LogParsingError {
     MandatoryFieldIsEmpty { field_name: &'static str },
     UnparseableTime { field_name: &'static str, observed_number: String },
     EventParsingError { event_name: String, event_parsing_error: EventParsingError },
+    /// The event was recognized, but its data was cut short -- as in a line still being written: it may be retried later
+    TruncatedLine { event_name: String },
+}
+
+impl LogParsingError {
+    /// Tags this error with the number of the offending line -- so callers may tell the failing lines apart by matching on
+    /// [LineParsingError::line_number], rather than by parsing messages
+    pub fn at_line(self, line_number: Option<usize>) -> LineParsingError {
+        LineParsingError { line_number, error: self }
+    }
+}
+
+/// A [LogParsingError] along with the number of the offending line -- see [LogParsingError::at_line()]
+#[derive(Debug, PartialEq)]
+pub struct LineParsingError {
+    /// As given by the caller -- `None` if it wasn't known
+    pub line_number: Option<usize>,
+    pub error: LogParsingError,
 }
 
 /// The errors that could preventing the parsing of an event in a log line
@@ -48,14 +192,44 @@ pub enum EventParsingError {
     UnknownDataFormat { description: String },
 }
 
-fn from_parts<'a>(event_name: &str, data: &str) -> Result<Quake3FullEvents<'a>, EventParsingError> {
+/// Wraps the `event_parsing_error` of `event_name` -- telling apart the `data` that was merely cut short, as [LogParsingError::TruncatedLine]
+fn log_parsing_error(event_name: &str, data: &str, event_parsing_error: EventParsingError) -> LogParsingError {
+    if matches!(event_parsing_error, EventParsingError::UnknownDataFormat { .. }) && is_truncated(event_name, data) {
+        LogParsingError::TruncatedLine { event_name: event_name.to_string() }
+    } else {
+        LogParsingError::EventParsingError { event_name: event_name.to_string(), event_parsing_error }
+    }
+}
+
+/// Tells if the `data` of `event_name` is a prefix of a well formed one -- only `Kill`s, having a fixed form, are checked:
+/// `<KILLER_ID> <VICTIM_ID> <REASON_ID>: <KILLER_NAME> killed <VICTIM_NAME> by <REASON_NAME>` is truncated if only some
+/// of the ids are there or if the text description lacks the ` by ` part
+fn is_truncated(event_name: &str, data: &str) -> bool {
+    let is_number = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    match event_name {
+        "Kill" => match data.split_once(": ") {
+            Some((ids, text_description)) => ids.split(' ').all(is_number) && !text_description.contains(" by "),
+            None => data.split(' ').all(|part| is_number(part.strip_suffix(':').unwrap_or(part))),
+        },
+        _ => false,
+    }
+}
+
+/// Parses the `data` of the `event_name` into its event -- requiring `InitGame`s to have a `mapname` if `strict_init_game` is set
+/// & yielding [Quake3FullEvents::Ignored] for any of the `ignored_event_names`
+fn from_parts<'a>(event_name: &str, data: &str, strict_init_game: bool, ignored_event_names: &[&str]) -> Result<Quake3FullEvents<'a>, EventParsingError> {
     match event_name {
         "InitGame" => {
             let map = map_from_kv_data(data);
+            if strict_init_game && !map.contains_key("mapname") {
+                return Err(EventParsingError::AbsentKey { key_name: "mapname" })
+            }
             Ok(Quake3FullEvents::InitGame {
                 frag_limit: map.get("fraglimit").and_then(|n| number_from(n)),
                 capture_limit: map.get("capturelimit").and_then(|n| number_from(n)),
                 time_limit_min: map.get("timelimit").and_then(|n| number_from(n)),
+                map_name: map.get("mapname").map(|map_name| Cow::Owned(map_name.to_string())),
+                game_type: map.get("g_gametype").and_then(|n| number_from(n)),
             })
         },
         "ClientConnect" => {
@@ -83,8 +257,30 @@ fn from_parts<'a>(event_name: &str, data: &str) -> Result<Quake3FullEvents<'a>,
                 .map(|id| Quake3FullEvents::ClientDisconnect { id })
                 .ok_or_else(|| EventParsingError::UnparseableNumber { key_name: "client id", observed_data: data.to_string() })
         },
-        "Item" => Ok(Quake3FullEvents::Item),
-        "say" => Ok(Quake3FullEvents::Say),
+        "Item" => {
+            // the item name is whatever follows the client id
+            let (client_id_value, item_name) = data.split_once(" ")
+                .unwrap_or((data, ""));
+            let client_id = number_from(client_id_value)
+                .ok_or_else(|| EventParsingError::UnparseableNumber { key_name: "client id", observed_data: client_id_value.to_string() })?;
+            Ok(Quake3FullEvents::Item { client_id, item_name: Cow::Owned(item_name.to_owned()) })
+        },
+        // team chat (named differently across mods) is told apart by nothing but the event name: it is kept as a regular chat
+        "say" | "say_team" | "sayteam" => {
+            // only the first separator counts -- messages may have colons of their own -- and empty messages may lack the trailing space
+            let (name, message) = data.split_once(": ")
+                .or_else(|| data.strip_suffix(":").map(|name| (name, "")))
+                .ok_or_else(|| EventParsingError::UnknownDataFormat { description: format!("`{event_name}` data doesn't appear to be in the form '<NAME>: <MESSAGE>': data is '{data}'") })?;
+            Ok(Quake3FullEvents::Say { name: Cow::Owned(name.to_owned()), message: Cow::Owned(message.to_owned()) })
+        },
+        "tell" => {
+            let data_format_error = || EventParsingError::UnknownDataFormat { description: format!("`tell` data doesn't appear to be in the form '<FROM> to <TO>: <MESSAGE>': data is '{data}'") };
+            let (from_and_to, message) = data.split_once(": ")
+                .ok_or_else(data_format_error)?;
+            let (from, to) = from_and_to.split_once(" to ")
+                .ok_or_else(data_format_error)?;
+            Ok(Quake3FullEvents::Tell { from: Cow::Owned(from.to_owned()), to: Cow::Owned(to.to_owned()), message: Cow::Owned(message.to_owned()) })
+        },
         "Kill" => {
             let (
                     killer_id,
@@ -106,11 +302,18 @@ fn from_parts<'a>(event_name: &str, data: &str) -> Result<Quake3FullEvents<'a>,
                     parts.next().ok_or_else(data_format_error)?
                 )
             };
+            // player names may contain " killed " or " by " themselves: the reason name never does, so the last " by " is taken;
+            // then, the first " killed " splits the killer & victim names -- unless it is a suicide, where the split yielding
+            // equal names is preferred
             let (killer_name, victim_name, reason_name) = {
                 let text_description_format_error = || EventParsingError::UnknownDataFormat { description: format!("Text description in `Kill` data appears not to be in the form '<KILLER_NAME> killed <VICTIM_NAME> by <REASON_NAME>' -- it was '{text_description}'") };
-                let (killer_name, reminder) = text_description.split_once(" killed ")
+                let (killer_and_victim_names, reason_name) = text_description.rsplit_once(" by ")
                     .ok_or_else(text_description_format_error)?;
-                let (victim_name, reason_name) = reminder.rsplit_once(" by ")
+                let splits = || killer_and_victim_names.match_indices(" killed ")
+                    .map(|(index, separator)| (&killer_and_victim_names[..index], &killer_and_victim_names[index + separator.len()..]));
+                let (killer_name, victim_name) = splits()
+                    .find(|(killer_name, victim_name)| killer_id == victim_id && killer_name == victim_name)
+                    .or_else(|| splits().next())
                     .ok_or_else(text_description_format_error)?;
                 (killer_name.to_string(), victim_name.to_string(), reason_name.to_string())
             };
@@ -123,9 +326,11 @@ fn from_parts<'a>(event_name: &str, data: &str) -> Result<Quake3FullEvents<'a>,
                 reason_name: Cow::Owned(reason_name),
             })
         },
-        "Exit" => Ok(Quake3FullEvents::Exit),
+        "Exit" => Ok(Quake3FullEvents::Exit { reason: Cow::Owned(data.trim().to_owned()) }),
         "red" => {
-            let (red_value, blue_key_value) = data.split_once(" ")
+            // the scores may be separated by any run of whitespace: single or double spaces, tabs...
+            let mut values = data.split_whitespace();
+            let (red_value, blue_key_value) = values.next().zip(values.next())
                 .ok_or_else(|| EventParsingError::UnknownDataFormat { description: format!("event doesn't appear to be in the form 'red:n blue:n': log line: 'red:{data}'")})?;
             let red = number_from(red_value)
                 .ok_or_else(|| EventParsingError::UnparseableNumber { key_name: "red score", observed_data: red_value.to_string() })?;
@@ -140,7 +345,9 @@ fn from_parts<'a>(event_name: &str, data: &str) -> Result<Quake3FullEvents<'a>,
                 .ok_or_else(|| EventParsingError::UnknownDataFormat { description: format!("event doesn't appear to be in the form 'score: n  ping: n  client: n name': log line: 'score:{data}'")})?;
             let frags = number_from(frags_value)
                 .ok_or_else(|| EventParsingError::UnparseableNumber { key_name: "frags", observed_data: frags_value.to_string() })?;
-            let client_values = data.split(": ").skip(2).next()
+            // the name is whatever follows the client id -- and may contain spaces & colons
+            let client_values = data.split_once("client: ")
+                .map(|(_ping, client_values)| client_values)
                 .ok_or_else(|| EventParsingError::UnknownDataFormat { description: format!("couldn't extract client values out of `data` -- '{data}'") })?;
             let (client_id_value, client_name) = client_values.split_once(" ")
                 .ok_or_else(|| EventParsingError::UnknownDataFormat { description: format!("couldn't split client id and name out of `client_values` -- '{client_values}'") })?;
@@ -149,6 +356,7 @@ fn from_parts<'a>(event_name: &str, data: &str) -> Result<Quake3FullEvents<'a>,
             Ok(Quake3FullEvents::Score {frags, id: client_id, name: Cow::Owned(client_name.to_owned())} )
         },
         "ShutdownGame" => Ok(Quake3FullEvents::ShutdownGame),
+        _ if ignored_event_names.contains(&event_name) => Ok(Quake3FullEvents::Ignored { event_name: Cow::Owned(event_name.to_owned()) }),
         _ => Err(EventParsingError::UnknownEventName),
     }
 }
@@ -160,6 +368,33 @@ fn map_from_kv_data(data: &str) -> BTreeMap<&str, &str> {
     BTreeMap::from_iter(kv_iter.map(|(k, v)| (k, v)))
 }
 
+/// Converts the `MM:SS` log line time into seconds -- minutes may have any number of digits.\
+/// `None` if the time couldn't be parsed -- but parsed seconds beyond 59 are rejected with [LogParsingError::UnparseableTime]
+fn seconds_from(time: &str) -> Result<Option<u32>, LogParsingError> {
+    let Some((Some(minutes), seconds_value)) = time.split_once(":").map(|(minutes, seconds)| (number_from::<u32>(minutes), seconds))
+        else {
+            return Ok(None)
+        };
+    match number_from::<u32>(seconds_value) {
+        Some(seconds) if seconds >= 60 => Err(LogParsingError::UnparseableTime { field_name: "seconds", observed_number: seconds_value.to_string() }),
+        seconds => Ok(seconds.map(|seconds| minutes * 60 + seconds)),
+    }
+}
+
+/// Like [seconds_from()], but fails if the `MM:SS` log line time is not within `time_ranges`
+fn validated_seconds_from(time: &str, time_ranges: TimeRanges) -> Result<u32, LogParsingError> {
+    let unparseable = |field_name: &'static str, observed_number: &str| LogParsingError::UnparseableTime { field_name, observed_number: observed_number.to_string() };
+    let (minutes_value, seconds_value) = time.split_once(":")
+        .ok_or_else(|| unparseable("time", time))?;
+    let minutes = number_from::<u32>(minutes_value)
+        .filter(|minutes| time_ranges.max_minutes.is_none_or(|max_minutes| *minutes <= max_minutes))
+        .ok_or_else(|| unparseable("minutes", minutes_value))?;
+    let seconds = number_from::<u32>(seconds_value)
+        .filter(|seconds| *seconds < 60)
+        .ok_or_else(|| unparseable("seconds", seconds_value))?;
+    Ok(minutes * 60 + seconds)
+}
+
 fn number_from<T: FromStr>(number: &str) -> Option<T> {
     number.parse()
         .map_or_else(
@@ -172,6 +407,7 @@ fn number_from<T: FromStr>(number: &str) -> Option<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{GAME_TYPE_CAPTURE_THE_FLAG, GAME_TYPE_DEATHMATCH};
 
 
     // valid messages use cases
@@ -188,13 +424,39 @@ mod tests {
         assert_log_parsing(r#"980:37 ------------------------------------------------------------"#, Quake3FullEvents::Comment);
     }
 
+    /// Tests that the `MM:SS` time is converted to seconds -- or left out if it is not in that form
+    #[test]
+    fn time() {
+        let time_of = |log_line| deserialize_log_line_with_options(log_line, &DeserializationOptions::default()).expect("Log line couldn't be deserialized").0;
+        assert_eq!(time_of(r#"  0:37 ------------------------------------------------------------"#), Some(37));
+        assert_eq!(time_of(r#" 80:37 ClientConnect: 2"#), Some(80*60+37));
+        assert_eq!(time_of(r#"980:37 ShutdownGame:"#), Some(980*60+37));
+        assert_eq!(time_of(r#"2a:00 ShutdownGame:"#), None);
+    }
+
+    /// Tests that lines ending in `\r` -- as left by CRLF line endings -- are parsed as if they didn't
+    #[test]
+    fn crlf_line_endings() {
+        assert_log_parsing("10:28 ShutdownGame:\r", Quake3FullEvents::ShutdownGame);
+        assert_log_parsing("10:28 ShutdownGame:\r\n", Quake3FullEvents::ShutdownGame);
+        assert_log_parsing("10:28 ClientConnect: 2\r", Quake3FullEvents::ClientConnect { id: 2 });
+    }
+
+    /// Tests that only the line breaks are trimmed from the end of the line: the trailing spaces of a name are kept
+    #[test]
+    fn trailing_spaces_in_names() {
+        assert_log_parsing("10:28 ClientUserinfoChanged: 2 n\\Zeh \r\n", Quake3FullEvents::ClientUserinfoChanged { id: 2, name: "Zeh ".into() });
+        assert_log_parsing("10:28 ClientUserinfoChanged: 2 n\\Zeh  ", Quake3FullEvents::ClientUserinfoChanged { id: 2, name: "Zeh  ".into() });
+    }
+
     /// Tests that comment messages are correctly identified
     #[test]
     fn comment() {
         assert_log_parsing(r#"20:37 ------------------------------------------------------------"#, Quake3FullEvents::Comment);
     }
 
-    /// Tests the [Quake3Events::InitGame] messages for each of the game types: Death match vs Capture the flag.
+    /// Tests the [Quake3Events::InitGame] messages for each of the game types: Death match vs Capture the flag
+    /// -- which are told apart by their `game_type`.
     #[test]
     fn init_game() {
         // death match
@@ -203,6 +465,8 @@ mod tests {
                                frag_limit: Some(20),
                                capture_limit: Some(8),
                                time_limit_min: Some(15),
+                               map_name: Some("q3dm17".into()),
+                               game_type: Some(GAME_TYPE_DEATHMATCH),
                            });
         // capture the flag
         assert_log_parsing(r#" 2:33 InitGame: \capturelimit\8\g_maxGameClients\0\timelimit\15\fraglimit\20\dmflags\0\bot_minplayers\0\sv_allowDownload\0\sv_maxclients\16\sv_privateClients\2\g_gametype\4\sv_hostname\Code Miner Server\sv_minRate\0\sv_maxRate\10000\sv_minPing\0\sv_maxPing\0\sv_floodProtect\1\version\ioq3 1.36 linux-x86_64 Apr 12 2009\protocol\68\mapname\Q3TOURNEY6_CTF\gamename\baseq3\g_needpass\0"#,
@@ -210,6 +474,8 @@ mod tests {
                                frag_limit: Some(20),
                                capture_limit: Some(8),
                                time_limit_min: Some(15),
+                               map_name: Some("Q3TOURNEY6_CTF".into()),
+                               game_type: Some(GAME_TYPE_CAPTURE_THE_FLAG),
                            });
     }
     
@@ -218,6 +484,27 @@ mod tests {
         assert_log_parsing(r#" 2:33 ClientConnect: 2"#, Quake3FullEvents::ClientConnect {id: 2});
     }
 
+    /// Tests that all leading spaces of the event data are trimmed -- the same policy the `Regex` strategy
+    /// (see `benches/parsing_strategies.rs`) follows, so both yield identical results
+    #[test]
+    fn multi_space_data() {
+        let regex = regex::Regex::new(r#"^ *(?P<time>\d{1,3}:\d{2}) (?P<event_name>[^:]*):? *(?P<data>.*)$"#)
+            .expect("Regex compilation failed");
+        let regex_deserialization = |log_line| {
+            let captures = regex.captures(log_line).expect("Regex didn't match");
+            from_parts(&captures["event_name"], &captures["data"], false, DEFAULT_IGNORED_EVENT_NAMES)
+        };
+        for (log_line, expected_event) in [
+            (r#" 2:33 ClientConnect:  2"#,     Quake3FullEvents::ClientConnect { id: 2 }),
+            (r#" 2:33 ClientBegin:    2"#,     Quake3FullEvents::ClientBegin { id: 2 }),
+            (r#" 2:33 ClientDisconnect:   2"#, Quake3FullEvents::ClientDisconnect { id: 2 }),
+        ] {
+            assert_log_parsing(log_line, expected_event);
+            assert_eq!(regex_deserialization(log_line).ok(), deserialize_log_line(log_line).ok(),
+                       "The `Regex` & `str::split*()` strategies differ for '{log_line}'");
+        }
+    }
+
     #[test]
     fn client_info() {
         assert_log_parsing(r#"2:33 ClientUserinfoChanged: 2 n\Isgalamido\t\1\model\uriel/zael\hmodel\uriel/zael\g_redteam\\g_blueteam\\c1\5\c2\5\hc\100\w\0\l\0\tt\0\tl\0"#,
@@ -236,12 +523,26 @@ mod tests {
 
     #[test]
     fn item() {
-        assert_log_parsing(r#" 2:36 Item: 2 ammo_rockets"#, Quake3FullEvents::Item)
+        assert_log_parsing(r#" 2:36 Item: 2 ammo_rockets"#, Quake3FullEvents::Item { client_id: 2, item_name: "ammo_rockets".into() })
     }
 
     #[test]
     fn say() {
-        assert_log_parsing(r#"981:26 say: Isgalamido: team blue"#, Quake3FullEvents::Say)
+        assert_log_parsing(r#"981:26 say: Isgalamido: team blue"#, Quake3FullEvents::Say { name: "Isgalamido".into(), message: "team blue".into() });
+        // empty messages
+        assert_log_parsing(r#"981:26 say: Isgalamido: "#, Quake3FullEvents::Say { name: "Isgalamido".into(), message: "".into() });
+        assert_log_parsing(r#"981:26 say: Isgalamido:"#, Quake3FullEvents::Say { name: "Isgalamido".into(), message: "".into() });
+        // colons in the message
+        assert_log_parsing(r#"981:26 say: Isgalamido: gg: rematch at 10:30?"#, Quake3FullEvents::Say { name: "Isgalamido".into(), message: "gg: rematch at 10:30?".into() });
+        // team chat
+        assert_log_parsing(r#" 3:12 say_team: Isgalamido: cover me"#, Quake3FullEvents::Say { name: "Isgalamido".into(), message: "cover me".into() });
+        assert_log_parsing(r#" 3:12 sayteam: Isgalamido: cover me"#, Quake3FullEvents::Say { name: "Isgalamido".into(), message: "cover me".into() });
+    }
+
+    #[test]
+    fn tell() {
+        assert_log_parsing(r#"981:27 tell: Isgalamido to Dono da Bola: meet me at the rail: now"#,
+                           Quake3FullEvents::Tell { from: "Isgalamido".into(), to: "Dono da Bola".into(), message: "meet me at the rail: now".into() })
     }
 
     #[test]
@@ -257,9 +558,32 @@ mod tests {
                            });
     }
 
+    /// Tests that player names containing " killed " or " by " are told apart from the text description separators
+    #[test]
+    fn kill_event_with_unusual_names() {
+        let kill = |killer_id, victim_id, killer_name: &str, victim_name: &str| Quake3FullEvents::Kill {
+            killer_id,
+            victim_id,
+            reason_id: 6,
+            killer_name: killer_name.to_owned().into(),
+            victim_name: victim_name.to_owned().into(),
+            reason_name: "MOD_ROCKET".into(),
+        };
+        assert_log_parsing(r#"20:54 Kill: 3 2 6: killed by bot killed Isgalamido by MOD_ROCKET"#, kill(3, 2, "killed by bot", "Isgalamido"));
+        assert_log_parsing(r#"20:54 Kill: 2 3 6: Isgalamido killed killed by bot by MOD_ROCKET"#, kill(2, 3, "Isgalamido", "killed by bot"));
+        assert_log_parsing(r#"20:54 Kill: 2 3 6: Isgalamido killed Dono killed by bot by MOD_ROCKET"#, kill(2, 3, "Isgalamido", "Dono killed by bot"));
+        // suicides: both names are the same
+        assert_log_parsing(r#"20:54 Kill: 3 3 6: Mr killed Bot killed Mr killed Bot by MOD_ROCKET"#, kill(3, 3, "Mr killed Bot", "Mr killed Bot"));
+        // ambiguous: the first " killed " is taken
+        assert_log_parsing(r#"20:54 Kill: 3 2 6: Mr killed Bot killed Isgalamido by MOD_ROCKET"#, kill(3, 2, "Mr", "Bot killed Isgalamido"));
+    }
+
     #[test]
     fn exit() {
-        assert_log_parsing(r#"10:12 Exit: Capturelimit hit."#, Quake3FullEvents::Exit)
+        assert_log_parsing(r#"10:12 Exit: Capturelimit hit."#, Quake3FullEvents::Exit { reason: "Capturelimit hit.".into() });
+        assert_log_parsing(r#"15:00 Exit: Timelimit hit."#, Quake3FullEvents::Exit { reason: "Timelimit hit.".into() });
+        // no reason
+        assert_log_parsing(r#"10:12 Exit:"#, Quake3FullEvents::Exit { reason: "".into() });
     }
     
     #[test]
@@ -267,6 +591,15 @@ mod tests {
         assert_log_parsing(r#"10:12 red:8  blue:6"#, Quake3FullEvents::CaptureTheFlagResults { red: 8, blue: 6 })
     }
 
+    /// Tests that the capture the flag scores may be separated by any whitespace
+    #[test]
+    fn capture_the_flag_score_whitespace() {
+        assert_log_parsing("10:12 red:8 blue:6", Quake3FullEvents::CaptureTheFlagResults { red: 8, blue: 6 });
+        assert_log_parsing("10:12 red:8  blue:6", Quake3FullEvents::CaptureTheFlagResults { red: 8, blue: 6 });
+        assert_log_parsing("10:12 red:8\tblue:6", Quake3FullEvents::CaptureTheFlagResults { red: 8, blue: 6 });
+        assert_log_parsing("10:12 red:8 \t blue:6", Quake3FullEvents::CaptureTheFlagResults { red: 8, blue: 6 });
+    }
+
     /// Test scores with either positive or negative frags
     #[test]
     fn score() {
@@ -274,6 +607,14 @@ mod tests {
         assert_log_parsing(r#"10:12 score: -77  ping: 3  client: 5 Dono da Bola"#, Quake3FullEvents::Score { frags: -77, id: 5, name: "Dono da Bola".into() })
     }
 
+    /// Test scores for players whose names have multiple spaces or colons
+    #[test]
+    fn score_with_unusual_names() {
+        assert_log_parsing(r#"10:12 score: 7  ping: 3  client: 2 Dono  da   Bola "#, Quake3FullEvents::Score { frags: 7, id: 2, name: "Dono  da   Bola ".into() });
+        assert_log_parsing(r#"10:12 score: 7  ping: 3  client: 2 a: b"#, Quake3FullEvents::Score { frags: 7, id: 2, name: "a: b".into() });
+        assert_log_parsing(r#"10:12 score: 7  ping: 3  client: 2 client: 3"#, Quake3FullEvents::Score { frags: 7, id: 2, name: "client: 3".into() });
+    }
+
     #[test]
     fn shutdown() {
         assert_log_parsing(r#"10:28 ShutdownGame:"#, Quake3FullEvents::ShutdownGame)
@@ -314,6 +655,20 @@ mod tests {
                                  LogParsingError::EventParsingError { event_name: "Init_Game".to_string(), event_parsing_error: EventParsingError::UnknownEventName });
     }
 
+    /// Tests that known events we don't care about are deserialized into [Quake3FullEvents::Ignored]
+    /// -- whereas a genuinely unknown event name still fails
+    #[test]
+    fn ignored_events() {
+        assert_log_parsing(r#" 0:00 Warmup:"#, Quake3FullEvents::Ignored { event_name: "Warmup".into() });
+        assert_log_parsing_error(r#" 3:12 say_teams: Isgalamido: cover me"#,
+                                 LogParsingError::EventParsingError { event_name: "say_teams".to_string(), event_parsing_error: EventParsingError::UnknownEventName });
+        // custom sets replace the default one
+        assert_eq!(deserialize_log_line_with_options(r#" 3:12 Bogus: 1"#, &DeserializationOptions { ignored_event_names: &["Bogus"], ..DeserializationOptions::default() }),
+                   Ok((Some(3*60+12), Quake3FullEvents::Ignored { event_name: "Bogus".into() })));
+        assert_eq!(deserialize_log_line_with_options(r#" 0:00 Warmup:"#, &DeserializationOptions { ignored_event_names: &[], ..DeserializationOptions::default() }),
+                   Err(LogParsingError::EventParsingError { event_name: "Warmup".to_string(), event_parsing_error: EventParsingError::UnknownEventName }));
+    }
+
     /// Tests the [Quake3Events::InitGame] messages with unparseable data are correctly identified, reported and handled
     #[test]
     fn bad_client_connect() {
@@ -325,6 +680,15 @@ mod tests {
                                  LogParsingError::EventParsingError { event_name: String::from("ClientConnect"), event_parsing_error: EventParsingError::UnparseableNumber { key_name: "client id", observed_data: String::from("_2") } });
     }
 
+    /// Tests that `Item` events without a numeric client id are reported
+    #[test]
+    fn bad_item() {
+        assert_log_parsing_error(r#" 2:36 Item: ammo_rockets"#,
+                                 LogParsingError::EventParsingError { event_name: String::from("Item"), event_parsing_error: EventParsingError::UnparseableNumber { key_name: "client id", observed_data: String::from("ammo_rockets") } });
+        assert_log_parsing_error(r#" 2:36 Item: 2a ammo_rockets"#,
+                                 LogParsingError::EventParsingError { event_name: String::from("Item"), event_parsing_error: EventParsingError::UnparseableNumber { key_name: "client id", observed_data: String::from("2a") } });
+    }
+
     #[test]
     fn bad_client_info() {
         // no name -- no `n` key
@@ -354,6 +718,121 @@ mod tests {
 
 
 
+    /// Tests that the given line number is embedded in the errors
+    #[test]
+    fn numbered_errors() {
+        assert_eq!(deserialize_log_line("").map_err(|error| error.at_line(Some(7))), Err(LineParsingError { line_number: Some(7), error: LogParsingError::EmptyLine }));
+        assert_eq!(deserialize_log_line(r#" 3:12 Bogus: 1"#).map_err(|error| error.at_line(Some(42))),
+                   Err(LineParsingError {
+                       line_number: Some(42),
+                       error: LogParsingError::EventParsingError { event_name: "Bogus".to_string(), event_parsing_error: EventParsingError::UnknownEventName },
+                   }));
+        assert_eq!(deserialize_log_line("").map_err(|error| error.at_line(None)), Err(LineParsingError { line_number: None, error: LogParsingError::EmptyLine }));
+        assert_eq!(deserialize_log_line(r#"10:28 ShutdownGame:"#).map_err(|error| error.at_line(Some(1))), Ok(Quake3FullEvents::ShutdownGame));
+    }
+
+    /// Tests that, when time ranges are enforced, out of range or unparseable times are rejected
+    #[test]
+    fn out_of_range_time() {
+        let time_ranges = TimeRanges { max_minutes: Some(999) };
+        let parse = |log_line| deserialize_log_line_with_options(log_line, &DeserializationOptions { time_ranges: Some(time_ranges), ..DeserializationOptions::default() });
+        assert_eq!(parse(r#"10:75 ShutdownGame:"#), Err(LogParsingError::UnparseableTime { field_name: "seconds", observed_number: "75".to_string() }));
+        assert_eq!(parse(r#"1000:00 ShutdownGame:"#), Err(LogParsingError::UnparseableTime { field_name: "minutes", observed_number: "1000".to_string() }));
+        assert_eq!(parse(r#"10 ShutdownGame:"#), Err(LogParsingError::UnparseableTime { field_name: "time", observed_number: "10".to_string() }));
+        assert_eq!(parse(r#"12:99 ShutdownGame:"#), Err(LogParsingError::UnparseableTime { field_name: "seconds", observed_number: "99".to_string() }));
+        assert_eq!(parse(r#"999:59 ShutdownGame:"#), Ok((Some(999*60+59), Quake3FullEvents::ShutdownGame)));
+        assert_eq!(parse(r#"  0:37 ShutdownGame:"#), Ok((Some(37), Quake3FullEvents::ShutdownGame)));
+        assert_eq!(parse(r#" 80:37 ShutdownGame:"#), Ok((Some(80*60+37), Quake3FullEvents::ShutdownGame)));
+        // without the ranges, unparseable times are just left out -- but out of range seconds are still rejected
+        assert_eq!(deserialize_log_line_with_options(r#"10 ShutdownGame:"#, &DeserializationOptions::default()), Ok((None, Quake3FullEvents::ShutdownGame)));
+        assert_eq!(deserialize_log_line_with_options(r#"1000:00 ShutdownGame:"#, &DeserializationOptions::default()), Ok((Some(1000*60), Quake3FullEvents::ShutdownGame)));
+        assert_eq!(deserialize_log_line_with_options(r#"10:75 ShutdownGame:"#, &DeserializationOptions::default()), Err(LogParsingError::UnparseableTime { field_name: "seconds", observed_number: "75".to_string() }));
+        assert_eq!(deserialize_log_line(r#"10:75 ShutdownGame:"#), Err(LogParsingError::UnparseableTime { field_name: "seconds", observed_number: "75".to_string() }));
+    }
+
+    /// Tests that `InitGame`s lacking their data are only rejected when parsing strictly
+    #[test]
+    fn strict_init_game() {
+        let strict = |log_line| deserialize_log_line_with_options(log_line, &DeserializationOptions { strict_init_game: true, ..DeserializationOptions::default() });
+        let absent_map_name = Err(LogParsingError::EventParsingError { event_name: "InitGame".to_string(), event_parsing_error: EventParsingError::AbsentKey { key_name: "mapname" } });
+        assert_eq!(strict(r#"  0:00 InitGame:"#), absent_map_name, "An empty `InitGame` should be rejected when strict");
+        assert_eq!(strict(r#"  0:00 InitGame: \fraglimit\20"#), absent_map_name, "An `InitGame` without `mapname` should be rejected when strict");
+        assert!(matches!(strict(r#"  0:00 InitGame: \fraglimit\20\mapname\q3dm17"#), Ok((Some(0), Quake3FullEvents::InitGame { .. }))), "A complete `InitGame` should be accepted when strict");
+        assert_eq!(deserialize_log_line_with_options(r#"  0:00 InitGame:"#, &DeserializationOptions::default()),
+                   Ok((Some(0), Quake3FullEvents::InitGame { frag_limit: None, capture_limit: None, time_limit_min: None, map_name: None, game_type: None })),
+                   "An empty `InitGame` should be accepted when permissive");
+    }
+
+    /// Tests that a `Kill` cut short is told apart from a malformed one
+    #[test]
+    fn truncated_line() {
+        assert_log_parsing_error(r#"20:54 Kill: 2 3"#, LogParsingError::TruncatedLine { event_name: String::from("Kill") });
+        assert_log_parsing_error(r#"20:54 Kill: 1022 2 22: <world> killed Isgalamido"#, LogParsingError::TruncatedLine { event_name: String::from("Kill") });
+        assert!(matches!(deserialize_log_line(r#"20:54 Kill: 2 three"#), Err(LogParsingError::EventParsingError { event_parsing_error: EventParsingError::UnknownDataFormat { .. }, .. })),
+                "A `Kill` with a non numeric id is malformed, rather than truncated");
+    }
+
+    #[test]
+    fn bad_tell() {
+        assert_log_parsing_error(r#"981:27 tell: Isgalamido: hi"#,
+                                 LogParsingError::EventParsingError {
+                                     event_name: String::from("tell"),
+                                     event_parsing_error: EventParsingError::UnknownDataFormat {
+                                         description: String::from("`tell` data doesn't appear to be in the form '<FROM> to <TO>: <MESSAGE>': data is 'Isgalamido: hi'")
+                                     }
+                                 });
+    }
+
+    /// Tests that lines in a nonstandard format may be parsed with a custom regex, honoring the [DeserializationOptions]
+    /// -- and that regexes lacking the required named groups are refused
+    #[cfg(feature = "regex")]
+    #[test]
+    fn custom_regex() {
+        let regex = log_line_regex(r#"^\[(?P<hour>\d+)h(?P<minute>\d{2})\] (?P<event_name>[^:]*):? *(?P<data>.*)$"#)
+            .expect("The custom regex should have been accepted");
+        let parse = |log_line| deserialize_log_line_with_regex(log_line, &regex, &DeserializationOptions::default());
+        assert_eq!(parse(r#"[1h02] ClientConnect: 2"#), Ok((Some(3600+2*60), Quake3FullEvents::ClientConnect { id: 2 })));
+        assert_eq!(parse(r#"[0h00] ------------------------------------------------------------"#), Ok((Some(0), Quake3FullEvents::Comment)));
+        assert_eq!(parse(r#"[0h05] Kill: 1022 2 22: <world> killed Isgalamido by MOD_TRIGGER_HURT"#),
+                   Ok((Some(5*60), Quake3FullEvents::Kill {
+                       killer_id: 1022,
+                       victim_id: 2,
+                       reason_id: 22,
+                       killer_name: Cow::Borrowed("<world>"),
+                       victim_name: Cow::Borrowed("Isgalamido"),
+                       reason_name: Cow::Borrowed("MOD_TRIGGER_HURT"),
+                   })));
+        assert_eq!(parse(r#"  1:02 ClientConnect: 2"#), Err(LogParsingError::UnrecognizedLineFormat));
+        assert_eq!(parse(r#""#), Err(LogParsingError::EmptyLine));
+        let regex_with_seconds = log_line_regex(r#"^(?P<minute>\d+):(?P<second>\d+) (?P<event_name>[^:]*):? *(?P<data>.*)$"#)
+            .expect("The custom regex with seconds should have been accepted");
+        assert_eq!(deserialize_log_line_with_regex(r#"10:59 ShutdownGame:"#, &regex_with_seconds, &DeserializationOptions::default()), Ok((Some(10*60+59), Quake3FullEvents::ShutdownGame)));
+        assert_eq!(deserialize_log_line_with_regex(r#"10:75 ShutdownGame:"#, &regex_with_seconds, &DeserializationOptions::default()),
+                   Err(LogParsingError::UnparseableTime { field_name: "seconds", observed_number: "75".to_string() }));
+
+        // the options
+        let options = DeserializationOptions {
+            time_ranges: Some(TimeRanges { max_minutes: Some(90) }),
+            strict_init_game: true,
+            ignored_event_names: &["Bogus"],
+        };
+        let parse = |log_line| deserialize_log_line_with_regex(log_line, &regex, &options);
+        assert_eq!(parse(r#"[1h30] ShutdownGame:"#), Ok((Some(90*60), Quake3FullEvents::ShutdownGame)));
+        assert_eq!(parse(r#"[1h31] ShutdownGame:"#), Err(LogParsingError::UnparseableTime { field_name: "minutes", observed_number: "91".to_string() }));
+        assert_eq!(parse(r#"[0h01] Bogus: 1"#), Ok((Some(60), Quake3FullEvents::Ignored { event_name: "Bogus".into() })));
+        assert_eq!(parse(r#"[0h01] Warmup:"#),
+                   Err(LogParsingError::EventParsingError { event_name: "Warmup".to_string(), event_parsing_error: EventParsingError::UnknownEventName }));
+        assert_eq!(parse(r#"[0h00] InitGame:"#),
+                   Err(LogParsingError::EventParsingError { event_name: "InitGame".to_string(), event_parsing_error: EventParsingError::AbsentKey { key_name: "mapname" } }));
+        let regex_with_optional_minute = log_line_regex(r#"^(?P<minute>\d+)? ?(?P<event_name>[^:]*):? *(?P<data>.*)$"#)
+            .expect("The custom regex with an optional minute should have been accepted");
+        assert_eq!(deserialize_log_line_with_regex(r#"ShutdownGame:"#, &regex_with_optional_minute, &options),
+                   Err(LogParsingError::UnparseableTime { field_name: "time", observed_number: "".to_string() }));
+
+        assert_eq!(log_line_regex(r#"^(?P<minute>\d+) (?P<event_name>[^:]*):? *(?P<rest>.*)$"#).map(|_| ()), Err(LogLineRegexError::MissingGroup { group_name: "data" }));
+        assert!(matches!(log_line_regex(r#"^(?P<minute>\d+"#), Err(LogLineRegexError::InvalidPattern { .. })), "An invalid pattern should have been refused");
+    }
+
     fn assert_log_parsing_error(log_line: &str, expected_log_parsing_error: LogParsingError) {
         let deserialization_result = deserialize_log_line(log_line);
         assert!(deserialization_result.is_err(), "The bad log line '{log_line}' did not fail in the deserialization (as it should). The unexpected Ok parsing result was {:?}", deserialization_result.unwrap());
@@ -1,4 +1,5 @@
 #![doc = include_str!("../README.md")]
 
 pub mod deserializer_logs;
+pub mod serializer_logs;
 pub mod types;
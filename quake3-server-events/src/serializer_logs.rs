@@ -0,0 +1,101 @@
+//! The inverse of [deserializer_logs](crate::deserializer_logs): reconstructs canonical Quake 3 log lines out of the
+//! parsed events -- useful for generating test fixtures.
+//!
+//! Lines are not byte-identical to the ones produced by the server -- data not kept in the events (pings, most of the
+//! `InitGame` server info, ...) is left out -- but they deserialize back to the same events.
+
+use crate::types::Quake3FullEvents;
+use std::fmt::Write;
+
+
+/// Reconstructs a canonical Quake 3 log line for `event`, at the time `0:00` -- so that
+/// `deserialize_log_line(&serialize_log_line(&event)) == Ok(event)`
+pub fn serialize_log_line(event: &Quake3FullEvents) -> String {
+    serialize_log_line_with_time(0, event)
+}
+
+/// Same as [serialize_log_line()], but with the line's `MM:SS` time out of `time_secs`
+pub fn serialize_log_line_with_time(time_secs: u32, event: &Quake3FullEvents) -> String {
+    let mut log_line = format!("{:>3}:{:02} ", time_secs / 60, time_secs % 60);
+    // writing to a `String` never fails
+    let _ = match event {
+        Quake3FullEvents::InitGame { frag_limit, capture_limit, time_limit_min, map_name, game_type } => {
+            log_line.push_str("InitGame:");
+            if frag_limit.is_some() || capture_limit.is_some() || time_limit_min.is_some() || map_name.is_some() || game_type.is_some() {
+                log_line.push(' ');
+            }
+            let numbers = [("fraglimit", frag_limit), ("capturelimit", capture_limit), ("timelimit", time_limit_min)];
+            for (key, value) in numbers.into_iter().filter_map(|(key, value)| value.map(|value| (key, value))) {
+                let _ = write!(log_line, "\\{key}\\{value}");
+            }
+            if let Some(game_type) = game_type {
+                let _ = write!(log_line, "\\g_gametype\\{game_type}");
+            }
+            match map_name {
+                Some(map_name) => write!(log_line, "\\mapname\\{map_name}"),
+                None => Ok(()),
+            }
+        },
+        Quake3FullEvents::ClientConnect { id } => write!(log_line, "ClientConnect: {id}"),
+        Quake3FullEvents::ClientUserinfoChanged { id, name } => write!(log_line, "ClientUserinfoChanged: {id} n\\{name}"),
+        Quake3FullEvents::ClientBegin { id } => write!(log_line, "ClientBegin: {id}"),
+        Quake3FullEvents::ClientDisconnect { id } => write!(log_line, "ClientDisconnect: {id}"),
+        Quake3FullEvents::Item { client_id, item_name } if item_name.is_empty() => write!(log_line, "Item: {client_id}"),
+        Quake3FullEvents::Item { client_id, item_name } => write!(log_line, "Item: {client_id} {item_name}"),
+        Quake3FullEvents::Say { name, message } => write!(log_line, "say: {name}: {message}"),
+        Quake3FullEvents::Tell { from, to, message } => write!(log_line, "tell: {from} to {to}: {message}"),
+        Quake3FullEvents::Kill { killer_id, victim_id, reason_id, killer_name, victim_name, reason_name } =>
+            write!(log_line, "Kill: {killer_id} {victim_id} {reason_id}: {killer_name} killed {victim_name} by {reason_name}"),
+        Quake3FullEvents::Exit { reason } if reason.is_empty() => write!(log_line, "Exit:"),
+        Quake3FullEvents::Exit { reason } => write!(log_line, "Exit: {reason}"),
+        Quake3FullEvents::CaptureTheFlagResults { red, blue } => write!(log_line, "red:{red}  blue:{blue}"),
+        Quake3FullEvents::Score { frags, id, name } => write!(log_line, "score: {frags}  ping: 0  client: {id} {name}"),
+        Quake3FullEvents::ShutdownGame => write!(log_line, "ShutdownGame:"),
+        Quake3FullEvents::Comment => write!(log_line, "------------------------------------------------------------"),
+        Quake3FullEvents::Ignored { event_name } => write!(log_line, "{event_name}:"),
+    };
+    log_line
+}
+
+
+/// Unit tests for the [serializer](super) module
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deserializer_logs::{deserialize_log_line, deserialize_log_line_with_options, DeserializationOptions};
+    use std::borrow::Cow;
+
+
+    /// Tests that one sample of each variant survives the round trip through [serialize_log_line()] & `deserialize_log_line()`
+    #[test]
+    fn round_trip() {
+        let events = [
+            Quake3FullEvents::InitGame { frag_limit: Some(20), capture_limit: Some(8), time_limit_min: Some(15), map_name: Some(Cow::Borrowed("q3dm17")), game_type: Some(0) },
+            Quake3FullEvents::InitGame { frag_limit: None, capture_limit: None, time_limit_min: None, map_name: None, game_type: None },
+            Quake3FullEvents::ClientConnect { id: 2 },
+            Quake3FullEvents::ClientUserinfoChanged { id: 2, name: Cow::Borrowed("Dono da Bola") },
+            Quake3FullEvents::ClientBegin { id: 2 },
+            Quake3FullEvents::ClientDisconnect { id: 2 },
+            Quake3FullEvents::Item { client_id: 2, item_name: Cow::Borrowed("weapon_rocketlauncher") },
+            Quake3FullEvents::Item { client_id: 2, item_name: Cow::Borrowed("") },
+            Quake3FullEvents::Say { name: Cow::Borrowed("Isgalamido"), message: Cow::Borrowed("gg: wp") },
+            Quake3FullEvents::Tell { from: Cow::Borrowed("Isgalamido"), to: Cow::Borrowed("Zeh"), message: Cow::Borrowed("hi") },
+            Quake3FullEvents::Kill { killer_id: 1022, victim_id: 2, reason_id: 22, killer_name: Cow::Borrowed("<world>"), victim_name: Cow::Borrowed("Isgalamido"), reason_name: Cow::Borrowed("MOD_TRIGGER_HURT") },
+            Quake3FullEvents::Exit { reason: Cow::Borrowed("Fraglimit hit.") },
+            Quake3FullEvents::Exit { reason: Cow::Borrowed("") },
+            Quake3FullEvents::CaptureTheFlagResults { red: 8, blue: 6 },
+            Quake3FullEvents::Score { frags: -3, id: 4, name: Cow::Borrowed("Zeh: the 2nd") },
+            Quake3FullEvents::ShutdownGame,
+            Quake3FullEvents::Comment,
+            Quake3FullEvents::Ignored { event_name: Cow::Borrowed("Warmup") },
+        ];
+        for event in events {
+            let log_line = serialize_log_line(&event);
+            assert_eq!(deserialize_log_line(&log_line), Ok(event), "The log line '{log_line}' didn't deserialize back into its event");
+        }
+        assert_eq!(deserialize_log_line_with_options(&serialize_log_line_with_time(981*60+27, &Quake3FullEvents::ShutdownGame), &DeserializationOptions::default()),
+                   Ok((Some(981*60+27), Quake3FullEvents::ShutdownGame)),
+                   "The time didn't survive the round trip");
+    }
+
+}
@@ -2,6 +2,11 @@
 
 use std::borrow::Cow;
 
+/// The [Quake3FullEvents::InitGame] `game_type` of "Deathmatch" matches
+pub const GAME_TYPE_DEATHMATCH: u8 = 0;
+/// The [Quake3FullEvents::InitGame] `game_type` of "Capture the flag" matches
+pub const GAME_TYPE_CAPTURE_THE_FLAG: u8 = 4;
+
 /// Mappings for Quake 3 server events.\
 /// IMPLEMENTATION NOTE: The name says "full", despite this not being true, but the term is just to emphasize
 /// that this library represents an external piece of code without any relation to our Business Logic requisites.
@@ -15,6 +20,10 @@ pub enum Quake3FullEvents<'a> {
         capture_limit:  Option<u32>,
         /// Applicable to both modes, specifies the maximum duration for the match, in minutes
         time_limit_min: Option<u32>,
+        /// The map the match is played on -- as written in the log, with no casing normalization
+        map_name: Option<Cow<'a, str>>,
+        /// The `g_gametype` of the match: `0` for "Deathmatch", `4` for "Capture the flag" -- see [GAME_TYPE_DEATHMATCH] & [GAME_TYPE_CAPTURE_THE_FLAG]
+        game_type: Option<u8>,
     },
     /// A player has just connected
     ClientConnect {
@@ -35,9 +44,22 @@ pub enum Quake3FullEvents<'a> {
         id: u32,
     },
     /// Client grab an item
-    Item,
-    /// Client sent a chat
-    Say,
+    Item {
+        client_id: u32,
+        /// The item's class name, such as `ammo_rockets` or `weapon_railgun`
+        item_name: Cow<'a, str>,
+    },
+    /// Client sent a chat to everyone -- or to its team, as `say_team` / `sayteam` lines are also deserialized into it
+    Say {
+        name: Cow<'a, str>,
+        message: Cow<'a, str>,
+    },
+    /// Client sent a private message to another client
+    Tell {
+        from: Cow<'a, str>,
+        to: Cow<'a, str>,
+        message: Cow<'a, str>,
+    },
     /// Client killed someone or died due to injuries / suicide
     Kill {
         killer_id: u32,
@@ -48,7 +70,10 @@ pub enum Quake3FullEvents<'a> {
         reason_name: Cow<'a, str>,
     },
     /// Graceful game finish
-    Exit,
+    Exit {
+        /// Why the game finished, verbatim -- such as `Fraglimit hit.`, `Capturelimit hit.` or `Timelimit hit.`
+        reason: Cow<'a, str>,
+    },
     /// Scores for capture the flag games
     CaptureTheFlagResults {
         red: u32,
@@ -64,4 +89,9 @@ pub enum Quake3FullEvents<'a> {
     ShutdownGame,
     /// Log message that shares no event
     Comment,
+    /// A known event we don't care about -- one of the [crate::deserializer_logs::DeserializationOptions::ignored_event_names],
+    /// such as `Warmup` or `broadcast`: see [crate::deserializer_logs::DEFAULT_IGNORED_EVENT_NAMES]
+    Ignored {
+        event_name: Cow<'a, str>,
+    },
 }
\ No newline at end of file